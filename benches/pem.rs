@@ -0,0 +1,80 @@
+//! Decoding time for a bundle of 150 PEM certificates.
+//!
+//! Run with `cargo bench --features pem --bench pem`. This uses a plain timing loop rather
+//! than a benchmarking framework, so numbers vary more between runs; compare the best times.
+
+use std::fs;
+use std::time::{Duration, Instant};
+
+use rustls_pki_types::pem;
+use rustls_pki_types::CertificateDer;
+
+fn main() {
+    let bundle = bundle();
+    println!(
+        "bundle: {} certificates, {} bytes",
+        CERTIFICATES,
+        bundle.len()
+    );
+
+    bench("pem::read_all", || {
+        pem::read_all(&bundle).filter(|item| item.is_ok()).count()
+    });
+    bench("pem::read_one_from_slice", || {
+        let (mut rest, mut count) = (&bundle[..], 0);
+        while let Some((_, next)) = pem::read_one_from_slice(rest).unwrap() {
+            (rest, count) = (next, count + 1);
+        }
+        count
+    });
+}
+
+/// Run `f` repeatedly, and print the best and median time of one run
+///
+/// Each run's result is checked, so that the work cannot be optimized away.
+fn bench(name: &str, mut f: impl FnMut() -> usize) {
+    let mut times = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            let count = f();
+            let elapsed = start.elapsed();
+            assert_eq!(count, CERTIFICATES);
+            elapsed
+        })
+        .collect::<Vec<Duration>>();
+    times.sort();
+    println!(
+        "{name}: best {:?}, median {:?}",
+        times[0],
+        times[times.len() / 2]
+    );
+}
+
+/// The test certificates, repeated to make a bundle about the size of a public root store
+fn bundle() -> Vec<u8> {
+    let certs = [
+        "root",
+        "root2",
+        "int",
+        "int-cross",
+        "leaf",
+        "leaf-any-eku",
+        "rsaleaf",
+    ]
+    .iter()
+    .map(|name| {
+        let path = format!("{}/testdata/{name}.der", env!("CARGO_MANIFEST_DIR"));
+        CertificateDer::from(fs::read(path).unwrap())
+    })
+    .collect::<Vec<_>>();
+
+    certs
+        .iter()
+        .cycle()
+        .take(CERTIFICATES)
+        .flat_map(|cert| cert.to_pem().into_bytes())
+        .collect()
+}
+
+const CERTIFICATES: usize = 150;
+const ITERATIONS: usize = 200;
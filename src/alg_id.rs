@@ -0,0 +1,187 @@
+//! Common values of the PKIX [`AlgorithmIdentifier`] type.
+//!
+//! Each constant holds the DER encoding of the `algorithm` OID followed by the `parameters`
+//! value (if any), without the outer `SEQUENCE`, as described on [`AlgorithmIdentifier`].
+//!
+//! If you need an [`AlgorithmIdentifier`] that is not defined here, you can define it locally
+//! with [`AlgorithmIdentifier::from_slice`].
+
+use crate::AlgorithmIdentifier;
+
+// Elliptic curve keys and ECDSA signatures.
+//
+// The key identifiers combine `id-ecPublicKey` with the named curve as `parameters`, so there is
+// one per curve. The ECDSA signature identifiers only name the hash function, and are shared by
+// all curves.
+
+/// AlgorithmIdentifier for `id-ecPublicKey` with named curve `brainpoolP256r1`.
+///
+/// The OIDs are `1.2.840.10045.2.1` and `1.3.36.3.3.2.8.1.1.7`.
+pub const ECDSA_BRAINPOOL_P256R1: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x09, 0x2b, 0x24, 0x03, 0x03, 0x02,
+    0x08, 0x01, 0x01, 0x07,
+]);
+
+/// AlgorithmIdentifier for `id-ecPublicKey` with named curve `brainpoolP384r1`.
+///
+/// The OIDs are `1.2.840.10045.2.1` and `1.3.36.3.3.2.8.1.1.11`.
+pub const ECDSA_BRAINPOOL_P384R1: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x09, 0x2b, 0x24, 0x03, 0x03, 0x02,
+    0x08, 0x01, 0x01, 0x0b,
+]);
+
+/// AlgorithmIdentifier for `id-ecPublicKey` with named curve `brainpoolP512r1`.
+///
+/// The OIDs are `1.2.840.10045.2.1` and `1.3.36.3.3.2.8.1.1.13`.
+pub const ECDSA_BRAINPOOL_P512R1: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x09, 0x2b, 0x24, 0x03, 0x03, 0x02,
+    0x08, 0x01, 0x01, 0x0d,
+]);
+
+/// AlgorithmIdentifier for `ecdsa-with-SHA256`, with parameters absent.
+///
+/// The OID is `1.2.840.10045.4.3.2`.
+pub const ECDSA_SHA256: AlgorithmIdentifier =
+    AlgorithmIdentifier::from_slice(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02]);
+
+/// AlgorithmIdentifier for `ecdsa-with-SHA384`, with parameters absent.
+///
+/// The OID is `1.2.840.10045.4.3.3`.
+pub const ECDSA_SHA384: AlgorithmIdentifier =
+    AlgorithmIdentifier::from_slice(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03]);
+
+/// AlgorithmIdentifier for `ecdsa-with-SHA512`, with parameters absent.
+///
+/// The OID is `1.2.840.10045.4.3.4`.
+pub const ECDSA_SHA512: AlgorithmIdentifier =
+    AlgorithmIdentifier::from_slice(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04]);
+
+// Edwards and Montgomery curve algorithms (RFC 8410).
+//
+// `parameters` is always absent. The `id-Ed448` identifier is used both for the key and the
+// signature algorithm.
+
+/// AlgorithmIdentifier for `id-Ed448`, with parameters absent.
+///
+/// The OID is `1.3.101.113`.
+pub const ED448: AlgorithmIdentifier =
+    AlgorithmIdentifier::from_slice(&[0x06, 0x03, 0x2b, 0x65, 0x71]);
+
+/// AlgorithmIdentifier for `id-X448`, with parameters absent.
+///
+/// The OID is `1.3.101.111`. This identifies a key agreement key, which cannot be used to
+/// verify signatures.
+pub const X448: AlgorithmIdentifier =
+    AlgorithmIdentifier::from_slice(&[0x06, 0x03, 0x2b, 0x65, 0x6f]);
+
+// Post-quantum signature algorithms.
+//
+// ML-DSA (FIPS 204) and SLH-DSA (FIPS 205) use the same identifier for the signature algorithm
+// and for the `subjectPublicKeyInfo` algorithm, and `parameters` is always absent. Each constant
+// below therefore serves as both the `public_key_alg_id()` and the `signature_alg_id()` of a
+// `SignatureVerificationAlgorithm`.
+
+/// AlgorithmIdentifier for `id-ml-dsa-44`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.17`.
+pub const ML_DSA_44: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x11,
+]);
+
+/// AlgorithmIdentifier for `id-ml-dsa-65`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.18`.
+pub const ML_DSA_65: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x12,
+]);
+
+/// AlgorithmIdentifier for `id-ml-dsa-87`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.19`.
+pub const ML_DSA_87: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x13,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-sha2-128s`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.20`.
+pub const SLH_DSA_SHA2_128S: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x14,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-sha2-128f`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.21`.
+pub const SLH_DSA_SHA2_128F: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x15,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-sha2-192s`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.22`.
+pub const SLH_DSA_SHA2_192S: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x16,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-sha2-192f`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.23`.
+pub const SLH_DSA_SHA2_192F: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x17,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-sha2-256s`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.24`.
+pub const SLH_DSA_SHA2_256S: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x18,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-sha2-256f`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.25`.
+pub const SLH_DSA_SHA2_256F: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x19,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-shake-128s`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.26`.
+pub const SLH_DSA_SHAKE_128S: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x1a,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-shake-128f`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.27`.
+pub const SLH_DSA_SHAKE_128F: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x1b,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-shake-192s`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.28`.
+pub const SLH_DSA_SHAKE_192S: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x1c,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-shake-192f`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.29`.
+pub const SLH_DSA_SHAKE_192F: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x1d,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-shake-256s`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.30`.
+pub const SLH_DSA_SHAKE_256S: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x1e,
+]);
+
+/// AlgorithmIdentifier for `id-slh-dsa-shake-256f`, with parameters absent.
+///
+/// The OID is `2.16.840.1.101.3.4.3.31`.
+pub const SLH_DSA_SHAKE_256F: AlgorithmIdentifier = AlgorithmIdentifier::from_slice(&[
+    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x1f,
+]);
@@ -0,0 +1,131 @@
+//! Checked construction of trust anchors.
+
+use core::fmt;
+
+use crate::der::{self, InvalidDer, Reader};
+use crate::{Der, TrustAnchor};
+
+impl<'a> TrustAnchor<'a> {
+    /// Make a trust anchor from its parts, checking that they have the expected structure
+    ///
+    /// As in the fields of [`TrustAnchor`], each part is the contents of the corresponding
+    /// `SEQUENCE`, without its tag and length:
+    ///
+    /// - `subject` must be the non-empty list of `RelativeDistinguishedName` sets of a `Name`,
+    /// - `subject_public_key_info` must be an `AlgorithmIdentifier` `SEQUENCE` followed by
+    ///   the public key `BIT STRING`, and
+    /// - `name_constraints`, if given, must hold only the optional `permittedSubtrees` and
+    ///   `excludedSubtrees` elements.
+    ///
+    /// The checks are cheap and only look at the outer layer of each part. They catch parts
+    /// taken from the wrong place, like the public key `BIT STRING` in place of the whole
+    /// `SubjectPublicKeyInfo`, or parts that still have their outer tag and length. A struct
+    /// literal or [`TrustAnchor::from_parts()`] can still be used for data that is trusted.
+    ///
+    /// ```
+    /// use rustls_pki_types::{InvalidDer, InvalidTrustAnchor, TrustAnchor};
+    ///
+    /// let subject: &[u8] = &[
+    ///     0x31, 0x0b, 0x30, 0x09, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x02, b'e', b'd',
+    /// ];
+    /// let algorithm: &[u8] = &[0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70];
+    /// let key: &[u8] = &[0x03, 0x03, 0x00, b'p', b'k'];
+    ///
+    /// let spki = [algorithm, key].concat();
+    /// assert!(TrustAnchor::new(subject, &spki[..], None).is_ok());
+    ///
+    /// assert_eq!(
+    ///     TrustAnchor::new(subject, key, None),
+    ///     Err(InvalidTrustAnchor::SubjectPublicKeyInfo(InvalidDer::UnexpectedTag {
+    ///         expected: 0x30,
+    ///         found: 0x03,
+    ///     }))
+    /// );
+    /// ```
+    pub fn new(
+        subject: impl Into<Der<'a>>,
+        subject_public_key_info: impl Into<Der<'a>>,
+        name_constraints: Option<Der<'a>>,
+    ) -> Result<Self, InvalidTrustAnchor> {
+        let anchor = Self {
+            subject: subject.into(),
+            subject_public_key_info: subject_public_key_info.into(),
+            name_constraints,
+        };
+
+        check_subject(&anchor.subject).map_err(InvalidTrustAnchor::Subject)?;
+        check_spki(&anchor.subject_public_key_info)
+            .map_err(InvalidTrustAnchor::SubjectPublicKeyInfo)?;
+        if let Some(nc) = &anchor.name_constraints {
+            check_name_constraints(nc).map_err(InvalidTrustAnchor::NameConstraints)?;
+        }
+
+        Ok(anchor)
+    }
+}
+
+/// Check that `subject` is a non-empty sequence of `SET`s
+fn check_subject(subject: &[u8]) -> Result<(), InvalidDer> {
+    if subject.is_empty() {
+        return Err(InvalidDer::InvalidValue);
+    }
+
+    let mut reader = Reader::new(subject);
+    while reader.peek_tag().is_some() {
+        reader.read(der::SET)?;
+    }
+    Ok(())
+}
+
+/// Check that `spki` is an `AlgorithmIdentifier` followed by a `BIT STRING`
+fn check_spki(spki: &[u8]) -> Result<(), InvalidDer> {
+    let mut reader = Reader::new(spki);
+    reader.read(der::SEQUENCE)?;
+    reader.read(der::BIT_STRING)?;
+    reader.finish()
+}
+
+/// Check that `name_constraints` holds only the optional `[0]` and `[1]` subtrees
+fn check_name_constraints(name_constraints: &[u8]) -> Result<(), InvalidDer> {
+    let mut reader = Reader::new(name_constraints);
+    reader.read_optional(der::context_constructed(0))?;
+    reader.read_optional(der::context_constructed(1))?;
+    reader.finish()
+}
+
+/// An error from [`TrustAnchor::new()`], naming the part that was malformed
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidTrustAnchor {
+    /// The subject was not a non-empty list of `RelativeDistinguishedName` sets
+    Subject(InvalidDer),
+    /// The public key was not an `AlgorithmIdentifier` followed by a `BIT STRING`
+    SubjectPublicKeyInfo(InvalidDer),
+    /// The name constraints held something other than the permitted and excluded subtrees
+    NameConstraints(InvalidDer),
+}
+
+impl fmt::Display for InvalidTrustAnchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Subject(err) => write!(f, "invalid trust anchor subject: {err}"),
+            Self::SubjectPublicKeyInfo(err) => {
+                write!(f, "invalid trust anchor public key: {err}")
+            }
+            Self::NameConstraints(err) => {
+                write!(f, "invalid trust anchor name constraints: {err}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidTrustAnchor {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Subject(err) | Self::SubjectPublicKeyInfo(err) | Self::NameConstraints(err) => {
+                Some(err)
+            }
+        }
+    }
+}
@@ -0,0 +1,339 @@
+//! Base64 encoding and decoding, as used in PEM and other textual encodings of DER.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{CertificateDer, CertificateSigningRequestDer};
+#[cfg(feature = "x509")]
+use crate::{CertificateRevocationListDer, InvalidDer, SubjectPublicKeyInfoDer};
+
+impl CertificateDer<'_> {
+    /// Encode this certificate as unpadded base64url
+    ///
+    /// This is the form used in JOSE and ACME (RFC 8555) messages: the URL-safe alphabet of
+    /// RFC 4648, section 5, without `=` padding.
+    pub fn to_base64url(&self) -> String {
+        encode(self.as_ref(), URL_SAFE, false)
+    }
+
+    /// Decode a certificate from unpadded base64url
+    ///
+    /// This is the inverse of [`CertificateDer::to_base64url()`]. Padding, whitespace and
+    /// characters from the standard alphabet are rejected. The contents are not checked to be
+    /// a certificate.
+    pub fn from_base64url(encoded: &str) -> Result<CertificateDer<'static>, InvalidBase64> {
+        Ok(CertificateDer::from(decode_url_safe(encoded.as_bytes())?))
+    }
+
+    /// Decode a certificate from base64, without PEM armor
+    ///
+    /// This is the form often found in configuration, environment variables and the `x5c`
+    /// field of a JWK. Characters from both the standard and the URL-safe alphabet are
+    /// accepted, padding is optional, and whitespace is ignored. The structure of the
+    /// certificate is checked as by [`CertificateDer::spans()`].
+    #[cfg(feature = "x509")]
+    pub fn from_base64(encoded: &str) -> Result<CertificateDer<'static>, Base64DerError> {
+        let der = decode_der(encoded, |der| CertificateDer::from(der).spans().map(|_| ()))?;
+        Ok(CertificateDer::from(der))
+    }
+}
+
+#[cfg(feature = "x509")]
+impl CertificateRevocationListDer<'_> {
+    /// Decode a CRL from base64, without PEM armor
+    ///
+    /// As for [`CertificateDer::from_base64()`], either alphabet is accepted, padding is
+    /// optional, and whitespace is ignored. The structure of the CRL is checked as
+    /// by [`CertificateRevocationListDer::spans()`].
+    pub fn from_base64(
+        encoded: &str,
+    ) -> Result<CertificateRevocationListDer<'static>, Base64DerError> {
+        let der = decode_der(encoded, |der| {
+            CertificateRevocationListDer::from(der).spans().map(|_| ())
+        })?;
+        Ok(CertificateRevocationListDer::from(der))
+    }
+}
+
+#[cfg(feature = "x509")]
+impl SubjectPublicKeyInfoDer<'_> {
+    /// Decode a public key from base64, without PEM armor
+    ///
+    /// As for [`CertificateDer::from_base64()`], either alphabet is accepted, padding is
+    /// optional, and whitespace is ignored. The structure of the key is checked as
+    /// by [`SubjectPublicKeyInfoDer::algorithm()`].
+    pub fn from_base64(encoded: &str) -> Result<SubjectPublicKeyInfoDer<'static>, Base64DerError> {
+        let der = decode_der(encoded, |der| {
+            SubjectPublicKeyInfoDer::from(der).algorithm().map(|_| ())
+        })?;
+        Ok(SubjectPublicKeyInfoDer::from(der))
+    }
+}
+
+impl CertificateSigningRequestDer<'_> {
+    /// Encode this certificate signing request as unpadded base64url
+    ///
+    /// This is the form expected in the `csr` field of an ACME `finalize` request (RFC 8555,
+    /// section 7.4): the URL-safe alphabet of RFC 4648, section 5, without `=` padding.
+    pub fn to_base64url(&self) -> String {
+        encode(self.as_ref(), URL_SAFE, false)
+    }
+
+    /// Decode a certificate signing request from unpadded base64url
+    ///
+    /// This is the inverse of [`CertificateSigningRequestDer::to_base64url()`]. Padding,
+    /// whitespace and characters from the standard alphabet are rejected. The contents are not
+    /// checked to be a certificate signing request.
+    pub fn from_base64url(
+        encoded: &str,
+    ) -> Result<CertificateSigningRequestDer<'static>, InvalidBase64> {
+        Ok(CertificateSigningRequestDer::from(decode_url_safe(
+            encoded.as_bytes(),
+        )?))
+    }
+
+    /// Decode a certificate signing request from base64, without PEM armor
+    ///
+    /// As for [`CertificateDer::from_base64()`], either alphabet is accepted, padding is
+    /// optional, and whitespace is ignored. The structure of the request is checked
+    /// as far as needed to locate its public key.
+    #[cfg(feature = "x509")]
+    pub fn from_base64(
+        encoded: &str,
+    ) -> Result<CertificateSigningRequestDer<'static>, Base64DerError> {
+        let der = decode_der(encoded, |der| {
+            CertificateSigningRequestDer::from(der)
+                .subject_public_key_info()
+                .map(|_| ())
+        })?;
+        Ok(CertificateSigningRequestDer::from(der))
+    }
+}
+
+/// Decode base64 `encoded`, in either alphabet, and check the result with `validate`
+#[cfg(feature = "x509")]
+fn decode_der(
+    encoded: &str,
+    validate: impl FnOnce(&[u8]) -> Result<(), InvalidDer>,
+) -> Result<Vec<u8>, Base64DerError> {
+    let der = decode_with(encoded.as_bytes(), &EITHER_VALUES, Mode::Lenient)
+        .map_err(Base64DerError::Base64)?;
+    validate(&der).map_err(Base64DerError::Der)?;
+    Ok(der)
+}
+
+/// Encode `input` as base64 using `alphabet`, optionally with padding
+pub(crate) fn encode(input: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut out = String::with_capacity((input.len() + 2) / 3 * 4);
+    encode_into(input, alphabet, pad, &mut out);
+    out
+}
+
+/// Append the base64 encoding of `input` to `out`, as for [`encode()`]
+pub(crate) fn encode_into(input: &[u8], alphabet: &[u8; 64], pad: bool, out: &mut String) {
+    // Writing to a `String` cannot fail.
+    let _ = write(input, alphabet, pad, out);
+}
+
+/// Write the base64 encoding of `input` to `out`, as for [`encode()`]
+pub(crate) fn write(
+    input: &[u8],
+    alphabet: &[u8; 64],
+    pad: bool,
+    out: &mut impl fmt::Write,
+) -> fmt::Result {
+    for chunk in input.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | u32::from(b) << (16 - 8 * i));
+
+        // Each input byte contributes to one more output character.
+        for i in 0..=chunk.len() {
+            let value = (group >> (18 - 6 * i)) & 0x3f;
+            out.write_char(char::from(alphabet[value as usize]))?;
+        }
+
+        if pad {
+            for _ in chunk.len()..3 {
+                out.write_char('=')?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Decode standard base64 `input`, ignoring ASCII whitespace
+///
+/// Padding is accepted but not required.
+#[cfg(any(
+    feature = "pem",
+    feature = "std",
+    feature = "serde",
+    feature = "serde-secrets"
+))]
+pub(crate) fn decode(input: &[u8]) -> Result<Vec<u8>, InvalidBase64> {
+    decode_with(input, &STANDARD_VALUES, Mode::Lenient)
+}
+
+/// Decode standard base64 `input`, requiring padding and not tolerating whitespace
+pub(crate) fn decode_padded(input: &[u8]) -> Result<Vec<u8>, InvalidBase64> {
+    decode_with(input, &STANDARD_VALUES, Mode::Padded)
+}
+
+/// Decode unpadded base64url `input`, without tolerating whitespace or padding
+pub(crate) fn decode_url_safe(input: &[u8]) -> Result<Vec<u8>, InvalidBase64> {
+    decode_with(input, &URL_SAFE_VALUES, Mode::Unpadded)
+}
+
+/// How [`decode_with()`] treats whitespace and padding
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    /// Whitespace is ignored, and padding is accepted but not required
+    Lenient,
+    /// Padding is required, and whitespace is rejected
+    Padded,
+    /// Padding and whitespace are rejected
+    Unpadded,
+}
+
+/// Decode `input` using `values`, the inverse of an alphabet from [`inverse()`]
+fn decode_with(input: &[u8], values: &[u8; 256], mode: Mode) -> Result<Vec<u8>, InvalidBase64> {
+    if mode == Mode::Padded && input.len() % 4 != 0 {
+        return Err(InvalidBase64);
+    }
+
+    // Exact for unbroken, unpadded input; padding and whitespace only make the output shorter.
+    let mut out = Vec::with_capacity(input.len() / 4 * 3 + input.len() % 4 * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let mut padding = 0usize;
+
+    for &byte in input {
+        if mode == Mode::Lenient && byte.is_ascii_whitespace() {
+            continue;
+        }
+
+        if mode != Mode::Unpadded && byte == b'=' {
+            padding += 1;
+            continue;
+        } else if padding > 0 {
+            // Padding may only appear at the end.
+            return Err(InvalidBase64);
+        }
+
+        let value = values[usize::from(byte)];
+        if value == INVALID {
+            return Err(InvalidBase64);
+        }
+
+        acc = (acc << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    // A single dangling character cannot encode a full byte, and any unused bits must be zero.
+    // If present, padding must complete the final group of four characters: two leftover
+    // characters leave four bits and need two padding characters, three leave two and need one.
+    if bits == 6 || acc != 0 || (padding > 0 && padding != (bits / 2) as usize) {
+        return Err(InvalidBase64);
+    }
+
+    Ok(out)
+}
+
+/// The standard alphabet, from RFC 4648, section 4
+pub(crate) const STANDARD: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The URL- and filename-safe alphabet, from RFC 4648, section 5
+pub(crate) const URL_SAFE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Whether `byte` is a character of the standard alphabet, not counting padding
+#[cfg(any(feature = "pem", feature = "std"))]
+pub(crate) fn is_standard(byte: u8) -> bool {
+    STANDARD_VALUES[usize::from(byte)] != INVALID
+}
+
+/// The value of each character of [`STANDARD`], indexed by the character
+static STANDARD_VALUES: [u8; 256] = inverse(STANDARD);
+
+/// The value of each character of [`URL_SAFE`], indexed by the character
+static URL_SAFE_VALUES: [u8; 256] = inverse(URL_SAFE);
+
+/// The value of each character of [`STANDARD`] and [`URL_SAFE`], indexed by the character
+#[cfg(feature = "x509")]
+static EITHER_VALUES: [u8; 256] = {
+    let mut values = inverse(STANDARD);
+    values[b'-' as usize] = 62;
+    values[b'_' as usize] = 63;
+    values
+};
+
+/// Map each character of `alphabet` to its value, and every other octet to [`INVALID`]
+///
+/// Looking characters up like this, rather than searching the alphabet, keeps decoding large
+/// PEM bundles fast.
+const fn inverse(alphabet: &[u8; 64]) -> [u8; 256] {
+    let mut values = [INVALID; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        values[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    values
+}
+
+/// A value outside of the alphabet, for octets which are not base64 characters
+const INVALID: u8 = 0xff;
+
+/// Input was not valid base64
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidBase64;
+
+impl fmt::Display for InvalidBase64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid base64")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidBase64 {}
+
+/// An error decoding a DER-encoded object from base64
+#[cfg(feature = "x509")]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Base64DerError {
+    /// The input was not valid base64
+    Base64(InvalidBase64),
+    /// The decoded bytes did not have the structure of the expected object
+    Der(InvalidDer),
+}
+
+#[cfg(feature = "x509")]
+impl fmt::Display for Base64DerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base64(err) => err.fmt(f),
+            Self::Der(err) => write!(f, "invalid DER after base64 decoding: {err}"),
+        }
+    }
+}
+
+#[cfg(all(feature = "x509", feature = "std"))]
+impl std::error::Error for Base64DerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Base64(err) => Some(err),
+            Self::Der(err) => Some(err),
+        }
+    }
+}
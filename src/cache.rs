@@ -0,0 +1,370 @@
+//! A binary format for caching certificate chains.
+//!
+//! A cache file holds one or more records. Each record starts with the four octets `pkic`, a
+//! one-octet format version and the number of entries as a big-endian `u32`, followed by each
+//! entry's DER encoding, prefixed with its length as a big-endian `u32`. The record ends with
+//! the CRC-32 of everything before it in the record, from the version on, as a big-endian
+//! `u32`.
+//!
+//! A [`QualifiedTrustAnchor`] is written as one record, whose entries each start with a
+//! one-octet tag saying what follows. The subject and public key come first, then the name
+//! constraints, distrust date, allowed extended key usages and flags if there are any, in the
+//! order of their tags.
+
+use core::fmt;
+use std::io::{self, Read, Write};
+
+use std::time::Duration;
+
+use crate::{
+    CertificateChainDer, CertificateDer, Der, OcspResponseDer, QualifiedTrustAnchor, TrustAnchor,
+    UnixTime,
+};
+
+impl CertificateChainDer<'_> {
+    /// Write this chain to `w` in the cache format
+    ///
+    /// The chain is written as a single record. Fails with [`CacheError::EntryTooLarge`] if a
+    /// certificate is longer than [`MAX_CACHED_ENTRY_LEN`], so that everything written can be
+    /// read back.
+    pub fn write_cached(&self, mut w: impl Write) -> Result<(), CacheError> {
+        write_record(&mut w, self.iter().map(|cert| cert.as_ref()))
+    }
+
+    /// Write this chain and an optional OCSP response for its end-entity certificate to `w`
+    ///
+    /// The chain is written as for [`CertificateChainDer::write_cached()`], followed by a
+    /// record holding the OCSP response, if any.
+    pub fn write_cached_with_ocsp(
+        &self,
+        ocsp: Option<&OcspResponseDer<'_>>,
+        mut w: impl Write,
+    ) -> Result<(), CacheError> {
+        self.write_cached(&mut w)?;
+        write_record(&mut w, ocsp.map(|ocsp| ocsp.as_ref()).into_iter())
+    }
+
+    /// Read a chain written by [`CertificateChainDer::write_cached()`] from `r`
+    ///
+    /// Exactly one record is read, so `r` is left positioned after it. The certificates are
+    /// not validated.
+    pub fn read_cached(mut r: impl Read) -> Result<CertificateChainDer<'static>, CacheError> {
+        let certs = read_record(&mut r)?
+            .into_iter()
+            .map(CertificateDer::from)
+            .collect::<Vec<_>>();
+        Ok(CertificateChainDer::from(certs))
+    }
+
+    /// Read a chain and OCSP response written by
+    /// [`CertificateChainDer::write_cached_with_ocsp()`] from `r`
+    pub fn read_cached_with_ocsp(
+        mut r: impl Read,
+    ) -> Result<
+        (
+            CertificateChainDer<'static>,
+            Option<OcspResponseDer<'static>>,
+        ),
+        CacheError,
+    > {
+        let chain = Self::read_cached(&mut r)?;
+        let mut ocsp = read_record(&mut r)?;
+        match ocsp.len() {
+            0 | 1 => Ok((chain, ocsp.pop().map(OcspResponseDer::from))),
+            count => Err(CacheError::UnexpectedCount(count)),
+        }
+    }
+}
+
+impl QualifiedTrustAnchor<'_> {
+    /// Write this anchor and its metadata to `w` in the cache format
+    ///
+    /// An anchor without metadata, like one converted from a plain [`TrustAnchor`], is written
+    /// as only its subject, public key and name constraints. Fails with
+    /// [`CacheError::EntryTooLarge`] if a part, with its one-octet tag, is longer than
+    /// [`MAX_CACHED_ENTRY_LEN`].
+    pub fn write_cached(&self, mut w: impl Write) -> Result<(), CacheError> {
+        let anchor = &self.anchor;
+        let mut entries = vec![
+            tagged(TAG_SUBJECT, &anchor.subject),
+            tagged(TAG_SPKI, &anchor.subject_public_key_info),
+        ];
+        if let Some(nc) = &anchor.name_constraints {
+            entries.push(tagged(TAG_NAME_CONSTRAINTS, nc));
+        }
+        if let Some(after) = self.distrust_after {
+            entries.push(tagged(TAG_DISTRUST_AFTER, &after.as_secs().to_be_bytes()));
+        }
+        if let Some(ekus) = &self.allowed_ekus {
+            entries.push(tagged(TAG_EKUS_RESTRICTED, &[]));
+            entries.extend(ekus.iter().map(|eku| tagged(TAG_ALLOWED_EKU, eku)));
+        }
+        entries.extend(
+            self.flags
+                .iter()
+                .map(|flag| tagged(TAG_FLAG, flag.as_bytes())),
+        );
+
+        write_record(&mut w, entries.iter().map(|entry| &entry[..]))
+    }
+
+    /// Read an anchor written by [`QualifiedTrustAnchor::write_cached()`] from `r`
+    ///
+    /// Exactly one record is read, so `r` is left positioned after it. The anchor is not
+    /// validated. Fails with [`CacheError::MalformedEntry`] if an entry has an unknown tag or
+    /// is out of order, or if the subject or public key is missing.
+    pub fn read_cached(mut r: impl Read) -> Result<QualifiedTrustAnchor<'static>, CacheError> {
+        let (mut subject, mut spki, mut name_constraints) = (None, None, None);
+        let (mut distrust_after, mut allowed_ekus, mut flags) = (None, None, Vec::new());
+
+        let mut last = None;
+        for mut entry in read_record(&mut r)? {
+            let tag = match entry.first() {
+                Some(&tag) => tag,
+                None => return Err(CacheError::MalformedEntry),
+            };
+            // Only the allowed extended key usages and flags may repeat.
+            let repeats = matches!(tag, TAG_ALLOWED_EKU | TAG_FLAG);
+            match last {
+                Some(last) if last > tag || (last == tag && !repeats) => {
+                    return Err(CacheError::MalformedEntry)
+                }
+                _ => last = Some(tag),
+            }
+
+            let value = entry.split_off(1);
+            match tag {
+                TAG_SUBJECT => subject = Some(Der::from(value)),
+                TAG_SPKI => spki = Some(Der::from(value)),
+                TAG_NAME_CONSTRAINTS => name_constraints = Some(Der::from(value)),
+                TAG_DISTRUST_AFTER => {
+                    let secs =
+                        <[u8; 8]>::try_from(&value[..]).map_err(|_| CacheError::MalformedEntry)?;
+                    distrust_after = Some(UnixTime::since_unix_epoch(Duration::from_secs(
+                        u64::from_be_bytes(secs),
+                    )));
+                }
+                TAG_EKUS_RESTRICTED if value.is_empty() => allowed_ekus = Some(Vec::new()),
+                TAG_ALLOWED_EKU => match &mut allowed_ekus {
+                    Some(ekus) => ekus.push(Der::from(value)),
+                    None => return Err(CacheError::MalformedEntry),
+                },
+                TAG_FLAG => {
+                    flags.push(String::from_utf8(value).map_err(|_| CacheError::MalformedEntry)?)
+                }
+                _ => return Err(CacheError::MalformedEntry),
+            }
+        }
+
+        let anchor = match (subject, spki) {
+            (Some(subject), Some(subject_public_key_info)) => TrustAnchor {
+                subject,
+                subject_public_key_info,
+                name_constraints,
+            },
+            _ => return Err(CacheError::MalformedEntry),
+        };
+
+        Ok(QualifiedTrustAnchor {
+            anchor,
+            distrust_after,
+            allowed_ekus,
+            flags,
+        })
+    }
+}
+
+/// `value` prefixed with the anchor entry tag `tag`
+fn tagged(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut entry = Vec::with_capacity(1 + value.len());
+    entry.push(tag);
+    entry.extend_from_slice(value);
+    entry
+}
+
+fn write_record<'a>(
+    w: &mut impl Write,
+    entries: impl Iterator<Item = &'a [u8]> + Clone,
+) -> Result<(), CacheError> {
+    let count = entries.clone().count();
+    let count = u32::try_from(count).map_err(|_| CacheError::UnexpectedCount(count))?;
+    if let Some(entry) = entries.clone().find(|e| e.len() > MAX_CACHED_ENTRY_LEN) {
+        return Err(CacheError::EntryTooLarge { len: entry.len() });
+    }
+
+    w.write_all(CACHE_MAGIC)?;
+    let mut crc = Crc32::new();
+    let mut write = |bytes: &[u8]| {
+        crc.update(bytes);
+        w.write_all(bytes)
+    };
+
+    write(&[CACHE_VERSION])?;
+    write(&count.to_be_bytes())?;
+    for entry in entries {
+        // Lengths were checked against `MAX_CACHED_ENTRY_LEN` above.
+        write(&(entry.len() as u32).to_be_bytes())?;
+        write(entry)?;
+    }
+
+    w.write_all(&crc.finish().to_be_bytes())?;
+    Ok(())
+}
+
+fn read_record(r: &mut impl Read) -> Result<Vec<Vec<u8>>, CacheError> {
+    let mut magic = [0; 4];
+    r.read_exact(&mut magic)?;
+    if &magic != CACHE_MAGIC {
+        return Err(CacheError::BadMagic);
+    }
+
+    let mut crc = Crc32::new();
+    let mut read = |buf: &mut [u8]| {
+        r.read_exact(buf)?;
+        crc.update(buf);
+        Ok::<_, CacheError>(())
+    };
+
+    let mut version = [0; 1];
+    read(&mut version)?;
+    if version[0] != CACHE_VERSION {
+        return Err(CacheError::UnsupportedVersion(version[0]));
+    }
+
+    let mut count = [0; 4];
+    read(&mut count)?;
+    let count = u32::from_be_bytes(count);
+    // The count is not trusted for preallocation: a corrupt file could claim any number.
+    let mut entries = Vec::with_capacity(count.min(16) as usize);
+    for _ in 0..count {
+        let mut len = [0; 4];
+        read(&mut len)?;
+        let len = u32::from_be_bytes(len) as usize;
+        if len > MAX_CACHED_ENTRY_LEN {
+            return Err(CacheError::EntryTooLarge { len });
+        }
+
+        let mut entry = vec![0; len];
+        read(&mut entry)?;
+        entries.push(entry);
+    }
+
+    let expected = crc.finish();
+    let mut found = [0; 4];
+    r.read_exact(&mut found)?;
+    if u32::from_be_bytes(found) != expected {
+        return Err(CacheError::ChecksumMismatch);
+    }
+
+    Ok(entries)
+}
+
+/// A CRC-32, as used by gzip and PNG, computed a bit at a time
+struct Crc32(u32);
+
+impl Crc32 {
+    fn new() -> Self {
+        Self(!0)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u32::from(byte);
+            for _ in 0..8 {
+                let mask = (self.0 & 1).wrapping_neg();
+                self.0 = (self.0 >> 1) ^ (0xedb8_8320 & mask);
+            }
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+/// The longest entry allowed in the cache format, in bytes
+pub const MAX_CACHED_ENTRY_LEN: usize = 1 << 20;
+
+/// The octets that start every record
+const CACHE_MAGIC: &[u8; 4] = b"pkic";
+
+/// The version of the cache format written by this crate
+const CACHE_VERSION: u8 = 1;
+
+/// Tags of the entries in a [`QualifiedTrustAnchor`] record, in the order they are written
+const TAG_SUBJECT: u8 = 0;
+const TAG_SPKI: u8 = 1;
+const TAG_NAME_CONSTRAINTS: u8 = 2;
+/// Seconds since the Unix epoch, as a big-endian `u64`
+const TAG_DISTRUST_AFTER: u8 = 3;
+/// Empty, marking that the extended key usages are restricted to those that follow
+const TAG_EKUS_RESTRICTED: u8 = 4;
+const TAG_ALLOWED_EKU: u8 = 5;
+/// UTF-8 text
+const TAG_FLAG: u8 = 6;
+
+/// An error reading or writing the cache format
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum CacheError {
+    /// Reading or writing failed
+    Io(io::Error),
+    /// The input ended before the end of a record
+    Truncated,
+    /// A record did not start with the cache format's magic octets
+    BadMagic,
+    /// A record had a format version other than the one written by this crate
+    UnsupportedVersion(u8),
+    /// An entry was longer than [`MAX_CACHED_ENTRY_LEN`]
+    EntryTooLarge {
+        /// The length of the entry, in bytes
+        len: usize,
+    },
+    /// A record had a number of entries that is not allowed in its position
+    UnexpectedCount(usize),
+    /// A record's checksum did not match its contents
+    ChecksumMismatch,
+    /// An entry of a trust anchor record was unknown, out of order or malformed, or a
+    /// required entry was missing
+    MalformedEntry,
+}
+
+impl From<io::Error> for CacheError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::UnexpectedEof => Self::Truncated,
+            _ => Self::Io(err),
+        }
+    }
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "cache I/O failed: {err}"),
+            Self::Truncated => f.write_str("cache record is truncated"),
+            Self::BadMagic => f.write_str("not a cache record"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported cache format version {version}")
+            }
+            Self::EntryTooLarge { len } => write!(
+                f,
+                "cache entry of {len} bytes exceeds the limit of {MAX_CACHED_ENTRY_LEN} bytes"
+            ),
+            Self::UnexpectedCount(count) => {
+                write!(f, "unexpected number of entries in cache record: {count}")
+            }
+            Self::ChecksumMismatch => f.write_str("cache record checksum mismatch"),
+            Self::MalformedEntry => f.write_str("malformed trust anchor cache entry"),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
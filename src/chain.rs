@@ -0,0 +1,341 @@
+//! Certificate chains: decoding them from JOSE `x5c` arrays, and putting them in order.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::base64::{self, STANDARD};
+use crate::CertificateDer;
+#[cfg(feature = "x509")]
+use crate::{InvalidDer, SubjectPublicKeyInfoDer};
+
+/// A chain of DER-encoded X.509 certificates, starting with the end-entity certificate
+///
+/// The end-entity (or leaf) certificate comes first, and each following certificate is
+/// expected to have issued the one before it. The chain dereferences to a slice of
+/// certificates, so it can be passed to APIs that take `&[CertificateDer]`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CertificateChainDer<'a>(pub(crate) Vec<CertificateDer<'a>>);
+
+impl<'a> CertificateChainDer<'a> {
+    /// Normalize a certificate chain as downloaded from an ACME server
+    ///
+    /// RFC 8555 requires the `application/pem-certificate-chain` download to start with the
+    /// end-entity certificate, followed by the certificates needed to verify it, but servers
+    /// differ: some include the root, and some list intermediates in an unexpected order.
+    ///
+    /// This checks that the first certificate in `certs` has the public key `end_entity` (for a
+    /// certificate requested with a CSR, see [`subject_public_key_info()`][csr-spki]), then
+    /// orders the remaining certificates so that each one's subject is the issuer of the
+    /// certificate before it, and finally leaves out a trailing self-issued certificate. Names
+    /// are compared byte-for-byte, and no signatures are verified.
+    ///
+    /// Certificates are never left out or reordered silently: the returned
+    /// [`ChainNormalization`] describes what was changed, and an error is returned if the order
+    /// cannot be determined unambiguously, or if any certificate is not part of the chain.
+    ///
+    /// [csr-spki]: crate::CertificateSigningRequestDer::subject_public_key_info
+    #[cfg(feature = "x509")]
+    pub fn normalize_acme(
+        certs: Vec<CertificateDer<'a>>,
+        end_entity: &SubjectPublicKeyInfoDer<'_>,
+    ) -> Result<(Self, ChainNormalization), ChainError> {
+        let normalization = ChainNormalization::new(&certs, end_entity)?;
+
+        let mut certs = certs.into_iter().map(Some).collect::<Vec<_>>();
+        let chain = normalization
+            .order
+            .iter()
+            .filter_map(|&i| certs[i].take())
+            .collect();
+        Ok((Self(chain), normalization))
+    }
+
+    /// Decode a chain from the value of a JOSE `x5c` header parameter
+    ///
+    /// Each element of `x5c` is a standard base64 (not base64url) encoded certificate, starting
+    /// with the end-entity certificate, as specified in RFC 7515 section 4.1.6. Elements must
+    /// be padded and must not contain whitespace. An empty array is rejected.
+    ///
+    /// The certificates themselves are not decoded. To check that they are in order, see
+    /// `CertificateChainDer::check_issuer_order()`.
+    pub fn from_x5c<I>(x5c: I) -> Result<CertificateChainDer<'static>, X5cError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let certs = x5c
+            .into_iter()
+            .enumerate()
+            .map(
+                |(index, encoded)| match base64::decode_padded(encoded.as_ref().as_bytes()) {
+                    Ok(der) => Ok(CertificateDer::from(der)),
+                    Err(_) => Err(X5cError::InvalidBase64 { index }),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if certs.is_empty() {
+            return Err(X5cError::Empty);
+        }
+
+        Ok(CertificateChainDer(certs))
+    }
+
+    /// Check that each certificate's issuer is the subject of the one following it
+    ///
+    /// Names are compared byte-for-byte, and no signatures are verified. This is meant for
+    /// chains from [`CertificateChainDer::from_x5c()`], which RFC 7515 requires to be in this
+    /// order; to put a chain in order instead, see [`CertificateChainDer::normalize_acme()`].
+    #[cfg(feature = "x509")]
+    pub fn check_issuer_order(&self) -> Result<(), X5cError> {
+        let spans = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(index, cert)| {
+                cert.spans()
+                    .map_err(|error| X5cError::InvalidCertificate { index, error })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match spans
+            .windows(2)
+            .position(|pair| pair[0].issuer() != pair[1].subject())
+        {
+            Some(index) => Err(X5cError::NotLeafFirst { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Encode this chain as the value of a JOSE `x5c` header parameter
+    ///
+    /// Each certificate is encoded as standard base64 with padding, in the order of the chain.
+    pub fn to_x5c(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|cert| base64::encode(cert.as_ref(), STANDARD, true))
+            .collect()
+    }
+
+    /// The end-entity certificate, if the chain is not empty
+    pub fn leaf(&self) -> Option<&CertificateDer<'a>> {
+        self.0.first()
+    }
+
+    /// The certificates following the end-entity certificate
+    pub fn intermediates(&self) -> &[CertificateDer<'a>] {
+        self.0.get(1..).unwrap_or(&[])
+    }
+
+    /// Append `cert` to the end of the chain
+    pub fn push(&mut self, cert: CertificateDer<'a>) {
+        self.0.push(cert);
+    }
+
+    /// Yield the certificates in the chain, starting with the end-entity certificate
+    pub fn into_inner(self) -> Vec<CertificateDer<'a>> {
+        self.0
+    }
+
+    /// Yield a `'static` lifetime of the chain, copying only the certificates that are borrowed
+    pub fn into_owned(self) -> CertificateChainDer<'static> {
+        CertificateChainDer(crate::certs_into_owned(self.0))
+    }
+}
+
+impl<'a> Deref for CertificateChainDer<'a> {
+    type Target = [CertificateDer<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> From<Vec<CertificateDer<'a>>> for CertificateChainDer<'a> {
+    fn from(certs: Vec<CertificateDer<'a>>) -> Self {
+        Self(certs)
+    }
+}
+
+impl<'a> FromIterator<CertificateDer<'a>> for CertificateChainDer<'a> {
+    fn from_iter<I: IntoIterator<Item = CertificateDer<'a>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// An error decoding a chain with [`CertificateChainDer::from_x5c()`], or checking it with
+/// `CertificateChainDer::check_issuer_order()`
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum X5cError {
+    /// The array was empty
+    Empty,
+    /// An element was not valid base64
+    InvalidBase64 {
+        /// The index of the element
+        index: usize,
+    },
+    /// The structure of a certificate could not be decoded
+    #[cfg(feature = "x509")]
+    InvalidCertificate {
+        /// The index of the element
+        index: usize,
+        /// The reason the certificate could not be decoded
+        error: InvalidDer,
+    },
+    /// A certificate was not issued by the one following it
+    #[cfg(feature = "x509")]
+    NotLeafFirst {
+        /// The index of the element
+        index: usize,
+    },
+}
+
+impl fmt::Display for X5cError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("x5c array is empty"),
+            Self::InvalidBase64 { index } => write!(f, "element {index} is not valid base64"),
+            #[cfg(feature = "x509")]
+            Self::InvalidCertificate { index, error } => {
+                write!(f, "element {index} is not a valid certificate: {error}")
+            }
+            #[cfg(feature = "x509")]
+            Self::NotLeafFirst { index } => {
+                write!(f, "element {index} was not issued by element {}", index + 1)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for X5cError {}
+
+/// What [`CertificateChainDer::normalize_acme()`] changed about a certificate chain
+#[cfg(feature = "x509")]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainNormalization {
+    /// For each certificate in the normalized chain, its index in the input
+    pub order: Vec<usize>,
+    /// The index in the input of the self-issued certificate that was left out, if any
+    pub removed_root: Option<usize>,
+}
+
+#[cfg(feature = "x509")]
+impl ChainNormalization {
+    fn new(
+        certs: &[CertificateDer<'_>],
+        end_entity: &SubjectPublicKeyInfoDer<'_>,
+    ) -> Result<Self, ChainError> {
+        let spans = certs
+            .iter()
+            .enumerate()
+            .map(|(index, cert)| {
+                cert.spans()
+                    .map_err(|error| ChainError::InvalidCertificate { index, error })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let leaf = spans.first().ok_or(ChainError::Empty)?;
+        if leaf.subject_public_key_info() != *end_entity {
+            return Err(ChainError::EndEntityMismatch);
+        }
+
+        let mut order = alloc::vec![0];
+        let mut removed_root = None;
+        let (mut index, mut current) = (0, leaf);
+        while !current.is_self_issued() {
+            let candidates = (0..spans.len())
+                .filter(|i| !order.contains(i) && spans[*i].subject() == current.issuer())
+                .collect::<Vec<_>>();
+            let next = match candidates[..] {
+                [] => break,
+                [next] => next,
+                _ => return Err(ChainError::AmbiguousIssuer { index, candidates }),
+            };
+
+            if spans[next].is_self_issued() {
+                removed_root = Some(next);
+                break;
+            }
+
+            order.push(next);
+            (index, current) = (next, &spans[next]);
+        }
+
+        if let Some(index) =
+            (0..spans.len()).find(|i| !order.contains(i) && removed_root != Some(*i))
+        {
+            return Err(ChainError::Unchained { index });
+        }
+
+        Ok(Self {
+            order,
+            removed_root,
+        })
+    }
+
+    /// Whether the chain was already normalized
+    pub fn is_unchanged(&self) -> bool {
+        self.removed_root.is_none() && self.order.iter().enumerate().all(|(i, &j)| i == j)
+    }
+}
+
+/// An error normalizing a certificate chain
+#[cfg(feature = "x509")]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChainError {
+    /// The chain contained no certificates
+    Empty,
+    /// The structure of a certificate could not be decoded
+    InvalidCertificate {
+        /// The index of the certificate in the input
+        index: usize,
+        /// The reason the certificate could not be decoded
+        error: InvalidDer,
+    },
+    /// The first certificate did not have the expected public key
+    EndEntityMismatch,
+    /// Several certificates could have issued a certificate
+    AmbiguousIssuer {
+        /// The index in the input of the issued certificate
+        index: usize,
+        /// The indices in the input of the candidate issuers
+        candidates: Vec<usize>,
+    },
+    /// A certificate was not part of the chain starting from the end-entity certificate
+    Unchained {
+        /// The index of the certificate in the input
+        index: usize,
+    },
+}
+
+#[cfg(feature = "x509")]
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("certificate chain is empty"),
+            Self::InvalidCertificate { index, error } => {
+                write!(f, "certificate {index} could not be decoded: {error}")
+            }
+            Self::EndEntityMismatch => {
+                f.write_str("first certificate does not have the expected public key")
+            }
+            Self::AmbiguousIssuer { index, candidates } => write!(
+                f,
+                "certificate {index} could have been issued by any of certificates {candidates:?}"
+            ),
+            Self::Unchained { index } => write!(
+                f,
+                "certificate {index} is not part of the chain from the end-entity certificate"
+            ),
+        }
+    }
+}
+
+#[cfg(all(feature = "x509", feature = "std"))]
+impl std::error::Error for ChainError {}
@@ -0,0 +1,131 @@
+//! Generation of Rust source code for embedding root stores.
+//!
+//! Crates that ship a fixed set of trust anchors (like
+//! [webpki-roots](https://crates.io/crates/webpki-roots)) usually generate a Rust source file
+//! holding them as a `static` array, so that no parsing happens at runtime. [`emit_rust()`]
+//! produces such a file, in a format that is kept in sync with the types in this crate.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::{Der, TrustAnchor};
+
+/// Write Rust source code for a `static` slice containing `anchors`
+///
+/// The output looks like this, with the name, visibility and crate path taken from `options`:
+///
+/// ```text
+/// pub static ROOTS: &[rustls_pki_types::TrustAnchor<'static>] = &[
+///     rustls_pki_types::TrustAnchor::from_parts(
+///         b"...",
+///         b"...",
+///         None,
+///     ),
+/// ];
+/// ```
+///
+/// Anchors are built with [`TrustAnchor::from_parts()`], which is a `const fn`, so the output
+/// does not depend on the fields of [`TrustAnchor`] or how [`Der`] stores its contents.
+///
+/// Anchors are emitted sorted by their encoded contents, so the output does not depend on the
+/// order of `anchors`.
+///
+/// ```
+/// use rustls_pki_types::codegen::{emit_rust, EmitOptions};
+/// use rustls_pki_types::TrustAnchor;
+///
+/// let anchor = TrustAnchor::from_parts(&[0x30, 0x00], &[0x30, 0x00], None);
+///
+/// let mut out = String::new();
+/// let options = EmitOptions {
+///     name: "MY_ROOTS",
+///     visibility: "pub(crate)",
+///     ..EmitOptions::default()
+/// };
+/// emit_rust(&[anchor], &mut out, &options).unwrap();
+/// assert!(out.starts_with("pub(crate) static MY_ROOTS: "));
+/// ```
+pub fn emit_rust(
+    anchors: &[TrustAnchor<'_>],
+    writer: &mut impl Write,
+    options: &EmitOptions<'_>,
+) -> fmt::Result {
+    let mut sorted = anchors.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|ta| {
+        (
+            ta.subject.as_ref(),
+            ta.subject_public_key_info.as_ref(),
+            ta.name_constraints.as_ref().map(|nc| nc.as_ref()),
+        )
+    });
+
+    let krate = options.crate_path;
+    if !options.visibility.is_empty() {
+        write!(writer, "{} ", options.visibility)?;
+    }
+    writeln!(
+        writer,
+        "static {}: &[{krate}::TrustAnchor<'static>] = &[",
+        options.name
+    )?;
+
+    for anchor in sorted {
+        writeln!(writer, "    {krate}::TrustAnchor::from_parts(")?;
+        emit_bytes(writer, &anchor.subject)?;
+        emit_bytes(writer, &anchor.subject_public_key_info)?;
+        match &anchor.name_constraints {
+            Some(nc) => {
+                write!(writer, "        Some(")?;
+                emit_literal(writer, nc)?;
+                writeln!(writer, "),")?;
+            }
+            None => writeln!(writer, "        None,")?,
+        }
+        writeln!(writer, "    ),")?;
+    }
+
+    writeln!(writer, "];")
+}
+
+/// Options for [`emit_rust()`]
+#[derive(Clone, Debug)]
+pub struct EmitOptions<'a> {
+    /// Name of the generated `static`
+    pub name: &'a str,
+    /// Visibility of the generated `static`, like `pub` or `pub(crate)`; empty for private
+    pub visibility: &'a str,
+    /// Path to this crate as seen from the generated code
+    pub crate_path: &'a str,
+}
+
+impl Default for EmitOptions<'_> {
+    fn default() -> Self {
+        Self {
+            name: "ROOTS",
+            visibility: "pub",
+            crate_path: "rustls_pki_types",
+        }
+    }
+}
+
+/// Write `der` as an argument line holding a byte string literal
+fn emit_bytes(writer: &mut impl Write, der: &Der<'_>) -> fmt::Result {
+    write!(writer, "        ")?;
+    emit_literal(writer, der)?;
+    writeln!(writer, ",")
+}
+
+/// Write `der` as a byte string literal, wrapping long values
+fn emit_literal(writer: &mut impl Write, der: &Der<'_>) -> fmt::Result {
+    write!(writer, "b\"")?;
+    for (i, byte) in der.iter().enumerate() {
+        if i > 0 && i % BYTES_PER_LINE == 0 {
+            // A backslash before a newline skips the newline and the following indentation.
+            write!(writer, "\\\n          ")?;
+        }
+        write!(writer, "\\x{byte:02x}")?;
+    }
+    write!(writer, "\"")
+}
+
+const BYTES_PER_LINE: usize = 20;
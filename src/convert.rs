@@ -0,0 +1,511 @@
+//! Conversion of private keys between their DER formats, and to their public keys.
+//!
+//! These conversions only change the framing of a key: no cryptography is done, and the key
+//! itself is not checked.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::der::{self, InvalidDer, Reader};
+use crate::{
+    PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer,
+    SubjectPublicKeyInfoDer,
+};
+
+impl PrivatePkcs8KeyDer<'_> {
+    /// Wrap a PKCS#1 RSA key in a PKCS#8 `PrivateKeyInfo`
+    ///
+    /// The PKCS#1 key is included unchanged as the `privateKey`, with the `rsaEncryption`
+    /// algorithm, as by `openssl pkcs8 -topk8 -nocrypt`.
+    ///
+    /// ```
+    /// use rustls_pki_types::{PrivatePkcs1KeyDer, PrivatePkcs8KeyDer};
+    ///
+    /// let pkcs1 = PrivatePkcs1KeyDer::from(&[0x30, 0x00][..]);
+    /// let pkcs8 = PrivatePkcs8KeyDer::from_pkcs1(&pkcs1);
+    /// assert_eq!(
+    ///     pkcs8.secret_pkcs8_der(),
+    ///     &[
+    ///         0x30, 0x16, 0x02, 0x01, 0x00, 0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7,
+    ///         0x0d, 0x01, 0x01, 0x01, 0x05, 0x00, 0x04, 0x02, 0x30, 0x00,
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_pkcs1(key: &PrivatePkcs1KeyDer<'_>) -> PrivatePkcs8KeyDer<'static> {
+        PrivatePkcs8KeyDer::from(wrap_pkcs8(RSA_ALGORITHM, &[key.secret_pkcs1_der()]))
+    }
+
+    /// Wrap a SEC1 EC key in a PKCS#8 `PrivateKeyInfo`
+    ///
+    /// The key must name its curve in its `parameters`, which are moved into the
+    /// `id-ecPublicKey` algorithm: the output is the same as that of
+    /// `openssl pkcs8 -topk8 -nocrypt`. Keys with explicit curve parameters are not supported.
+    ///
+    /// ```
+    /// use rustls_pki_types::{KeyConversionError, PrivatePkcs8KeyDer, PrivateSec1KeyDer};
+    ///
+    /// // version 1, a one-byte private key, and the P-256 curve
+    /// let sec1 = PrivateSec1KeyDer::from(&[
+    ///     0x30, 0x12, 0x02, 0x01, 0x01, 0x04, 0x01, 0x01, 0xa0, 0x0a, 0x06, 0x08, 0x2a, 0x86,
+    ///     0x48, 0xce, 0x3d, 0x03, 0x01, 0x07,
+    /// ][..]);
+    /// let pkcs8 = PrivatePkcs8KeyDer::from_sec1(&sec1).unwrap();
+    /// assert!(pkcs8
+    ///     .secret_pkcs8_der()
+    ///     .ends_with(&[0x04, 0x08, 0x30, 0x06, 0x02, 0x01, 0x01, 0x04, 0x01, 0x01]));
+    ///
+    /// // the same key, without its curve
+    /// let sec1 = PrivateSec1KeyDer::from(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x04, 0x01, 0x01][..]);
+    /// assert_eq!(
+    ///     PrivatePkcs8KeyDer::from_sec1(&sec1),
+    ///     Err(KeyConversionError::MissingCurve)
+    /// );
+    /// ```
+    pub fn from_sec1(
+        key: &PrivateSec1KeyDer<'_>,
+    ) -> Result<PrivatePkcs8KeyDer<'static>, KeyConversionError> {
+        sec1_to_pkcs8(key.secret_sec1_der()).map(PrivatePkcs8KeyDer::from)
+    }
+
+    /// Unwrap the PKCS#1 key from this `rsaEncryption` key
+    ///
+    /// This is the inverse of [`PrivatePkcs8KeyDer::from_pkcs1()`]. Keys with other algorithms
+    /// yield [`KeyConversionError::UnsupportedAlgorithm`].
+    ///
+    /// ```
+    /// use rustls_pki_types::{KeyConversionError, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer};
+    ///
+    /// let pkcs1 = PrivatePkcs1KeyDer::from(&[0x30, 0x00][..]);
+    /// let pkcs8 = PrivatePkcs8KeyDer::from_pkcs1(&pkcs1);
+    /// assert_eq!(pkcs8.try_into_pkcs1().unwrap(), pkcs1);
+    ///
+    /// // an Ed25519 key
+    /// let mut der = vec![
+    ///     0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22,
+    ///     0x04, 0x20,
+    /// ];
+    /// der.extend_from_slice(&[0x42; 32]);
+    /// assert_eq!(
+    ///     PrivatePkcs8KeyDer::from(der).try_into_pkcs1(),
+    ///     Err(KeyConversionError::UnsupportedAlgorithm(vec![0x2b, 0x65, 0x70]))
+    /// );
+    /// ```
+    pub fn try_into_pkcs1(&self) -> Result<PrivatePkcs1KeyDer<'static>, KeyConversionError> {
+        let info = PrivateKeyInfo::new(self.secret_pkcs8_der())?;
+        if info.algorithm != der::RSA_ENCRYPTION {
+            return Err(KeyConversionError::UnsupportedAlgorithm(
+                info.algorithm.to_vec(),
+            ));
+        }
+
+        der::expect_single(info.private_key, der::SEQUENCE)?;
+        Ok(PrivatePkcs1KeyDer::from(info.private_key.to_vec()))
+    }
+
+    /// Unwrap the SEC1 key from this `id-ecPublicKey` key
+    ///
+    /// This is the inverse of [`PrivatePkcs8KeyDer::from_sec1()`]: if the SEC1 key does not
+    /// include its curve `parameters`, they are copied from the algorithm, as by
+    /// `openssl ec -outform der`. Keys with other algorithms yield
+    /// [`KeyConversionError::UnsupportedAlgorithm`].
+    ///
+    /// ```
+    /// use rustls_pki_types::{PrivatePkcs8KeyDer, PrivateSec1KeyDer};
+    ///
+    /// // version 1, a one-byte private key, and the P-256 curve
+    /// let sec1 = PrivateSec1KeyDer::from(&[
+    ///     0x30, 0x12, 0x02, 0x01, 0x01, 0x04, 0x01, 0x01, 0xa0, 0x0a, 0x06, 0x08, 0x2a, 0x86,
+    ///     0x48, 0xce, 0x3d, 0x03, 0x01, 0x07,
+    /// ][..]);
+    /// let pkcs8 = PrivatePkcs8KeyDer::from_sec1(&sec1).unwrap();
+    /// assert_eq!(pkcs8.try_into_sec1().unwrap(), sec1);
+    /// ```
+    pub fn try_into_sec1(&self) -> Result<PrivateSec1KeyDer<'static>, KeyConversionError> {
+        let info = PrivateKeyInfo::new(self.secret_pkcs8_der())?;
+        if info.algorithm != der::EC_PUBLIC_KEY {
+            return Err(KeyConversionError::UnsupportedAlgorithm(
+                info.algorithm.to_vec(),
+            ));
+        }
+
+        let mut sec1 = Reader::new(der::expect_single(info.private_key, der::SEQUENCE)?);
+        let version = sec1.read_element(der::INTEGER)?;
+        let private_key = sec1.read_element(der::OCTET_STRING)?;
+        if sec1.peek_tag() == Some(der::context_constructed(0)) {
+            return Ok(PrivateSec1KeyDer::from(info.private_key.to_vec()));
+        }
+
+        let parameters = match info.parameters {
+            Some(parameters) => parameters,
+            None => return Err(KeyConversionError::MissingCurve),
+        };
+        let public_key = match sec1.peek_tag() {
+            Some(_) => sec1.read_element(der::context_constructed(1))?.encoded,
+            None => &[],
+        };
+        sec1.finish()?;
+
+        let len = version.encoded.len()
+            + private_key.encoded.len()
+            + der::header_len(parameters.len())
+            + parameters.len()
+            + public_key.len();
+        let mut out = Vec::with_capacity(der::header_len(len) + len);
+        der::write_header(&mut out, der::SEQUENCE, len);
+        out.extend_from_slice(version.encoded);
+        out.extend_from_slice(private_key.encoded);
+        der::write_header(&mut out, der::context_constructed(0), parameters.len());
+        out.extend_from_slice(parameters);
+        out.extend_from_slice(public_key);
+        Ok(PrivateSec1KeyDer::from(out))
+    }
+
+    /// The version of this key's encoding
+    ///
+    /// Version 2 keys (`OneAsymmetricKey`, RFC 5958) may include their public key, which some
+    /// libraries do not accept; see [`PrivatePkcs8KeyDer::strip_public_key()`].
+    pub fn version(&self) -> Result<Pkcs8Version, InvalidDer> {
+        Ok(PrivateKeyInfo::new(self.secret_pkcs8_der())?.version)
+    }
+
+    /// Whether this key includes its public key, as the `publicKey` of a version 2 key
+    ///
+    /// EC keys usually include their public key inside the SEC1 key instead: that is not
+    /// counted here.
+    pub fn has_public_key(&self) -> Result<bool, InvalidDer> {
+        Ok(PrivateKeyInfo::new(self.secret_pkcs8_der())?
+            .public_key
+            .is_some())
+    }
+
+    /// Encode this key as version 1, without its `publicKey` or `attributes`
+    ///
+    /// The algorithm and the private key are copied unchanged.
+    ///
+    /// ```
+    /// use rustls_pki_types::{Pkcs8Version, PrivatePkcs8KeyDer};
+    ///
+    /// // a version 2 Ed25519 key, with its public key
+    /// let mut der = vec![
+    ///     0x30, 0x51, 0x02, 0x01, 0x01, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22,
+    ///     0x04, 0x20,
+    /// ];
+    /// der.extend_from_slice(&[0x42; 32]);
+    /// der.extend_from_slice(&[0x81, 0x21, 0x00]);
+    /// der.extend_from_slice(&[0x17; 32]);
+    /// let key = PrivatePkcs8KeyDer::from(der);
+    /// assert_eq!(key.version(), Ok(Pkcs8Version::V2));
+    /// assert_eq!(key.has_public_key(), Ok(true));
+    ///
+    /// let stripped = key.strip_public_key().unwrap();
+    /// assert_eq!(stripped.version(), Ok(Pkcs8Version::V1));
+    /// assert_eq!(stripped.has_public_key(), Ok(false));
+    /// assert_eq!(stripped.secret_pkcs8_der().len(), 48);
+    /// ```
+    pub fn strip_public_key(&self) -> Result<PrivatePkcs8KeyDer<'static>, InvalidDer> {
+        let info = PrivateKeyInfo::new(self.secret_pkcs8_der())?;
+        Ok(PrivatePkcs8KeyDer::from(wrap_pkcs8(
+            info.algorithm_identifier,
+            &[info.private_key],
+        )))
+    }
+
+    /// The `SubjectPublicKeyInfo` of the public key for this key, without any cryptography
+    ///
+    /// This is the `publicKey` of a version 2 key (a `OneAsymmetricKey`, RFC 5958) if it
+    /// has one. Otherwise, for RSA keys it is made from the modulus and public exponent in the
+    /// key, and for EC keys from the `publicKey` in the SEC1 key, which OpenSSL includes.
+    /// Other keys, like Ed25519 keys from `openssl genpkey`, yield
+    /// [`KeyConversionError::PublicKeyNotPresent`]: their public keys can only be computed.
+    ///
+    /// ```
+    /// use rustls_pki_types::{KeyConversionError, PrivatePkcs8KeyDer};
+    ///
+    /// // an Ed25519 key, without its public key
+    /// let mut der = vec![
+    ///     0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22,
+    ///     0x04, 0x20,
+    /// ];
+    /// der.extend_from_slice(&[0x42; 32]);
+    /// let key = PrivatePkcs8KeyDer::from(der.clone());
+    /// assert_eq!(
+    ///     key.subject_public_key_info(),
+    ///     Err(KeyConversionError::PublicKeyNotPresent)
+    /// );
+    ///
+    /// // the same key as version 2, with its public key
+    /// der[1] += 35;
+    /// der[4] = 1;
+    /// der.extend_from_slice(&[0x81, 0x21, 0x00]);
+    /// der.extend_from_slice(&[0x17; 32]);
+    /// let spki = PrivatePkcs8KeyDer::from(der).subject_public_key_info().unwrap();
+    /// assert!(spki.starts_with(&[
+    ///     0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00, 0x17,
+    /// ]));
+    /// ```
+    pub fn subject_public_key_info(
+        &self,
+    ) -> Result<SubjectPublicKeyInfoDer<'static>, KeyConversionError> {
+        let info = PrivateKeyInfo::new(self.secret_pkcs8_der())?;
+        if let Some(public_key) = info.public_key {
+            return Ok(encode_spki(info.algorithm_identifier, &[public_key]));
+        }
+
+        match info.algorithm {
+            der::RSA_ENCRYPTION => rsa_spki(info.algorithm_identifier, info.private_key),
+            der::EC_PUBLIC_KEY => sec1_spki(
+                info.parameters.map(|_| info.algorithm_identifier),
+                info.private_key,
+            ),
+            _ => Err(KeyConversionError::PublicKeyNotPresent),
+        }
+    }
+}
+
+impl PrivateKeyDer<'_> {
+    /// The `SubjectPublicKeyInfo` of the public key for this key, without any cryptography
+    ///
+    /// PKCS#1 keys always include their public key, and SEC1 keys usually do. For PKCS#8 keys,
+    /// see [`PrivatePkcs8KeyDer::subject_public_key_info()`].
+    pub fn subject_public_key_info(
+        &self,
+    ) -> Result<SubjectPublicKeyInfoDer<'static>, KeyConversionError> {
+        match self {
+            Self::Pkcs1(key) => rsa_spki(RSA_ALGORITHM, key.secret_pkcs1_der()),
+            Self::Sec1(key) => sec1_spki(None, key.secret_sec1_der()),
+            Self::Pkcs8(key) => key.subject_public_key_info(),
+        }
+    }
+}
+
+/// The `SubjectPublicKeyInfo` for a PKCS#1 `RSAPrivateKey`
+///
+/// `algorithm` is the contents of the `AlgorithmIdentifier` to use.
+fn rsa_spki(
+    algorithm: &[u8],
+    pkcs1: &[u8],
+) -> Result<SubjectPublicKeyInfoDer<'static>, KeyConversionError> {
+    let mut rsa = Reader::new(der::expect_single(pkcs1, der::SEQUENCE)?);
+    let _version = rsa.read(der::INTEGER)?;
+    let modulus = rsa.read_element(der::INTEGER)?.encoded;
+    let exponent = rsa.read_element(der::INTEGER)?.encoded;
+
+    let len = modulus.len() + exponent.len();
+    let mut header = Vec::with_capacity(1 + der::header_len(len));
+    header.push(0);
+    der::write_header(&mut header, der::SEQUENCE, len);
+    Ok(encode_spki(algorithm, &[&header, modulus, exponent]))
+}
+
+/// The `SubjectPublicKeyInfo` for a SEC1 `ECPrivateKey`, from its `publicKey`
+///
+/// `algorithm` is the contents of the `AlgorithmIdentifier` to use, if known; otherwise it is
+/// made from the curve in the key's `parameters`.
+fn sec1_spki(
+    algorithm: Option<&[u8]>,
+    sec1: &[u8],
+) -> Result<SubjectPublicKeyInfoDer<'static>, KeyConversionError> {
+    let mut sec1 = Reader::new(der::expect_single(sec1, der::SEQUENCE)?);
+    let _version = sec1.read(der::INTEGER)?;
+    let _private_key = sec1.read(der::OCTET_STRING)?;
+    let curve = sec1.read_optional(der::context_constructed(0))?;
+    let public_key = match sec1.read_optional(der::context_constructed(1))? {
+        Some(public_key) => der::expect_single(public_key, der::BIT_STRING)?,
+        None => return Err(KeyConversionError::PublicKeyNotPresent),
+    };
+
+    match (algorithm, curve) {
+        (Some(algorithm), _) => Ok(encode_spki(algorithm, &[public_key])),
+        (None, Some(curve)) => {
+            let mut algorithm = Vec::with_capacity(EC_PUBLIC_KEY.len() + curve.len());
+            algorithm.extend_from_slice(EC_PUBLIC_KEY);
+            algorithm.extend_from_slice(curve);
+            Ok(encode_spki(&algorithm, &[public_key]))
+        }
+        (None, None) => Err(KeyConversionError::MissingCurve),
+    }
+}
+
+/// Encode a `SubjectPublicKeyInfo` with the concatenation of `public_key` as its `BIT STRING`
+///
+/// `algorithm` is the contents of the `AlgorithmIdentifier`, and `public_key` the contents of
+/// the `BIT STRING`, starting with the number of unused bits.
+fn encode_spki(algorithm: &[u8], public_key: &[&[u8]]) -> SubjectPublicKeyInfoDer<'static> {
+    let key_len = public_key.iter().map(|part| part.len()).sum();
+    let len =
+        der::header_len(algorithm.len()) + algorithm.len() + der::header_len(key_len) + key_len;
+
+    let mut out = Vec::with_capacity(der::header_len(len) + len);
+    der::write_header(&mut out, der::SEQUENCE, len);
+    der::write_header(&mut out, der::SEQUENCE, algorithm.len());
+    out.extend_from_slice(algorithm);
+    der::write_header(&mut out, der::BIT_STRING, key_len);
+    for part in public_key {
+        out.extend_from_slice(part);
+    }
+    SubjectPublicKeyInfoDer::from(out)
+}
+
+/// The parts of a PKCS#8 `PrivateKeyInfo` needed to unwrap or classify its key
+pub(crate) struct PrivateKeyInfo<'a> {
+    pub(crate) version: Pkcs8Version,
+    /// The contents of the `AlgorithmIdentifier`
+    pub(crate) algorithm_identifier: &'a [u8],
+    /// The contents of the algorithm's `OBJECT IDENTIFIER`
+    pub(crate) algorithm: &'a [u8],
+    /// The complete encoding of the algorithm's parameters, if any
+    pub(crate) parameters: Option<&'a [u8]>,
+    /// The contents of the `privateKey`
+    pub(crate) private_key: &'a [u8],
+    /// The contents of the `publicKey` `BIT STRING` of a version 2 key, if any
+    pub(crate) public_key: Option<&'a [u8]>,
+}
+
+impl<'a> PrivateKeyInfo<'a> {
+    pub(crate) fn new(der: &'a [u8]) -> Result<Self, InvalidDer> {
+        let mut pkcs8 = Reader::new(der::expect_single(der, der::SEQUENCE)?);
+        let version = match pkcs8.read(der::INTEGER)? {
+            [0] => Pkcs8Version::V1,
+            [1] => Pkcs8Version::V2,
+            _ => return Err(InvalidDer::InvalidValue),
+        };
+
+        let algorithm_identifier = pkcs8.read(der::SEQUENCE)?;
+        let mut algorithm = Reader::new(algorithm_identifier);
+        let oid = algorithm.read(der::OBJECT_IDENTIFIER)?;
+        let parameters = match algorithm.peek_tag() {
+            Some(_) => Some(algorithm.read_any()?.encoded),
+            None => None,
+        };
+        algorithm.finish()?;
+
+        let private_key = pkcs8.read(der::OCTET_STRING)?;
+        let _attributes = pkcs8.read_optional(der::context_constructed(0))?;
+        let public_key = pkcs8.read_optional(der::context_primitive(1))?;
+        pkcs8.finish()?;
+
+        Ok(Self {
+            version,
+            algorithm_identifier,
+            algorithm: oid,
+            parameters,
+            private_key,
+            public_key,
+        })
+    }
+}
+
+/// Convert a SEC1 `ECPrivateKey` into a PKCS#8 `PrivateKeyInfo`
+pub(crate) fn sec1_to_pkcs8(key: &[u8]) -> Result<Vec<u8>, KeyConversionError> {
+    let mut sec1 = Reader::new(der::expect_single(key, der::SEQUENCE)?);
+    let version = sec1.read_element(der::INTEGER)?;
+    let private_key = sec1.read_element(der::OCTET_STRING)?;
+    let parameters = match sec1.read_optional(der::context_constructed(0))? {
+        Some(parameters) => parameters,
+        None => return Err(KeyConversionError::MissingCurve),
+    };
+    let public_key = match sec1.peek_tag() {
+        Some(_) => sec1.read_element(der::context_constructed(1))?.encoded,
+        None => &[],
+    };
+    sec1.finish()?;
+
+    let mut parameters = Reader::new(parameters);
+    let curve = parameters.read_element(der::OBJECT_IDENTIFIER)?;
+    parameters.finish()?;
+
+    let mut algorithm = Vec::with_capacity(EC_PUBLIC_KEY.len() + curve.encoded.len());
+    algorithm.extend_from_slice(EC_PUBLIC_KEY);
+    algorithm.extend_from_slice(curve.encoded);
+
+    // The curve is now named by the algorithm, so is left out of the key as by OpenSSL.
+    let len = version.encoded.len() + private_key.encoded.len() + public_key.len();
+    let mut header = Vec::with_capacity(der::header_len(len));
+    der::write_header(&mut header, der::SEQUENCE, len);
+    Ok(wrap_pkcs8(
+        &algorithm,
+        &[&header, version.encoded, private_key.encoded, public_key],
+    ))
+}
+
+/// Encode a version 0 `PrivateKeyInfo` holding the concatenation of `key`
+///
+/// `algorithm` is the contents of the `AlgorithmIdentifier`. The output is allocated with its
+/// exact length, so that no partial copies of the key are left behind by reallocation.
+pub(crate) fn wrap_pkcs8(algorithm: &[u8], key: &[&[u8]]) -> Vec<u8> {
+    let key_len = key.iter().map(|part| part.len()).sum();
+    let len = VERSION.len()
+        + der::header_len(algorithm.len())
+        + algorithm.len()
+        + der::header_len(key_len)
+        + key_len;
+
+    let mut out = Vec::with_capacity(der::header_len(len) + len);
+    der::write_header(&mut out, der::SEQUENCE, len);
+    out.extend_from_slice(VERSION);
+    der::write_header(&mut out, der::SEQUENCE, algorithm.len());
+    out.extend_from_slice(algorithm);
+    der::write_header(&mut out, der::OCTET_STRING, key_len);
+    for part in key {
+        out.extend_from_slice(part);
+    }
+    out
+}
+
+/// The version of a PKCS#8 key, from [`PrivatePkcs8KeyDer::version()`]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pkcs8Version {
+    /// A version 1 `PrivateKeyInfo`, as specified in RFC 5208
+    V1,
+    /// A version 2 `OneAsymmetricKey`, as specified in RFC 5958
+    V2,
+}
+
+/// An error converting a private key between formats
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyConversionError {
+    /// The structure of the key could not be decoded
+    InvalidKey(InvalidDer),
+    /// A SEC1 key did not name its curve
+    MissingCurve,
+    /// The key's algorithm is not supported by the conversion
+    ///
+    /// This holds the contents of the algorithm's `OBJECT IDENTIFIER`.
+    UnsupportedAlgorithm(Vec<u8>),
+    /// The key does not include its public key, which would have to be computed
+    PublicKeyNotPresent,
+}
+
+impl From<InvalidDer> for KeyConversionError {
+    fn from(err: InvalidDer) -> Self {
+        Self::InvalidKey(err)
+    }
+}
+
+impl fmt::Display for KeyConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey(err) => write!(f, "invalid private key: {err}"),
+            Self::MissingCurve => f.write_str("EC private key does not name its curve"),
+            Self::UnsupportedAlgorithm(oid) => {
+                f.write_str("cannot convert private key with algorithm ")?;
+                der::write_oid(f, oid)
+            }
+            Self::PublicKeyNotPresent => f.write_str("private key does not include its public key"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyConversionError {}
+
+/// The encoded `version` of a `PrivateKeyInfo`: `INTEGER 0`
+const VERSION: &[u8] = &[der::INTEGER, 1, 0];
+/// The contents of the `AlgorithmIdentifier` for RSA keys: `rsaEncryption` with `NULL` parameters
+pub(crate) const RSA_ALGORITHM: &[u8] = &[
+    0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01, 0x05, 0x00,
+];
+/// The encoded `id-ecPublicKey` object identifier, 1.2.840.10045.2.1
+const EC_PUBLIC_KEY: &[u8] = &[0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
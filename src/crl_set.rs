@@ -0,0 +1,125 @@
+//! Revocation lists from several issuers, indexed by issuer name.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::{CertificateRevocationListDer, InvalidDer};
+
+/// A collection of certificate revocation lists from several issuers, indexed by issuer
+///
+/// Verifiers can use this to find the CRLs relevant to a certificate from its issuer name,
+/// as returned by [`CertificateSpans::issuer()`](crate::CertificateSpans::issuer). Several CRLs
+/// from the same issuer may be held at once; [`CrlSet::freshest_for_issuer()`] picks the most
+/// recently issued one.
+#[derive(Clone, Debug, Default)]
+pub struct CrlSet<'a> {
+    /// For each issuer name, its CRLs in order of increasing `thisUpdate`
+    by_issuer: BTreeMap<Vec<u8>, Vec<Entry<'a>>>,
+}
+
+impl<'a> CrlSet<'a> {
+    /// Make an empty `CrlSet`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make a `CrlSet` from the given CRLs
+    ///
+    /// CRLs whose structure cannot be decoded are left out of the set, and reported
+    /// alongside their position in `crls`, so that one malformed CRL does not prevent
+    /// using the others.
+    pub fn from_crls(
+        crls: impl IntoIterator<Item = CertificateRevocationListDer<'a>>,
+    ) -> (Self, Vec<(usize, InvalidDer)>) {
+        let mut set = Self::new();
+        let mut errors = Vec::new();
+        for (i, crl) in crls.into_iter().enumerate() {
+            if let Err(err) = set.insert(crl) {
+                errors.push((i, err));
+            }
+        }
+
+        (set, errors)
+    }
+
+    /// Add `crl` to the set, next to any other CRLs from the same issuer
+    ///
+    /// Adding a CRL that is already present has no effect.
+    pub fn insert(&mut self, crl: CertificateRevocationListDer<'a>) -> Result<(), InvalidDer> {
+        let (issuer, entry) = Entry::new(crl)?;
+        let entries = self.by_issuer.entry(issuer).or_default();
+        if entries.iter().any(|e| e.crl == entry.crl) {
+            return Ok(());
+        }
+
+        let pos = entries.partition_point(|e| e.this_update <= entry.this_update);
+        entries.insert(pos, entry);
+        Ok(())
+    }
+
+    /// Add `crl` to the set, removing all other CRLs from the same issuer
+    ///
+    /// This is intended for periodic refreshes. The removed CRLs are returned.
+    pub fn replace(
+        &mut self,
+        crl: CertificateRevocationListDer<'a>,
+    ) -> Result<Vec<CertificateRevocationListDer<'a>>, InvalidDer> {
+        let (issuer, entry) = Entry::new(crl)?;
+        let old = self.by_issuer.insert(issuer, alloc::vec![entry]);
+        Ok(old.unwrap_or_default().into_iter().map(|e| e.crl).collect())
+    }
+
+    /// Iterate over the CRLs issued by `issuer`, from oldest to most recent
+    ///
+    /// `issuer` is an encoded `Name`, without the outer `SEQUENCE` tag and length.
+    pub fn find_by_issuer(
+        &self,
+        issuer: &[u8],
+    ) -> impl Iterator<Item = &CertificateRevocationListDer<'a>> + '_ {
+        self.by_issuer
+            .get(issuer)
+            .into_iter()
+            .flatten()
+            .map(|e| &e.crl)
+    }
+
+    /// The CRL issued by `issuer` with the latest `thisUpdate`, if any
+    ///
+    /// `issuer` is an encoded `Name`, without the outer `SEQUENCE` tag and length.
+    pub fn freshest_for_issuer(&self, issuer: &[u8]) -> Option<&CertificateRevocationListDer<'a>> {
+        self.by_issuer
+            .get(issuer)
+            .and_then(|entries| entries.last())
+            .map(|e| &e.crl)
+    }
+
+    /// Iterate over all CRLs in the set, grouped by issuer
+    pub fn iter(&self) -> impl Iterator<Item = &CertificateRevocationListDer<'a>> + '_ {
+        self.by_issuer.values().flatten().map(|e| &e.crl)
+    }
+
+    /// The number of CRLs in the set
+    pub fn len(&self) -> usize {
+        self.by_issuer.values().map(Vec::len).sum()
+    }
+
+    /// Whether the set contains no CRLs
+    pub fn is_empty(&self) -> bool {
+        self.by_issuer.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Entry<'a> {
+    this_update: u64,
+    crl: CertificateRevocationListDer<'a>,
+}
+
+impl<'a> Entry<'a> {
+    fn new(crl: CertificateRevocationListDer<'a>) -> Result<(Vec<u8>, Self), InvalidDer> {
+        let spans = crl.spans()?;
+        let issuer = spans.issuer().to_vec();
+        let this_update = spans.this_update().as_secs();
+        Ok((issuer, Self { this_update, crl }))
+    }
+}
@@ -0,0 +1,695 @@
+//! Minimal DER decoding, sufficient to locate elements inside encoded objects.
+//!
+//! This is not a general-purpose ASN.1 parser: it only supports single-octet tags and
+//! definite-length encodings, which covers everything found in X.509 certificates, CRLs
+//! and keys. Most of it is internal to this crate; the public functions help with framing
+//! DER elements received over a stream.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "x509")]
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "x509")]
+use crate::UnixTime;
+#[cfg(feature = "std")]
+use crate::{CertificateDer, CertificateRevocationListDer};
+
+/// A reader over a sequence of DER-encoded elements
+#[derive(Clone, Debug)]
+pub(crate) struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    /// Read the next element, whatever its tag
+    pub(crate) fn read_any(&mut self) -> Result<Element<'a>, InvalidDer> {
+        let (tag, header_len, contents_len) = read_header(self.input)?;
+        let total = header_len
+            .checked_add(contents_len)
+            .ok_or(InvalidDer::InvalidLength)?;
+        if total > self.input.len() {
+            return Err(InvalidDer::Truncated);
+        }
+
+        let (full, rest) = self.input.split_at(total);
+        self.input = rest;
+        Ok(Element {
+            tag,
+            contents: &full[header_len..],
+            encoded: full,
+        })
+    }
+
+    /// Read the next element, which must have the given `tag`, and yield its contents
+    pub(crate) fn read(&mut self, tag: u8) -> Result<&'a [u8], InvalidDer> {
+        Ok(self.read_element(tag)?.contents)
+    }
+
+    /// Read the next element, which must have the given `tag`
+    pub(crate) fn read_element(&mut self, tag: u8) -> Result<Element<'a>, InvalidDer> {
+        let element = self.read_any()?;
+        match element.tag == tag {
+            true => Ok(element),
+            false => Err(InvalidDer::UnexpectedTag {
+                expected: tag,
+                found: element.tag,
+            }),
+        }
+    }
+
+    /// Read the next element if it has the given `tag`, and yield its contents
+    pub(crate) fn read_optional(&mut self, tag: u8) -> Result<Option<&'a [u8]>, InvalidDer> {
+        match self.peek_tag() == Some(tag) {
+            true => self.read(tag).map(Some),
+            false => Ok(None),
+        }
+    }
+
+    /// Read the next element, which must be a `UTCTime` or `GeneralizedTime`
+    #[cfg(feature = "x509")]
+    pub(crate) fn read_time(&mut self) -> Result<UnixTime, InvalidDer> {
+        let element = self.read_any()?;
+        let (year, rest) = match element.tag {
+            UTC_TIME => {
+                let (yy, rest) = split_digits(element.contents, 2)?;
+                // RFC 5280 section 4.1.2.5.1: years 50-99 are in the 20th century.
+                (if yy >= 50 { 1900 + yy } else { 2000 + yy }, rest)
+            }
+            GENERALIZED_TIME => split_digits(element.contents, 4)?,
+            found => {
+                return Err(InvalidDer::UnexpectedTag {
+                    expected: UTC_TIME,
+                    found,
+                })
+            }
+        };
+
+        let (month, rest) = split_digits(rest, 2)?;
+        let (day, rest) = split_digits(rest, 2)?;
+        let (hours, rest) = split_digits(rest, 2)?;
+        let (minutes, rest) = split_digits(rest, 2)?;
+        let (seconds, rest) = split_digits(rest, 2)?;
+        if rest != b"Z"
+            || year < 1970
+            || !(1..=12).contains(&month)
+            || day < 1
+            || day > days_in_month(year, month)
+            || hours > 23
+            || minutes > 59
+            || seconds > 59
+        {
+            return Err(InvalidDer::InvalidValue);
+        }
+
+        let days = days_since_epoch(year, month, day);
+        let secs = days * 86_400 + hours * 3_600 + minutes * 60 + seconds;
+        Ok(UnixTime::since_unix_epoch(Duration::from_secs(secs)))
+    }
+
+    /// Yield the tag of the next element, without consuming it
+    pub(crate) fn peek_tag(&self) -> Option<u8> {
+        self.input.first().copied()
+    }
+
+    /// Check that all input has been consumed
+    pub(crate) fn finish(&self) -> Result<(), InvalidDer> {
+        match self.input.is_empty() {
+            true => Ok(()),
+            false => Err(InvalidDer::TrailingData),
+        }
+    }
+}
+
+/// A single DER-encoded element
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Element<'a> {
+    pub(crate) tag: u8,
+    /// The contents octets, without the tag and length
+    pub(crate) contents: &'a [u8],
+    /// The complete encoding, including the tag and length
+    pub(crate) encoded: &'a [u8],
+}
+
+/// Parse `input` as exactly one element with the given `tag`, and yield its contents
+pub(crate) fn expect_single(input: &[u8], tag: u8) -> Result<&[u8], InvalidDer> {
+    let mut reader = Reader::new(input);
+    let contents = reader.read(tag)?;
+    reader.finish()?;
+    Ok(contents)
+}
+
+/// Check that `input` is a single element with the given `tag`, without decoding its contents
+///
+/// Unlike [`expect_single()`], a different tag is reported as such even if the length octets
+/// after it are malformed or do not match the input, and PEM text is reported as
+/// [`InvalidDer::LooksLikePem`].
+pub(crate) fn check_framing(input: &[u8], tag: u8) -> Result<(), InvalidDer> {
+    match split_framed(input, tag)? {
+        (_, []) => Ok(()),
+        _ => Err(InvalidDer::TrailingData),
+    }
+}
+
+/// Split the element with the given `tag` at the start of `input` from the input after it
+///
+/// The element is checked as for [`check_framing()`], and its complete encoding is yielded.
+pub(crate) fn split_framed(input: &[u8], tag: u8) -> Result<(&[u8], &[u8]), InvalidDer> {
+    match input.first() {
+        Some(_) if looks_like_pem(input) => Err(InvalidDer::LooksLikePem),
+        Some(&found) if found != tag => Err(InvalidDer::UnexpectedTag {
+            expected: tag,
+            found,
+        }),
+        _ => {
+            let mut reader = Reader::new(input);
+            let element = reader.read_element(tag)?;
+            Ok((element.encoded, reader.input))
+        }
+    }
+}
+
+/// As for [`check_framing()`], but first check that `input` is at most `max` bytes long
+pub(crate) fn check_framing_capped(input: &[u8], tag: u8, max: usize) -> Result<(), InvalidDer> {
+    match input.len() > max {
+        true => Err(InvalidDer::TooLong {
+            len: input.len(),
+            max,
+        }),
+        false => check_framing(input, tag),
+    }
+}
+
+/// Whether `input` starts with a PEM `BEGIN` line, after any byte order mark and whitespace
+fn looks_like_pem(input: &[u8]) -> bool {
+    let input = input.strip_prefix(b"\xef\xbb\xbf").unwrap_or(input);
+    let start = input
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    input[start..].starts_with(b"-----BEGIN")
+}
+
+/// Decode the tag and length octets at the start of `input`
+///
+/// Yields the tag, the length of the header (tag and length octets) and the length of the
+/// contents. The contents themselves are not required to be present.
+pub(crate) fn read_header(input: &[u8]) -> Result<(u8, usize, usize), InvalidDer> {
+    let (&tag, rest) = input.split_first().ok_or(InvalidDer::Truncated)?;
+    if tag & 0x1f == 0x1f {
+        // High tag number form, which is not used by anything we decode.
+        return Err(InvalidDer::UnsupportedTag(tag));
+    }
+
+    let (&first, rest) = rest.split_first().ok_or(InvalidDer::Truncated)?;
+    if first < 0x80 {
+        return Ok((tag, 2, usize::from(first)));
+    }
+
+    // 0x80 is the indefinite length form, which DER forbids. We support up to four
+    // length octets, which is more than enough for anything we expect to handle.
+    let octets = usize::from(first & 0x7f);
+    if octets == 0 || octets > 4 {
+        return Err(InvalidDer::InvalidLength);
+    }
+
+    let bytes = rest.get(..octets).ok_or(InvalidDer::Truncated)?;
+    if bytes[0] == 0 {
+        // Not minimally encoded.
+        return Err(InvalidDer::InvalidLength);
+    }
+
+    let len = bytes
+        .iter()
+        .fold(0usize, |acc, &b| (acc << 8) | usize::from(b));
+    if len < 0x80 {
+        // Should have used the short form.
+        return Err(InvalidDer::InvalidLength);
+    }
+
+    Ok((tag, 2 + octets, len))
+}
+
+/// The total length of the DER element starting with `prefix`, if it can be determined
+///
+/// This looks at the tag and length octets only, so can be used to find out how much input to
+/// wait for before a complete element is available. Yields `None` if `prefix` ends before the
+/// length octets do. Indefinite lengths, which DER does not allow, are rejected.
+pub fn peek_len(prefix: &[u8]) -> Result<Option<usize>, InvalidDer> {
+    match read_header(prefix) {
+        Ok((_, header_len, len)) => match header_len.checked_add(len) {
+            Some(total) => Ok(Some(total)),
+            None => Err(InvalidDer::InvalidLength),
+        },
+        Err(InvalidDer::Truncated) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Read exactly one DER element from `r`
+///
+/// No more is read from `r` than the element, so further elements can be read afterwards.
+/// Fails with [`ReadDerError::TooLarge`] before reading the contents if the element, including
+/// its tag and length octets, is longer than `max_len`. Only the framing of the element is
+/// checked, not its contents.
+#[cfg(feature = "std")]
+pub fn read_one_der(mut r: impl io::Read, max_len: usize) -> Result<Vec<u8>, ReadDerError> {
+    let mut der = Vec::with_capacity(6);
+    let len = loop {
+        if let Some(len) = peek_len(&der)? {
+            break len;
+        }
+
+        let mut byte = [0; 1];
+        r.read_exact(&mut byte)?;
+        der.push(byte[0]);
+    };
+
+    if len > max_len {
+        return Err(ReadDerError::TooLarge { len, max_len });
+    }
+
+    let header_len = der.len();
+    der.resize(len, 0);
+    r.read_exact(&mut der[header_len..])?;
+    Ok(der)
+}
+
+#[cfg(feature = "std")]
+impl CertificateDer<'static> {
+    /// Read a DER-encoded certificate from the file at `path`
+    ///
+    /// The whole file must be one certificate, checked as for
+    /// [`CertificateDer::try_from_der()`]. Nothing is trimmed: a line ending added after the
+    /// certificate, for example by a text editor, fails with [`InvalidDer::TrailingData`].
+    ///
+    /// ```
+    /// # fn main() -> std::io::Result<()> {
+    /// use rustls_pki_types::der::ReadDerError;
+    /// use rustls_pki_types::{CertificateDer, InvalidDer};
+    ///
+    /// let path = std::env::temp_dir().join(format!("pki-types-{}.crt", std::process::id()));
+    /// std::fs::write(&path, [0x30, 0x01, 0x05])?;
+    /// let cert = CertificateDer::from_der_file(&path).unwrap();
+    /// assert_eq!(cert.as_ref(), &[0x30, 0x01, 0x05]);
+    ///
+    /// std::fs::write(&path, b"\x30\x01\x05\n")?;
+    /// let err = CertificateDer::from_der_file(&path).unwrap_err();
+    /// assert_eq!(err.path, path);
+    /// assert!(matches!(err.error, ReadDerError::InvalidDer(InvalidDer::TrailingData)));
+    ///
+    /// std::fs::remove_file(&path)?;
+    /// let err = CertificateDer::from_der_file(&path).unwrap_err();
+    /// assert!(matches!(err.error, ReadDerError::Io(_)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_der_file(path: impl AsRef<Path>) -> Result<Self, DerFileError> {
+        read_file(path.as_ref()).map(Self::from)
+    }
+
+    /// Read a DER-encoded certificate from `r`, up to its end
+    ///
+    /// See [`CertificateDer::from_der_file()`]. All of `r` is read, so for input from an
+    /// untrusted source, limit its length first, for example with [`io::Read::take()`], or
+    /// use [`read_one_der()`].
+    pub fn from_der_reader(r: impl io::Read) -> Result<Self, ReadDerError> {
+        read_to_end(r).map(Self::from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl CertificateRevocationListDer<'static> {
+    /// Read a DER-encoded CRL from the file at `path`
+    ///
+    /// See [`CertificateDer::from_der_file()`].
+    pub fn from_der_file(path: impl AsRef<Path>) -> Result<Self, DerFileError> {
+        read_file(path.as_ref()).map(Self::from)
+    }
+
+    /// Read a DER-encoded CRL from `r`, up to its end
+    ///
+    /// See [`CertificateDer::from_der_reader()`].
+    pub fn from_der_reader(r: impl io::Read) -> Result<Self, ReadDerError> {
+        read_to_end(r).map(Self::from)
+    }
+}
+
+/// Read the file at `path` as a single `SEQUENCE`, checked as for [`check_framing()`]
+#[cfg(feature = "std")]
+fn read_file(path: &Path) -> Result<Vec<u8>, DerFileError> {
+    fs::File::open(path)
+        .map_err(ReadDerError::Io)
+        .and_then(read_to_end)
+        .map_err(|error| DerFileError {
+            path: path.to_owned(),
+            error,
+        })
+}
+
+/// Read all of `r` as a single `SEQUENCE`, checked as for [`check_framing()`]
+#[cfg(feature = "std")]
+fn read_to_end(mut r: impl io::Read) -> Result<Vec<u8>, ReadDerError> {
+    let mut der = Vec::new();
+    r.read_to_end(&mut der).map_err(ReadDerError::Io)?;
+    check_framing(&der, SEQUENCE)?;
+    Ok(der)
+}
+
+/// The length of the tag and length octets for an element with `len` contents octets
+#[cfg(feature = "alloc")]
+pub(crate) fn header_len(len: usize) -> usize {
+    match len {
+        0..=0x7f => 2,
+        _ => 2 + (usize::BITS - len.leading_zeros() + 7) as usize / 8,
+    }
+}
+
+/// Write the tag and length octets for an element with `len` contents octets
+#[cfg(feature = "alloc")]
+pub(crate) fn write_header(out: &mut Vec<u8>, tag: u8, len: usize) {
+    out.push(tag);
+    match len {
+        0..=0x7f => out.push(len as u8),
+        _ => {
+            let octets = header_len(len) - 2;
+            out.push(0x80 | octets as u8);
+            out.extend_from_slice(&len.to_be_bytes()[core::mem::size_of::<usize>() - octets..]);
+        }
+    }
+}
+
+/// Decode `n` ASCII digits at the start of `input`
+#[cfg(feature = "x509")]
+fn split_digits(input: &[u8], n: usize) -> Result<(u64, &[u8]), InvalidDer> {
+    if input.len() < n {
+        return Err(InvalidDer::InvalidValue);
+    }
+
+    let (digits, rest) = input.split_at(n);
+    let mut value = 0;
+    for &d in digits {
+        if !d.is_ascii_digit() {
+            return Err(InvalidDer::InvalidValue);
+        }
+        value = value * 10 + u64::from(d - b'0');
+    }
+
+    Ok((value, rest))
+}
+
+#[cfg(feature = "x509")]
+fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(feature = "x509")]
+fn is_leap_year(year: u64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days from 1970-01-01 to the given date, which must not be earlier
+#[cfg(feature = "x509")]
+fn days_since_epoch(year: u64, month: u64, day: u64) -> u64 {
+    let leap_days = |y: u64| y / 4 - y / 100 + y / 400;
+    let mut days = (year - 1970) * 365 + leap_days(year - 1) - leap_days(1969);
+    for m in 1..month {
+        days += days_in_month(year, m);
+    }
+    days + day - 1
+}
+
+/// Write the contents of an `OBJECT IDENTIFIER` in dotted decimal form
+///
+/// Malformed contents are written as hex instead.
+pub(crate) fn write_oid(f: &mut fmt::Formatter<'_>, oid: &[u8]) -> fmt::Result {
+    let mut arcs = [0u64; 32];
+    let mut count = 0;
+    let mut value = 0u64;
+    for (i, &byte) in oid.iter().enumerate() {
+        // Subidentifiers are minimally encoded in base 128, with the high bit set on all but
+        // the last octet.
+        let leading = value == 0 && byte == 0x80;
+        let last = i + 1 == oid.len();
+        if leading || value >> 57 != 0 || count == arcs.len() || (last && byte & 0x80 != 0) {
+            return oid.iter().try_for_each(|b| write!(f, "{b:02x}"));
+        }
+
+        value = (value << 7) | u64::from(byte & 0x7f);
+        if byte & 0x80 == 0 {
+            arcs[count] = value;
+            count += 1;
+            value = 0;
+        }
+    }
+
+    let (first, rest) = match arcs[..count].split_first() {
+        Some(split) => split,
+        None => return Ok(()),
+    };
+
+    // The first subidentifier combines the first two arcs.
+    match first {
+        0..=39 => write!(f, "0.{first}")?,
+        40..=79 => write!(f, "1.{}", first - 40)?,
+        _ => write!(f, "2.{}", first - 80)?,
+    }
+
+    rest.iter().try_for_each(|arc| write!(f, ".{arc}"))
+}
+
+#[cfg(feature = "x509")]
+pub(crate) const BOOLEAN: u8 = 0x01;
+pub(crate) const INTEGER: u8 = 0x02;
+pub(crate) const BIT_STRING: u8 = 0x03;
+pub(crate) const OCTET_STRING: u8 = 0x04;
+#[cfg(feature = "jwk")]
+pub(crate) const NULL: u8 = 0x05;
+pub(crate) const OBJECT_IDENTIFIER: u8 = 0x06;
+#[cfg(feature = "x509")]
+pub(crate) const ENUMERATED: u8 = 0x0a;
+#[cfg(feature = "x509")]
+pub(crate) const UTC_TIME: u8 = 0x17;
+#[cfg(feature = "x509")]
+pub(crate) const GENERALIZED_TIME: u8 = 0x18;
+pub(crate) const SEQUENCE: u8 = 0x30;
+pub(crate) const SET: u8 = 0x31;
+
+/// `rsaEncryption`, 1.2.840.113549.1.1.1
+pub(crate) const RSA_ENCRYPTION: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+/// `id-ecPublicKey`, 1.2.840.10045.2.1
+pub(crate) const EC_PUBLIC_KEY: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+/// `secp256r1`, 1.2.840.10045.3.1.7
+pub(crate) const SECP256R1: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+/// `secp384r1`, 1.3.132.0.34
+pub(crate) const SECP384R1: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x22];
+/// `secp521r1`, 1.3.132.0.35
+pub(crate) const SECP521R1: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x23];
+/// `id-X25519`, 1.3.101.110
+pub(crate) const X25519: &[u8] = &[0x2b, 0x65, 0x6e];
+/// `id-X448`, 1.3.101.111
+pub(crate) const X448: &[u8] = &[0x2b, 0x65, 0x6f];
+/// `id-Ed25519`, 1.3.101.112
+pub(crate) const ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+/// `id-Ed448`, 1.3.101.113
+pub(crate) const ED448: &[u8] = &[0x2b, 0x65, 0x71];
+
+/// Tag for a primitive, context-specific element with the given number
+pub(crate) const fn context_primitive(number: u8) -> u8 {
+    0x80 | number
+}
+
+/// Tag for a constructed, context-specific element with the given number
+pub(crate) const fn context_constructed(number: u8) -> u8 {
+    0xa0 | number
+}
+
+/// An error indicating that DER-encoded input was malformed or did not have the expected structure
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidDer {
+    /// An element had a different tag than the one expected at its position
+    UnexpectedTag {
+        /// The tag that was expected
+        expected: u8,
+        /// The tag that was found
+        found: u8,
+    },
+    /// An element used the high tag number form, which is not supported
+    UnsupportedTag(u8),
+    /// A length was indefinite, not minimally encoded, or too large
+    InvalidLength,
+    /// The input ended before an element, or the contents of an element, were complete
+    Truncated,
+    /// Unexpected data followed the end of an element
+    TrailingData,
+    /// The contents of an element were not valid for its type
+    InvalidValue,
+    /// The input was PEM text, which must be decoded to DER first
+    LooksLikePem,
+    /// The input was longer than the given limit
+    TooLong {
+        /// The length of the input
+        len: usize,
+        /// The limit, in bytes
+        max: usize,
+    },
+}
+
+impl fmt::Display for InvalidDer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedTag { expected, found } => {
+                write!(f, "expected DER tag {expected:#04x}, found {found:#04x}")
+            }
+            Self::UnsupportedTag(tag) => write!(f, "unsupported DER tag {tag:#04x}"),
+            Self::InvalidLength => f.write_str("invalid DER length encoding"),
+            Self::Truncated => f.write_str("DER input is truncated"),
+            Self::TrailingData => f.write_str("unexpected data after the end of a DER element"),
+            Self::InvalidValue => f.write_str("invalid contents in DER element"),
+            Self::LooksLikePem => f.write_str(
+                "input is PEM, not DER; decode it first, for example with `PemObject` from the \
+                 `pem` module",
+            ),
+            Self::TooLong { len, max } => {
+                write!(
+                    f,
+                    "DER input of {len} bytes exceeds the limit of {max} bytes"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDer {}
+
+/// An error splitting concatenated certificates with `CertificateDer::from_concatenated()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConcatenatedDerError {
+    /// The offset in the input of the start of the certificate that could not be split off
+    pub offset: usize,
+    /// What was wrong with the certificate's framing
+    pub error: InvalidDer,
+}
+
+impl fmt::Display for ConcatenatedDerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid certificate at offset {}: {}",
+            self.offset, self.error
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConcatenatedDerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error reading a DER element with [`read_one_der()`]
+#[cfg(feature = "std")]
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum ReadDerError {
+    /// Reading failed
+    Io(io::Error),
+    /// The tag or length octets were malformed, or the input ended before the element did
+    InvalidDer(InvalidDer),
+    /// The element was longer than the given limit
+    TooLarge {
+        /// The length of the element, including its tag and length octets
+        len: usize,
+        /// The limit, in bytes
+        max_len: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for ReadDerError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::UnexpectedEof => Self::InvalidDer(InvalidDer::Truncated),
+            _ => Self::Io(err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<InvalidDer> for ReadDerError {
+    fn from(err: InvalidDer) -> Self {
+        Self::InvalidDer(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ReadDerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read DER: {err}"),
+            Self::InvalidDer(err) => err.fmt(f),
+            Self::TooLarge { len, max_len } => {
+                write!(
+                    f,
+                    "DER element of {len} bytes exceeds the limit of {max_len} bytes"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReadDerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::InvalidDer(err) => Some(err),
+            Self::TooLarge { .. } => None,
+        }
+    }
+}
+
+/// An error reading a DER file with [`CertificateDer::from_der_file()`]
+///
+/// [`CertificateDer::from_der_file()`]: crate::CertificateDer::from_der_file
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct DerFileError {
+    /// The path of the file
+    pub path: PathBuf,
+    /// Why the file could not be read
+    pub error: ReadDerError,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for DerFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to read {}: {}", self.path.display(), self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DerFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
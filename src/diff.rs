@@ -0,0 +1,97 @@
+//! Comparison of trust anchor collections.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::x509::NameSummary;
+use crate::TrustAnchor;
+
+/// Compare two collections of trust anchors, like two versions of a root store
+///
+/// Anchors are identified by their subject and public key together. Anchors present in both
+/// collections are reported if their name constraints differ. If an anchor appears more than
+/// once within a collection, only its first occurrence is considered.
+///
+/// The lists in the returned [`AnchorDiff`] are ordered by the encoding of the anchors'
+/// subjects and then public keys, so the result does not depend on the order of the inputs.
+pub fn diff_anchors<'a>(old: &'a [TrustAnchor<'a>], new: &'a [TrustAnchor<'a>]) -> AnchorDiff<'a> {
+    let old = by_identity(old);
+    let mut new = by_identity(new);
+
+    let mut diff = AnchorDiff::default();
+    for (identity, old) in old {
+        match new.remove(&identity) {
+            Some(new) if new.name_constraints != old.name_constraints => {
+                diff.constraints_changed.push((old, new))
+            }
+            Some(_) => {}
+            None => diff.removed.push(old),
+        }
+    }
+
+    diff.added.extend(new.into_values());
+    diff
+}
+
+fn by_identity<'a>(
+    anchors: &'a [TrustAnchor<'a>],
+) -> BTreeMap<(&'a [u8], &'a [u8]), &'a TrustAnchor<'a>> {
+    let mut map = BTreeMap::new();
+    for anchor in anchors {
+        let identity = (
+            anchor.subject.as_ref(),
+            anchor.subject_public_key_info.as_ref(),
+        );
+        map.entry(identity).or_insert(anchor);
+    }
+    map
+}
+
+/// The differences between two collections of trust anchors, from [`diff_anchors()`]
+///
+/// The `Display` implementation writes a summary for humans: a line with the number of
+/// changes, followed by a line for each anchor, marked with `+` if added, `-` if removed or
+/// `~` if its name constraints changed.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnchorDiff<'a> {
+    /// Anchors only in the new collection
+    pub added: Vec<&'a TrustAnchor<'a>>,
+    /// Anchors only in the old collection
+    pub removed: Vec<&'a TrustAnchor<'a>>,
+    /// Anchors in both collections whose name constraints differ, as the old and new anchor
+    pub constraints_changed: Vec<(&'a TrustAnchor<'a>, &'a TrustAnchor<'a>)>,
+}
+
+impl AnchorDiff<'_> {
+    /// Whether the two collections hold the same anchors
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.constraints_changed.is_empty()
+    }
+}
+
+impl fmt::Display for AnchorDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} added, {} removed, {} with changed name constraints",
+            self.added.len(),
+            self.removed.len(),
+            self.constraints_changed.len()
+        )?;
+
+        let changed = self.constraints_changed.iter().map(|(_, new)| new);
+        for (mark, anchors) in [
+            ('+', self.added.iter().collect::<Vec<_>>()),
+            ('-', self.removed.iter().collect()),
+            ('~', changed.collect()),
+        ] {
+            for anchor in anchors {
+                writeln!(f, "{mark} {}", NameSummary(anchor.subject.as_ref()))?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+//! Checking and decoding certificates embedded at compile time.
+//!
+//! These functions back the [`include_der_cert!`] and [`include_pem_cert!`] macros. They run in
+//! constant evaluation, so problems with the embedded file are reported as build failures
+//! pointing at the macro invocation that names the file.
+//!
+//! [`include_der_cert!`]: crate::include_der_cert
+//! [`include_pem_cert!`]: crate::include_pem_cert
+
+/// Include a DER-encoded certificate file, as a constant [`CertificateDer<'static>`]
+///
+/// The path is resolved like the path given to [`include_bytes!`]: relative to the file the
+/// macro is used in, unless it is absolute. The outer framing of the certificate is checked as
+/// by `CertificateDer::try_from_der()`, and the build fails if it is wrong. The contents are
+/// not decoded.
+///
+/// ```
+/// use rustls_pki_types::{include_der_cert, CertificateDer};
+///
+/// const ROOT: CertificateDer<'static> =
+///     include_der_cert!(concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/ed25519.der"));
+///
+/// let der = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/ed25519.der")).unwrap();
+/// assert_eq!(ROOT.as_ref(), &der[..]);
+/// ```
+///
+/// [`CertificateDer<'static>`]: crate::CertificateDer
+#[macro_export]
+macro_rules! include_der_cert {
+    ($path:expr) => {{
+        const DER: &[u8] = $crate::embed::check_der(include_bytes!($path));
+        $crate::CertificateDer::from_slice(DER)
+    }};
+}
+
+/// Include a PEM-encoded certificate file, as a constant [`CertificateDer<'static>`]
+///
+/// The file is decoded while building, into a static array holding the DER encoding, so
+/// nothing is decoded or allocated at run time. The path is resolved as for
+/// [`include_der_cert!`].
+///
+/// The first `CERTIFICATE` section of the file is used, as by
+/// `PemObject::from_pem_slice()`, and any text or sections around it are ignored. The build
+/// fails if the file has no such section, if the section is not valid base64, or if the
+/// decoded bytes are not framed as a certificate.
+///
+/// ```
+/// use rustls_pki_types::{include_pem_cert, CertificateDer};
+///
+/// const ROOT: CertificateDer<'static> =
+///     include_pem_cert!(concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/ed25519.pem"));
+///
+/// # #[cfg(feature = "pem")] {
+/// use rustls_pki_types::pem::PemObject;
+///
+/// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/ed25519.pem");
+/// assert_eq!(ROOT, CertificateDer::from_pem_file(path).unwrap());
+/// # }
+/// ```
+///
+/// [`CertificateDer<'static>`]: crate::CertificateDer
+#[macro_export]
+macro_rules! include_pem_cert {
+    ($path:expr) => {{
+        const PEM: &[u8] = include_bytes!($path);
+        const LEN: usize = $crate::embed::pem_decoded_len(PEM);
+        const DER: &[u8] = $crate::embed::check_der(&$crate::embed::decode_pem::<LEN>(PEM));
+        $crate::CertificateDer::from_slice(DER)
+    }};
+}
+
+/// Fail if `der` is not a single DER `SEQUENCE`, and otherwise return it
+pub const fn check_der(der: &[u8]) -> &[u8] {
+    if der.len() < 2 || der[0] != 0x30 {
+        panic!("embedded certificate does not start with a DER SEQUENCE");
+    }
+
+    let (len, header) = match der[1] {
+        len if len < 0x80 => (len as usize, 2),
+        0x81..=0x84 => {
+            let octets = (der[1] & 0x7f) as usize;
+            if der.len() < 2 + octets || der[2] == 0 {
+                panic!("embedded certificate has an invalid DER length");
+            }
+
+            let mut len = 0;
+            let mut i = 0;
+            while i < octets {
+                len = (len << 8) | der[2 + i] as usize;
+                i += 1;
+            }
+
+            if len < 0x80 {
+                panic!("embedded certificate has an invalid DER length");
+            }
+            (len, 2 + octets)
+        }
+        _ => panic!("embedded certificate has an invalid DER length"),
+    };
+
+    if der.len() - header != len {
+        panic!("embedded certificate is not exactly one DER SEQUENCE");
+    }
+
+    der
+}
+
+/// The length of the DER encoding in the first `CERTIFICATE` section of `pem`
+pub const fn pem_decoded_len(pem: &[u8]) -> usize {
+    let (mut i, end) = body(pem);
+    let mut sextets = 0;
+    let mut padding = 0;
+    while i < end {
+        match pem[i] {
+            b' ' | b'\t' | b'\r' | b'\n' => {}
+            b'=' => padding += 1,
+            c => {
+                if padding > 0 {
+                    panic!("embedded PEM certificate has base64 after the padding");
+                }
+                value(c);
+                sextets += 1;
+            }
+        }
+        i += 1;
+    }
+
+    if sextets % 4 == 1 || padding > 2 || (padding > 0 && (sextets + padding) % 4 != 0) {
+        panic!("embedded PEM certificate has base64 of an invalid length");
+    }
+
+    sextets * 6 / 8
+}
+
+/// Decode the first `CERTIFICATE` section of `pem`, which is `N` octets long
+///
+/// `N` must be the length given by [`pem_decoded_len()`].
+pub const fn decode_pem<const N: usize>(pem: &[u8]) -> [u8; N] {
+    let (mut i, end) = body(pem);
+    let mut out = [0; N];
+    let mut written = 0;
+    let mut bits = 0u32;
+    let mut len = 0;
+    while i < end && written < N {
+        match pem[i] {
+            b' ' | b'\t' | b'\r' | b'\n' | b'=' => {}
+            c => {
+                bits = (bits << 6 | value(c) as u32) & 0xffff;
+                len += 6;
+                if len >= 8 {
+                    len -= 8;
+                    out[written] = (bits >> len) as u8;
+                    written += 1;
+                }
+            }
+        }
+        i += 1;
+    }
+
+    out
+}
+
+/// The range of `pem` between the `BEGIN` and `END` lines of its first `CERTIFICATE` section
+const fn body(pem: &[u8]) -> (usize, usize) {
+    let start = match find_line(pem, BEGIN, 0) {
+        Some(at) => at + BEGIN.len(),
+        None => panic!("embedded PEM file has no `-----BEGIN CERTIFICATE-----` line"),
+    };
+
+    match find_line(pem, END, start) {
+        Some(end) => (start, end),
+        None => panic!("embedded PEM certificate has no `-----END CERTIFICATE-----` line"),
+    }
+}
+
+/// The offset of the first line of `pem` from `from` that starts with `prefix`
+const fn find_line(pem: &[u8], prefix: &[u8], from: usize) -> Option<usize> {
+    let mut at = from;
+    while at + prefix.len() <= pem.len() {
+        if at == 0 || pem[at - 1] == b'\n' {
+            let mut i = 0;
+            while i < prefix.len() && pem[at + i] == prefix[i] {
+                i += 1;
+            }
+            if i == prefix.len() {
+                return Some(at);
+            }
+        }
+        at += 1;
+    }
+
+    None
+}
+
+/// The value of a character of the standard base64 alphabet
+const fn value(c: u8) -> u8 {
+    match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => panic!("embedded PEM certificate has a character that is not base64"),
+    }
+}
+
+const BEGIN: &[u8] = b"-----BEGIN CERTIFICATE-----";
+const END: &[u8] = b"-----END CERTIFICATE-----";
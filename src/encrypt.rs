@@ -0,0 +1,127 @@
+//! Writing of encrypted private keys, with the encryption left to the caller.
+
+use core::fmt;
+use std::io;
+use std::path::Path;
+
+use zeroize::Zeroizing;
+
+use crate::convert::{sec1_to_pkcs8, wrap_pkcs8, KeyConversionError, RSA_ALGORITHM};
+use crate::der::InvalidDer;
+use crate::pem;
+use crate::{write, EncryptedPrivateKeyInfoDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+
+/// Encrypts private keys for storage
+///
+/// This crate does not implement any cryptography: implement this trait with a cryptographic
+/// library to produce an `EncryptedPrivateKeyInfo` (for example, using PBES2 from RFC 8018)
+/// for [`PrivateKeyDer::write_encrypted_pem_file()`].
+pub trait KeyEncryptor {
+    /// Encrypt `key` with a key derived from `passphrase`
+    fn encrypt(
+        &self,
+        key: &PrivatePkcs8KeyDer<'_>,
+        passphrase: &[u8],
+    ) -> Result<EncryptedPrivateKeyInfoDer<'static>, EncryptError>;
+}
+
+impl PrivateKeyDer<'_> {
+    /// Encrypt this key and write it to the file at `path` as `ENCRYPTED PRIVATE KEY` PEM
+    ///
+    /// PKCS#1 and SEC1 keys are first converted to PKCS#8, which requires a SEC1 key to
+    /// include its curve parameters. The passphrase is only requested from `passphrase` once
+    /// the key is known to be convertible, and is handed to `encryptor` with the PKCS#8 key.
+    /// Plaintext copies of the key and the passphrase made here are held in [`Zeroizing`]
+    /// buffers, so they are overwritten before they are freed; this is why writing encrypted
+    /// keys needs the `zeroize` feature.
+    ///
+    /// The file is replaced atomically, and on Unix is created readable and writable only by
+    /// its owner (mode 0600). It holds nothing but the encrypted key.
+    pub fn write_encrypted_pem_file(
+        &self,
+        path: impl AsRef<Path>,
+        passphrase: impl FnOnce() -> Result<Vec<u8>, EncryptError>,
+        encryptor: &dyn KeyEncryptor,
+    ) -> Result<(), EncryptError> {
+        let converted = match self {
+            Self::Pkcs1(key) => Some(Zeroizing::new(wrap_pkcs8(
+                RSA_ALGORITHM,
+                &[key.secret_pkcs1_der()],
+            ))),
+            Self::Sec1(key) => Some(Zeroizing::new(sec1_to_pkcs8(key.secret_sec1_der())?)),
+            Self::Pkcs8(_) => None,
+        };
+
+        let encrypted = {
+            let key = match (&converted, self) {
+                (Some(der), _) => PrivatePkcs8KeyDer::from(&der[..]),
+                (None, Self::Pkcs8(key)) => PrivatePkcs8KeyDer::from(key.secret_pkcs8_der()),
+                (None, _) => unreachable!(),
+            };
+
+            let passphrase = Zeroizing::new(passphrase()?);
+            encryptor.encrypt(&key, &passphrase)?
+        };
+        drop(converted);
+
+        let pem = pem::encode(pem::ENCRYPTED_PKCS8_KEY, &encrypted);
+        write::write_private_file(path.as_ref(), pem.as_bytes()).map_err(EncryptError::Io)
+    }
+}
+
+/// An error writing an encrypted private key
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum EncryptError {
+    /// The structure of the key could not be decoded
+    InvalidKey(InvalidDer),
+    /// A SEC1 key did not name its curve, so could not be converted to PKCS#8
+    MissingCurve,
+    /// The passphrase could not be obtained
+    Passphrase(Box<dyn std::error::Error + Send + Sync>),
+    /// The key could not be encrypted
+    Encryptor(Box<dyn std::error::Error + Send + Sync>),
+    /// The file could not be written
+    Io(io::Error),
+}
+
+impl From<InvalidDer> for EncryptError {
+    fn from(err: InvalidDer) -> Self {
+        Self::InvalidKey(err)
+    }
+}
+
+impl From<KeyConversionError> for EncryptError {
+    fn from(err: KeyConversionError) -> Self {
+        match err {
+            KeyConversionError::InvalidKey(err) => Self::InvalidKey(err),
+            KeyConversionError::MissingCurve => Self::MissingCurve,
+            // Never produced by conversion to PKCS#8.
+            KeyConversionError::UnsupportedAlgorithm(_)
+            | KeyConversionError::PublicKeyNotPresent => Self::InvalidKey(InvalidDer::InvalidValue),
+        }
+    }
+}
+
+impl fmt::Display for EncryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey(err) => write!(f, "invalid private key: {err}"),
+            Self::MissingCurve => f.write_str("EC private key does not name its curve"),
+            Self::Passphrase(err) => write!(f, "failed to obtain passphrase: {err}"),
+            Self::Encryptor(err) => write!(f, "failed to encrypt private key: {err}"),
+            Self::Io(err) => write!(f, "failed to write encrypted private key: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for EncryptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidKey(err) => Some(err),
+            Self::Passphrase(err) | Self::Encryptor(err) => Some(&**err),
+            Self::Io(err) => Some(err),
+            Self::MissingCurve => None,
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Accessors for common certificate extensions.
+
+use crate::der::{self, InvalidDer, Reader};
+use crate::{CertificateDer, CertificateSpans};
+
+impl CertificateDer<'_> {
+    /// The certificate's key usage extension, if present
+    ///
+    /// See [`CertificateSpans::key_usage()`].
+    pub fn key_usage(&self) -> Result<Option<KeyUsage>, InvalidDer> {
+        self.spans()?.key_usage()
+    }
+
+    /// The certificate's extended key usage extension, if present
+    ///
+    /// See [`CertificateSpans::extended_key_usage()`].
+    pub fn extended_key_usage(&self) -> Result<Option<ExtendedKeyUsage<'_>>, InvalidDer> {
+        self.spans()?.extended_key_usage()
+    }
+
+    /// The certificate's basic constraints extension, if present
+    ///
+    /// See [`CertificateSpans::basic_constraints()`].
+    pub fn basic_constraints(&self) -> Result<Option<BasicConstraints>, InvalidDer> {
+        self.spans()?.basic_constraints()
+    }
+}
+
+impl<'a> CertificateSpans<'a> {
+    /// The key usage extension, if present
+    pub fn key_usage(&self) -> Result<Option<KeyUsage>, InvalidDer> {
+        let extension = match self.extension(KEY_USAGE)? {
+            Some(extension) => extension,
+            None => return Ok(None),
+        };
+
+        // Named bits are numbered from the most significant bit of the first octet, and DER
+        // leaves off trailing zero bits.
+        let bits = match der::expect_single(extension.value, der::BIT_STRING)? {
+            [unused, bits @ ..] if *unused < 8 && bits.len() <= 2 => {
+                bits.iter().enumerate().fold(0u16, |acc, (i, b)| {
+                    acc | u16::from(b.reverse_bits()) << (8 * i)
+                })
+            }
+            _ => return Err(InvalidDer::InvalidValue),
+        };
+
+        Ok(Some(KeyUsage {
+            bits,
+            critical: extension.critical,
+        }))
+    }
+
+    /// The extended key usage extension, if present
+    pub fn extended_key_usage(&self) -> Result<Option<ExtendedKeyUsage<'a>>, InvalidDer> {
+        let extension = match self.extension(EXTENDED_KEY_USAGE)? {
+            Some(extension) => extension,
+            None => return Ok(None),
+        };
+
+        Ok(Some(ExtendedKeyUsage {
+            purposes: der::expect_single(extension.value, der::SEQUENCE)?,
+            critical: extension.critical,
+        }))
+    }
+
+    /// The basic constraints extension, if present
+    pub fn basic_constraints(&self) -> Result<Option<BasicConstraints>, InvalidDer> {
+        let extension = match self.extension(BASIC_CONSTRAINTS)? {
+            Some(extension) => extension,
+            None => return Ok(None),
+        };
+
+        let mut constraints = Reader::new(der::expect_single(extension.value, der::SEQUENCE)?);
+        let ca = match constraints.read_optional(der::BOOLEAN)? {
+            None | Some([0x00]) => false,
+            Some([0xff]) => true,
+            Some(_) => return Err(InvalidDer::InvalidValue),
+        };
+        let path_len = match constraints.read_optional(der::INTEGER)? {
+            None => None,
+            Some(len) => Some(path_len(len)?),
+        };
+        constraints.finish()?;
+
+        Ok(Some(BasicConstraints {
+            ca,
+            path_len,
+            critical: extension.critical,
+        }))
+    }
+}
+
+/// Decode the contents of a `pathLenConstraint` `INTEGER`, saturating at 255
+fn path_len(int: &[u8]) -> Result<u8, InvalidDer> {
+    match int {
+        [len] if *len < 0x80 => Ok(*len),
+        [0x00, len] if *len >= 0x80 => Ok(*len),
+        // Negative, or not minimally encoded.
+        [] | [0x80..=0xff, ..] | [0x00, 0x00..=0x7f, ..] => Err(InvalidDer::InvalidValue),
+        _ => Ok(u8::MAX),
+    }
+}
+
+/// The key usage extension of a certificate (RFC 5280 section 4.2.1.3)
+///
+/// The usages are held as bits, numbered as in the `KeyUsage` `BIT STRING`: test for them with
+/// [`KeyUsage::contains()`] and constants like [`KeyUsage::DIGITAL_SIGNATURE`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyUsage {
+    bits: u16,
+    critical: bool,
+}
+
+impl KeyUsage {
+    /// `digitalSignature` (0)
+    pub const DIGITAL_SIGNATURE: u16 = 1 << 0;
+    /// `nonRepudiation`, also known as `contentCommitment` (1)
+    pub const NON_REPUDIATION: u16 = 1 << 1;
+    /// `keyEncipherment` (2)
+    pub const KEY_ENCIPHERMENT: u16 = 1 << 2;
+    /// `dataEncipherment` (3)
+    pub const DATA_ENCIPHERMENT: u16 = 1 << 3;
+    /// `keyAgreement` (4)
+    pub const KEY_AGREEMENT: u16 = 1 << 4;
+    /// `keyCertSign` (5)
+    pub const KEY_CERT_SIGN: u16 = 1 << 5;
+    /// `cRLSign` (6)
+    pub const CRL_SIGN: u16 = 1 << 6;
+    /// `encipherOnly` (7)
+    pub const ENCIPHER_ONLY: u16 = 1 << 7;
+    /// `decipherOnly` (8)
+    pub const DECIPHER_ONLY: u16 = 1 << 8;
+
+    /// Whether all of the usages in `bits` are asserted
+    pub fn contains(&self, bits: u16) -> bool {
+        self.bits & bits == bits
+    }
+
+    /// The asserted usages, with bit `n` of the `BIT STRING` as `1 << n`
+    pub fn bits(&self) -> u16 {
+        self.bits
+    }
+
+    /// Whether the extension is marked critical
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+}
+
+/// The extended key usage extension of a certificate (RFC 5280 section 4.2.1.12)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExtendedKeyUsage<'a> {
+    purposes: &'a [u8],
+    critical: bool,
+}
+
+impl<'a> ExtendedKeyUsage<'a> {
+    /// `id-kp-serverAuth`, 1.3.6.1.5.5.7.3.1
+    pub const SERVER_AUTH: &'static [u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x01];
+    /// `id-kp-clientAuth`, 1.3.6.1.5.5.7.3.2
+    pub const CLIENT_AUTH: &'static [u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x02];
+    /// `id-kp-codeSigning`, 1.3.6.1.5.5.7.3.3
+    pub const CODE_SIGNING: &'static [u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x03];
+    /// `id-kp-OCSPSigning`, 1.3.6.1.5.5.7.3.9
+    pub const OCSP_SIGNING: &'static [u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x09];
+    /// `anyExtendedKeyUsage`, 2.5.29.37.0
+    pub const ANY: &'static [u8] = &[0x55, 0x1d, 0x25, 0x00];
+
+    /// Iterate over the key purposes, as the contents of their `OBJECT IDENTIFIER`s
+    ///
+    /// These can be compared to constants like [`ExtendedKeyUsage::SERVER_AUTH`]. After
+    /// yielding an error, the iterator yields nothing further.
+    pub fn iter(&self) -> KeyPurposes<'a> {
+        KeyPurposes {
+            reader: Reader::new(self.purposes),
+        }
+    }
+
+    /// Whether `purpose` is listed
+    ///
+    /// This is an exact match: [`ExtendedKeyUsage::ANY`] is not taken to include other
+    /// purposes.
+    pub fn contains(&self, purpose: &[u8]) -> Result<bool, InvalidDer> {
+        for listed in self.iter() {
+            if listed? == purpose {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Whether the extension is marked critical
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+}
+
+impl<'a> IntoIterator for &ExtendedKeyUsage<'a> {
+    type Item = Result<&'a [u8], InvalidDer>;
+    type IntoIter = KeyPurposes<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the key purposes of an [`ExtendedKeyUsage`]
+#[derive(Clone, Debug)]
+pub struct KeyPurposes<'a> {
+    reader: Reader<'a>,
+}
+
+impl<'a> Iterator for KeyPurposes<'a> {
+    type Item = Result<&'a [u8], InvalidDer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.peek_tag()?;
+
+        let purpose = self.reader.read(der::OBJECT_IDENTIFIER);
+        if purpose.is_err() {
+            self.reader = Reader::new(&[]);
+        }
+
+        Some(purpose)
+    }
+}
+
+/// The basic constraints extension of a certificate (RFC 5280 section 4.2.1.9)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BasicConstraints {
+    ca: bool,
+    path_len: Option<u8>,
+    critical: bool,
+}
+
+impl BasicConstraints {
+    /// Whether the subject is a CA, from the `cA` field
+    ///
+    /// This is `false` if the field is absent.
+    pub fn is_ca(&self) -> bool {
+        self.ca
+    }
+
+    /// The maximum number of intermediate certificates that may follow this one, if limited
+    ///
+    /// A `pathLenConstraint` above 255 is taken as 255. No chain that can be built in practice
+    /// is that long, so this does not reject any chain that the larger value would allow.
+    pub fn path_len(&self) -> Option<u8> {
+        self.path_len
+    }
+
+    /// Whether the extension is marked critical
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+}
+
+/// `id-ce-basicConstraints`, 2.5.29.19
+const BASIC_CONSTRAINTS: &[u8] = &[0x55, 0x1d, 0x13];
+/// `id-ce-keyUsage`, 2.5.29.15
+const KEY_USAGE: &[u8] = &[0x55, 0x1d, 0x0f];
+/// `id-ce-extKeyUsage`, 2.5.29.37
+const EXTENDED_KEY_USAGE: &[u8] = &[0x55, 0x1d, 0x25];
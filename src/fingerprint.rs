@@ -0,0 +1,281 @@
+//! Fingerprints of certificates and keys, for pinning and display.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+#[cfg(feature = "sha2")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "subtle")]
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "sha2")]
+use crate::{CertificateDer, CertificateRevocationListDer, SubjectPublicKeyInfoDer};
+
+/// The digest of a certificate, public key or other object, as used for pinning
+///
+/// This holds up to 64 octets, enough for SHA-512. No hashing is done by this type: make it
+/// from the output of whatever hash function the application uses with
+/// [`Fingerprint::new()`].
+///
+/// It is written as lowercase hex octets separated by colons, like `1f:5c:53:...`, or without
+/// separators with the alternate form `{:#}`. [`fmt::UpperHex`] writes uppercase octets
+/// separated by colons, as `openssl x509 -fingerprint` does. Both forms are parsed, in either
+/// case:
+///
+/// ```
+/// use rustls_pki_types::Fingerprint;
+///
+/// // From `openssl x509 -noout -fingerprint -sha256`
+/// let openssl = "sha256 Fingerprint=1F:5C:53:9B:3C:DA:3E:5C:74:8D:70:AB:3C:A8:13:CC:\
+///                DD:2E:1E:1A:3C:FC:45:55:0C:12:D2:A0:5B:67:20:00";
+/// let (prefix, hex) = openssl.split_once('=').unwrap();
+/// let fingerprint = hex.parse::<Fingerprint>().unwrap();
+/// assert_eq!(fingerprint.as_ref().len(), 32);
+/// assert_eq!(format!("{prefix}={fingerprint:X}"), openssl);
+///
+/// assert_eq!(fingerprint.to_string(), hex.to_ascii_lowercase());
+/// let compact = format!("{fingerprint:#}");
+/// assert_eq!(compact, hex.replace(':', "").to_ascii_lowercase());
+/// assert_eq!(compact.to_ascii_uppercase().parse::<Fingerprint>(), Ok(fingerprint));
+/// ```
+///
+/// With the `subtle` feature, comparisons with `==` take the same time wherever the fingerprints
+/// differ, so that comparing against a pinned value does not reveal how much of it matched.
+/// Without it, they are only written not to exit early, as described under `eq()`.
+#[derive(Clone, Copy)]
+pub struct Fingerprint {
+    octets: [u8; MAX_LEN],
+    len: u8,
+}
+
+impl Fingerprint {
+    /// Make a fingerprint from the output of a hash function
+    ///
+    /// Fails if `digest` is empty or longer than 64 octets.
+    pub fn new(digest: &[u8]) -> Result<Self, InvalidFingerprint> {
+        if digest.is_empty() || digest.len() > MAX_LEN {
+            return Err(InvalidFingerprint);
+        }
+
+        let mut octets = [0; MAX_LEN];
+        octets[..digest.len()].copy_from_slice(digest);
+        Ok(Self {
+            octets,
+            len: digest.len() as u8,
+        })
+    }
+
+    fn write_hex(&self, f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+        for (i, octet) in self.as_ref().iter().enumerate() {
+            if i > 0 && !f.alternate() {
+                f.write_str(":")?;
+            }
+
+            match upper {
+                true => write!(f, "{octet:02X}")?,
+                false => write!(f, "{octet:02x}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl CertificateDer<'_> {
+    /// The SHA-256 digest of this certificate, as from `openssl x509 -fingerprint -sha256`
+    ///
+    /// The whole DER encoding is hashed.
+    ///
+    /// ```
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/ed25519.der");
+    /// let der = std::fs::read(path).unwrap();
+    /// let cert = CertificateDer::from(&der[..]);
+    ///
+    /// assert_eq!(
+    ///     format!("{:X}", cert.sha256_fingerprint()),
+    ///     "1F:5C:53:9B:3C:DA:3E:5C:74:8D:70:AB:3C:A8:13:CC:\
+    ///      DD:2E:1E:1A:3C:FC:45:55:0C:12:D2:A0:5B:67:20:00"
+    /// );
+    /// ```
+    pub fn sha256_fingerprint(&self) -> Fingerprint {
+        Fingerprint::sha256(self)
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl CertificateRevocationListDer<'_> {
+    /// The SHA-256 digest of this CRL's DER encoding
+    pub fn sha256_fingerprint(&self) -> Fingerprint {
+        Fingerprint::sha256(self)
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl SubjectPublicKeyInfoDer<'_> {
+    /// The SHA-256 digest of this public key's DER encoding, for public key pinning
+    ///
+    /// This is the digest used by HTTP Public Key Pinning (RFC 7469) and by the
+    /// `sha256//` pins of curl, which stay the same when a certificate is renewed with the same
+    /// key.
+    pub fn sha256_fingerprint(&self) -> Fingerprint {
+        Fingerprint::sha256(self)
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl Fingerprint {
+    fn sha256(der: &[u8]) -> Self {
+        let mut octets = [0; MAX_LEN];
+        octets[..32].copy_from_slice(&Sha256::digest(der));
+        Self { octets, len: 32 }
+    }
+}
+
+impl AsRef<[u8]> for Fingerprint {
+    fn as_ref(&self) -> &[u8] {
+        &self.octets[..usize::from(self.len)]
+    }
+}
+
+impl PartialEq for Fingerprint {
+    /// Compares the digests in constant time with the `subtle` feature
+    ///
+    /// Only the lengths, which are not secret, are compared with an early exit. With the
+    /// `subtle` feature, the octets are compared with `subtle::ConstantTimeEq`, which guards
+    /// against the optimizer reintroducing an early exit. Without it, the comparison is written
+    /// not to exit early at the first difference, but the optimizer is free to change that, so
+    /// it is not guaranteed to take constant time.
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "subtle")]
+        {
+            bool::from(self.as_ref().ct_eq(other.as_ref()))
+        }
+
+        #[cfg(not(feature = "subtle"))]
+        {
+            self.len == other.len
+                && self
+                    .as_ref()
+                    .iter()
+                    .zip(other.as_ref())
+                    .fold(0, |diff, (a, b)| diff | (a ^ b))
+                    == 0
+        }
+    }
+}
+
+impl Eq for Fingerprint {}
+
+impl Hash for Fingerprint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_hex(f, false)
+    }
+}
+
+impl fmt::UpperHex for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_hex(f, true)
+    }
+}
+
+impl fmt::Debug for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fingerprint({self})")
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = InvalidFingerprint;
+
+    /// Parse hex octets, either all separated by colons or not separated at all, in either case
+    fn from_str(text: &str) -> Result<Self, InvalidFingerprint> {
+        let text = text.as_bytes();
+        let (step, len) = match text.contains(&b':') {
+            // Each octet but the last is followed by a colon.
+            true => (3, (text.len() + 1) / 3),
+            false => (2, text.len() / 2),
+        };
+
+        if len == 0 || len > MAX_LEN || (len - 1) * step + 2 != text.len() {
+            return Err(InvalidFingerprint);
+        }
+
+        let mut octets = [0; MAX_LEN];
+        for (i, octet) in octets[..len].iter_mut().enumerate() {
+            let pair = &text[i * step..i * step + 2];
+            if !pair.iter().all(u8::is_ascii_hexdigit)
+                || (step == 3 && i > 0 && text[i * 3 - 1] != b':')
+            {
+                return Err(InvalidFingerprint);
+            }
+
+            *octet = hex_value(pair[0]) << 4 | hex_value(pair[1]);
+        }
+
+        Self::new(&octets[..len])
+    }
+}
+
+/// The value of an ASCII hex digit
+pub(crate) fn hex_value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        _ => (digit | 0x20) - b'a' + 10,
+    }
+}
+
+/// Serialized as lowercase hex separated by colons, as by the `Display` implementation
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fingerprint {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialized from hex, as by the `FromStr` implementation
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fingerprint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Fingerprint;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a fingerprint in hex")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<Fingerprint, E> {
+                text.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// A fingerprint was empty, too long or not valid hex
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidFingerprint;
+
+impl fmt::Display for InvalidFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid fingerprint")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidFingerprint {}
+
+/// The length of the longest supported digest, from SHA-512
+const MAX_LEN: usize = 64;
@@ -0,0 +1,83 @@
+//! Loading of gzip-compressed certificate revocation lists.
+
+use std::io::{self, Read};
+use std::path::Path;
+use std::{fmt, fs};
+
+use flate2::bufread::GzDecoder;
+
+use crate::CertificateRevocationListDer;
+
+impl CertificateRevocationListDer<'static> {
+    /// Decompress a gzip-compressed DER-encoded CRL
+    ///
+    /// Decompression stops with [`GzipError::TooLarge`] as soon as the output would exceed
+    /// `max_len` bytes, so that a small, highly compressible input cannot exhaust memory.
+    /// The decompressed contents are not checked to be a CRL.
+    ///
+    /// `compressed` must hold exactly one gzip member. Anything after it, including further
+    /// members (which `gunzip` would concatenate), is rejected with
+    /// [`GzipError::TrailingData`].
+    pub fn from_gzip_slice(compressed: &[u8], max_len: usize) -> Result<Self, GzipError> {
+        let mut decoder = GzDecoder::new(compressed).take((max_len as u64).saturating_add(1));
+        let mut der = Vec::new();
+        decoder.read_to_end(&mut der).map_err(GzipError::Corrupt)?;
+
+        if der.len() > max_len {
+            return Err(GzipError::TooLarge { max_len });
+        }
+
+        // The decoder reads no further than the end of the first member.
+        match decoder.into_inner().into_inner().is_empty() {
+            true => Ok(Self::from(der)),
+            false => Err(GzipError::TrailingData),
+        }
+    }
+
+    /// Read and decompress a gzip-compressed DER-encoded CRL from the file at `path`
+    ///
+    /// See [`CertificateRevocationListDer::from_gzip_slice()`].
+    pub fn from_gzip_file(path: impl AsRef<Path>, max_len: usize) -> Result<Self, GzipError> {
+        let compressed = fs::read(path).map_err(GzipError::Io)?;
+        Self::from_gzip_slice(&compressed, max_len)
+    }
+}
+
+/// An error loading a gzip-compressed CRL
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum GzipError {
+    /// The file could not be read
+    Io(io::Error),
+    /// The input was not valid gzip
+    Corrupt(io::Error),
+    /// The decompressed CRL would be longer than the given limit
+    TooLarge {
+        /// The limit, in bytes
+        max_len: usize,
+    },
+    /// There was more data after the end of the compressed CRL
+    TrailingData,
+}
+
+impl fmt::Display for GzipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read compressed CRL: {err}"),
+            Self::Corrupt(err) => write!(f, "invalid gzip data: {err}"),
+            Self::TooLarge { max_len } => {
+                write!(f, "decompressed CRL exceeds the limit of {max_len} bytes")
+            }
+            Self::TrailingData => f.write_str("unexpected data after the compressed CRL"),
+        }
+    }
+}
+
+impl std::error::Error for GzipError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) | Self::Corrupt(err) => Some(err),
+            Self::TooLarge { .. } | Self::TrailingData => None,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! IP networks in CIDR notation.
+
+use core::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// An IP network: an address prefix of a given length
+///
+/// Networks are written in CIDR notation, like `10.0.0.0/8` or `2001:db8::/32`. The address
+/// of a network never has bits set beyond the prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IpNet {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpNet {
+    /// Make a network from its address and prefix length
+    ///
+    /// Fails if `prefix_len` is longer than the address, or if `addr` has any bits set beyond
+    /// the prefix; see [`IpNet::new_truncated()`] to clear them instead.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Self, InvalidIpNet> {
+        let net = Self::new_truncated(addr, prefix_len)?;
+        match net.addr == addr {
+            true => Ok(net),
+            false => Err(InvalidIpNet::HostBitsSet),
+        }
+    }
+
+    /// Make a network from an address and prefix length, clearing the bits beyond the prefix
+    ///
+    /// Fails only if `prefix_len` is longer than the address.
+    pub fn new_truncated(addr: IpAddr, prefix_len: u8) -> Result<Self, InvalidIpNet> {
+        let addr = match addr {
+            IpAddr::V4(addr) if prefix_len <= 32 => {
+                IpAddr::V4(Ipv4Addr::from(u32::from(addr) & v4_mask(prefix_len)))
+            }
+            IpAddr::V6(addr) if prefix_len <= 128 => {
+                IpAddr::V6(Ipv6Addr::from(u128::from(addr) & v6_mask(prefix_len)))
+            }
+            _ => return Err(InvalidIpNet::PrefixTooLong),
+        };
+
+        Ok(Self { addr, prefix_len })
+    }
+
+    /// Decode a network from the `iPAddress` form of an X.509 name constraint
+    ///
+    /// RFC 5280 section 4.2.1.10 encodes a network as the address followed by a mask of the
+    /// same length: 8 octets for IPv4, and 32 for IPv6. The mask must be a contiguous run of
+    /// one bits followed by zero bits, and the address must not have bits set outside it.
+    pub fn from_name_constraint(bytes: &[u8]) -> Result<Self, InvalidIpNet> {
+        let (addr, mask) = bytes.split_at(bytes.len() / 2);
+        let (addr, mask) = match (<[u8; 4]>::try_from(addr), <[u8; 4]>::try_from(mask)) {
+            (Ok(addr), Ok(mask)) if bytes.len() == 8 => (
+                IpAddr::V4(Ipv4Addr::from(addr)),
+                u128::from(u32::from_be_bytes(mask)) << 96,
+            ),
+            _ => match (<[u8; 16]>::try_from(addr), <[u8; 16]>::try_from(mask)) {
+                (Ok(addr), Ok(mask)) if bytes.len() == 32 => {
+                    (IpAddr::V6(Ipv6Addr::from(addr)), u128::from_be_bytes(mask))
+                }
+                _ => return Err(InvalidIpNet::InvalidLength),
+            },
+        };
+
+        let prefix_len = mask.leading_ones();
+        if mask.checked_shl(prefix_len).unwrap_or(0) != 0 {
+            return Err(InvalidIpNet::NonContiguousMask);
+        }
+
+        Self::new(addr, prefix_len as u8)
+    }
+
+    /// Whether `addr` is within this network
+    ///
+    /// An IPv4 address is never within an IPv6 network, nor the other way around; this
+    /// includes IPv4-mapped IPv6 addresses.
+    pub fn contains(&self, addr: &IpAddr) -> bool {
+        match (self.addr, addr) {
+            (IpAddr::V4(net), IpAddr::V4(addr)) => {
+                u32::from(*addr) & v4_mask(self.prefix_len) == u32::from(net)
+            }
+            (IpAddr::V6(net), IpAddr::V6(addr)) => {
+                u128::from(*addr) & v6_mask(self.prefix_len) == u128::from(net)
+            }
+            _ => false,
+        }
+    }
+
+    /// The address of the network, with no bits set beyond the prefix
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// The length of the prefix, in bits
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+}
+
+impl TryFrom<&str> for IpNet {
+    type Error = InvalidIpNet;
+
+    /// Parse a network in CIDR notation, like `192.0.2.0/24`
+    ///
+    /// The prefix length is required, and the address must not have bits set beyond it.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (addr, prefix_len) = value.split_once('/').ok_or(InvalidIpNet::InvalidSyntax)?;
+        if prefix_len.is_empty() || !prefix_len.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(InvalidIpNet::InvalidSyntax);
+        }
+
+        let addr = addr.parse().map_err(|_| InvalidIpNet::InvalidSyntax)?;
+        let prefix_len = prefix_len
+            .parse()
+            .map_err(|_| InvalidIpNet::PrefixTooLong)?;
+        Self::new(addr, prefix_len)
+    }
+}
+
+impl fmt::Display for IpNet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+/// Serialized in CIDR notation, as by the `Display` implementation
+#[cfg(feature = "serde")]
+impl serde::Serialize for IpNet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialized from CIDR notation, as by the `TryFrom<&str>` implementation
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IpNet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = IpNet;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an IP network in CIDR notation")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<IpNet, E> {
+                IpNet::try_from(text).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+fn v4_mask(prefix_len: u8) -> u32 {
+    u32::MAX
+        .checked_shl(32 - u32::from(prefix_len))
+        .unwrap_or(0)
+}
+
+fn v6_mask(prefix_len: u8) -> u128 {
+    u128::MAX
+        .checked_shl(128 - u32::from(prefix_len))
+        .unwrap_or(0)
+}
+
+/// An error making an [`IpNet`]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidIpNet {
+    /// The text was not an address and a prefix length separated by `/`
+    InvalidSyntax,
+    /// The prefix length was longer than the address
+    PrefixTooLong,
+    /// The address had bits set beyond the prefix
+    HostBitsSet,
+    /// A name constraint had a length other than 8 or 32 octets
+    InvalidLength,
+    /// A name constraint's mask was not a contiguous prefix
+    NonContiguousMask,
+}
+
+impl fmt::Display for InvalidIpNet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax => f.write_str("malformed CIDR notation"),
+            Self::PrefixTooLong => f.write_str("prefix length is longer than the address"),
+            Self::HostBitsSet => f.write_str("address has bits set beyond the prefix"),
+            Self::InvalidLength => f.write_str("IP address name constraint has invalid length"),
+            Self::NonContiguousMask => {
+                f.write_str("IP address name constraint mask is not contiguous")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidIpNet {}
@@ -0,0 +1,207 @@
+//! Conversion of public keys to JSON Web Keys (JWK), as specified in RFC 7517.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::base64::{self, URL_SAFE};
+use crate::der::{self, InvalidDer, Reader};
+use crate::SubjectPublicKeyInfoDer;
+
+impl SubjectPublicKeyInfoDer<'_> {
+    /// Convert this public key to a JSON Web Key
+    ///
+    /// RSA, ECDSA (P-256 and P-384) and Ed25519 keys are supported. Only the parsing needed to
+    /// extract the key's parameters is done; in particular, EC points are not checked to be on
+    /// the curve.
+    pub fn to_jwk(&self) -> Result<Jwk, JwkError> {
+        let mut algorithm = Reader::new(self.algorithm()?);
+        let key = self.subject_public_key()?;
+        let oid = algorithm.read(der::OBJECT_IDENTIFIER)?;
+
+        match oid {
+            der::RSA_ENCRYPTION => {
+                let _null = algorithm.read_optional(der::NULL)?;
+                algorithm.finish()?;
+
+                let mut rsa = Reader::new(der::expect_single(key, der::SEQUENCE)?);
+                let n = unsigned(rsa.read(der::INTEGER)?)?;
+                let e = unsigned(rsa.read(der::INTEGER)?)?;
+                rsa.finish()?;
+
+                Ok(Jwk::Rsa {
+                    n: base64url(n),
+                    e: base64url(e),
+                })
+            }
+            der::EC_PUBLIC_KEY => {
+                let curve = algorithm.read(der::OBJECT_IDENTIFIER)?;
+                algorithm.finish()?;
+
+                let (crv, len) = match curve {
+                    der::SECP256R1 => ("P-256", 32),
+                    der::SECP384R1 => ("P-384", 48),
+                    _ => return Err(JwkError::UnsupportedCurve(curve.to_vec())),
+                };
+
+                // Only the uncompressed form is allowed in certificates (RFC 5480 section 2.2).
+                let point = match key.split_first() {
+                    Some((4, point)) if point.len() == 2 * len => point,
+                    _ => return Err(JwkError::InvalidKey),
+                };
+
+                let (x, y) = point.split_at(len);
+                Ok(Jwk::Ec {
+                    crv,
+                    x: base64url(x),
+                    y: base64url(y),
+                })
+            }
+            der::ED25519 => {
+                algorithm.finish()?;
+                if key.len() != 32 {
+                    return Err(JwkError::InvalidKey);
+                }
+
+                Ok(Jwk::Okp {
+                    crv: "Ed25519",
+                    x: base64url(key),
+                })
+            }
+            _ => Err(JwkError::UnsupportedAlgorithm(oid.to_vec())),
+        }
+    }
+}
+
+/// A public key as a JSON Web Key
+///
+/// The fields hold the key's parameters as unpadded base64url, as they appear in the JSON
+/// representation. When serialized, the members are written in lexicographic order, with no
+/// optional members; serialized as compact JSON, this is the input to the key's RFC 7638
+/// thumbprint.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Jwk {
+    /// An RSA public key, as specified in RFC 7518 section 6.3
+    Rsa {
+        /// The modulus
+        n: String,
+        /// The public exponent
+        e: String,
+    },
+    /// An elliptic curve public key, as specified in RFC 7518 section 6.2
+    Ec {
+        /// The curve, like `P-256`
+        crv: &'static str,
+        /// The x coordinate of the point
+        x: String,
+        /// The y coordinate of the point
+        y: String,
+    },
+    /// An octet key pair, as specified in RFC 8037
+    Okp {
+        /// The curve, like `Ed25519`
+        crv: &'static str,
+        /// The public key
+        x: String,
+    },
+}
+
+impl Jwk {
+    /// The key type, like `RSA`
+    pub fn kty(&self) -> &'static str {
+        match self {
+            Self::Rsa { .. } => "RSA",
+            Self::Ec { .. } => "EC",
+            Self::Okp { .. } => "OKP",
+        }
+    }
+}
+
+impl Serialize for Jwk {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Rsa { n, e } => {
+                let mut jwk = serializer.serialize_struct("Jwk", 3)?;
+                jwk.serialize_field("e", e.as_str())?;
+                jwk.serialize_field("kty", self.kty())?;
+                jwk.serialize_field("n", n.as_str())?;
+                jwk.end()
+            }
+            Self::Ec { crv, x, y } => {
+                let mut jwk = serializer.serialize_struct("Jwk", 4)?;
+                jwk.serialize_field("crv", crv)?;
+                jwk.serialize_field("kty", self.kty())?;
+                jwk.serialize_field("x", x.as_str())?;
+                jwk.serialize_field("y", y.as_str())?;
+                jwk.end()
+            }
+            Self::Okp { crv, x } => {
+                let mut jwk = serializer.serialize_struct("Jwk", 3)?;
+                jwk.serialize_field("crv", crv)?;
+                jwk.serialize_field("kty", self.kty())?;
+                jwk.serialize_field("x", x.as_str())?;
+                jwk.end()
+            }
+        }
+    }
+}
+
+/// The contents of a non-negative DER `INTEGER`, without the leading zero octet
+fn unsigned(int: &[u8]) -> Result<&[u8], JwkError> {
+    match int {
+        [0, rest @ ..] if !rest.is_empty() => Ok(rest),
+        [first, ..] if first & 0x80 == 0 => Ok(int),
+        _ => Err(JwkError::InvalidKey),
+    }
+}
+
+fn base64url(bytes: &[u8]) -> String {
+    base64::encode(bytes, URL_SAFE, false)
+}
+
+/// An error converting a public key to a JSON Web Key
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JwkError {
+    /// The structure of the `SubjectPublicKeyInfo` could not be decoded
+    InvalidDer(InvalidDer),
+    /// The key's algorithm is not supported
+    ///
+    /// This holds the contents of the algorithm's `OBJECT IDENTIFIER`.
+    UnsupportedAlgorithm(Vec<u8>),
+    /// The key is an EC key on a curve which is not supported
+    ///
+    /// This holds the contents of the curve's `OBJECT IDENTIFIER`.
+    UnsupportedCurve(Vec<u8>),
+    /// The key itself was malformed
+    InvalidKey,
+}
+
+impl From<InvalidDer> for JwkError {
+    fn from(err: InvalidDer) -> Self {
+        Self::InvalidDer(err)
+    }
+}
+
+impl fmt::Display for JwkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDer(err) => write!(f, "invalid SubjectPublicKeyInfo: {err}"),
+            Self::UnsupportedAlgorithm(oid) => {
+                f.write_str("unsupported public key algorithm ")?;
+                der::write_oid(f, oid)
+            }
+            Self::UnsupportedCurve(oid) => {
+                f.write_str("unsupported elliptic curve ")?;
+                der::write_oid(f, oid)
+            }
+            Self::InvalidKey => f.write_str("malformed public key"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JwkError {}
@@ -0,0 +1,171 @@
+//! Best-effort classification of private keys by their algorithm.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::convert::PrivateKeyInfo;
+use crate::der::{self, InvalidDer, Reader};
+use crate::{PrivateKeyDer, PrivatePkcs8KeyDer};
+
+impl PrivateKeyDer<'_> {
+    /// The algorithm of this key, with its size or curve where known
+    ///
+    /// Only the parsing needed to classify the key is done, so a key which yields an algorithm
+    /// may still be unusable.
+    ///
+    /// ```
+    /// use rustls_pki_types::{KeyAlgorithm, PrivateKeyDer, PrivatePkcs1KeyDer};
+    ///
+    /// // an RSA key with an 8-bit modulus
+    /// let key = PrivateKeyDer::from(PrivatePkcs1KeyDer::from(&[
+    ///     0x30, 0x0a, 0x02, 0x01, 0x00, 0x02, 0x02, 0x00, 0xc1, 0x02, 0x01, 0x03,
+    /// ][..]));
+    /// let algorithm = key.algorithm().unwrap();
+    /// assert_eq!(algorithm, KeyAlgorithm::Rsa { bits: 8 });
+    /// assert_eq!(algorithm.to_string(), "8-bit RSA");
+    /// ```
+    pub fn algorithm(&self) -> Result<KeyAlgorithm, InvalidDer> {
+        match self {
+            Self::Pkcs1(key) => rsa_algorithm(key.secret_pkcs1_der()),
+            Self::Sec1(key) => Ok(ec_algorithm(sec1_curve(key.secret_sec1_der())?)),
+            Self::Pkcs8(key) => key.algorithm(),
+        }
+    }
+}
+
+impl PrivatePkcs8KeyDer<'_> {
+    /// The algorithm of this key, with its size or curve where known
+    ///
+    /// This reads the `AlgorithmIdentifier`, and the key itself for the size of RSA keys.
+    ///
+    /// ```
+    /// use rustls_pki_types::{KeyAlgorithm, PrivatePkcs8KeyDer};
+    ///
+    /// let mut der = vec![
+    ///     0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22,
+    ///     0x04, 0x20,
+    /// ];
+    /// der.extend_from_slice(&[0x42; 32]);
+    /// let key = PrivatePkcs8KeyDer::from(der);
+    /// assert_eq!(key.algorithm().unwrap(), KeyAlgorithm::Ed25519);
+    /// ```
+    pub fn algorithm(&self) -> Result<KeyAlgorithm, InvalidDer> {
+        let info = PrivateKeyInfo::new(self.secret_pkcs8_der())?;
+        Ok(match info.algorithm {
+            der::RSA_ENCRYPTION => rsa_algorithm(info.private_key)?,
+            der::EC_PUBLIC_KEY => ec_algorithm(match info.parameters {
+                Some(parameters) => named_curve(parameters)?,
+                None => sec1_curve(info.private_key)?,
+            }),
+            der::ED25519 => KeyAlgorithm::Ed25519,
+            der::ED448 => KeyAlgorithm::Ed448,
+            der::X25519 => KeyAlgorithm::X25519,
+            der::X448 => KeyAlgorithm::X448,
+            oid => KeyAlgorithm::Unknown(oid.to_vec()),
+        })
+    }
+}
+
+/// The algorithm of a private key, from [`PrivateKeyDer::algorithm()`]
+///
+/// The `Display` implementation names the algorithm for humans, like `2048-bit RSA` or
+/// `ECDSA P-256`.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyAlgorithm {
+    /// An RSA key
+    Rsa {
+        /// The number of significant bits in the modulus
+        bits: usize,
+    },
+    /// An EC key on the P-256 curve
+    EcdsaP256,
+    /// An EC key on the P-384 curve
+    EcdsaP384,
+    /// An EC key on the P-521 curve
+    EcdsaP521,
+    /// An EC key on another curve, or which does not name its curve
+    Ec {
+        /// The contents of the curve's `OBJECT IDENTIFIER`, if named
+        curve: Option<Vec<u8>>,
+    },
+    /// An Ed25519 key
+    Ed25519,
+    /// An Ed448 key
+    Ed448,
+    /// An X25519 key
+    X25519,
+    /// An X448 key
+    X448,
+    /// A key with another algorithm
+    ///
+    /// This holds the contents of the algorithm's `OBJECT IDENTIFIER`.
+    Unknown(Vec<u8>),
+}
+
+impl fmt::Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rsa { bits } => write!(f, "{bits}-bit RSA"),
+            Self::EcdsaP256 => f.write_str("ECDSA P-256"),
+            Self::EcdsaP384 => f.write_str("ECDSA P-384"),
+            Self::EcdsaP521 => f.write_str("ECDSA P-521"),
+            Self::Ec { curve: Some(curve) } => {
+                f.write_str("ECDSA ")?;
+                der::write_oid(f, curve)
+            }
+            Self::Ec { curve: None } => f.write_str("ECDSA"),
+            Self::Ed25519 => f.write_str("Ed25519"),
+            Self::Ed448 => f.write_str("Ed448"),
+            Self::X25519 => f.write_str("X25519"),
+            Self::X448 => f.write_str("X448"),
+            Self::Unknown(oid) => der::write_oid(f, oid),
+        }
+    }
+}
+
+/// Classify a PKCS#1 `RSAPrivateKey` by the size of its modulus
+fn rsa_algorithm(pkcs1: &[u8]) -> Result<KeyAlgorithm, InvalidDer> {
+    let mut key = Reader::new(der::expect_single(pkcs1, der::SEQUENCE)?);
+    let _version = key.read(der::INTEGER)?;
+    let modulus = key.read(der::INTEGER)?;
+    let modulus = match modulus.iter().position(|&b| b != 0) {
+        Some(start) => &modulus[start..],
+        None => return Err(InvalidDer::InvalidValue),
+    };
+
+    Ok(KeyAlgorithm::Rsa {
+        bits: modulus.len() * 8 - modulus[0].leading_zeros() as usize,
+    })
+}
+
+fn ec_algorithm(curve: Option<&[u8]>) -> KeyAlgorithm {
+    match curve {
+        Some(der::SECP256R1) => KeyAlgorithm::EcdsaP256,
+        Some(der::SECP384R1) => KeyAlgorithm::EcdsaP384,
+        Some(der::SECP521R1) => KeyAlgorithm::EcdsaP521,
+        curve => KeyAlgorithm::Ec {
+            curve: curve.map(<[u8]>::to_vec),
+        },
+    }
+}
+
+/// The named curve in the `parameters` of a SEC1 `ECPrivateKey`, if any
+fn sec1_curve(sec1: &[u8]) -> Result<Option<&[u8]>, InvalidDer> {
+    let mut key = Reader::new(der::expect_single(sec1, der::SEQUENCE)?);
+    let _version = key.read(der::INTEGER)?;
+    let _private_key = key.read(der::OCTET_STRING)?;
+    match key.read_optional(der::context_constructed(0))? {
+        Some(parameters) => named_curve(parameters),
+        None => Ok(None),
+    }
+}
+
+/// The contents of the `OBJECT IDENTIFIER` in encoded EC `parameters`, if they name a curve
+fn named_curve(parameters: &[u8]) -> Result<Option<&[u8]>, InvalidDer> {
+    let mut parameters = Reader::new(parameters);
+    match parameters.peek_tag() {
+        Some(der::OBJECT_IDENTIFIER) => parameters.read(der::OBJECT_IDENTIFIER).map(Some),
+        _ => Ok(None),
+    }
+}
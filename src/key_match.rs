@@ -0,0 +1,97 @@
+//! Checking that a private key belongs to a certificate.
+
+use crate::convert::PrivateKeyInfo;
+use crate::der::{self, InvalidDer, Reader};
+use crate::{CertificateDer, KeyConversionError, PrivateKeyDer, SubjectPublicKeyInfoDer};
+
+impl PrivateKeyDer<'_> {
+    /// Check whether this key is the private key for the public key in `cert`
+    ///
+    /// This compares the public key included with this key, as found by
+    /// [`PrivateKeyDer::subject_public_key_info()`], to the certificate's. No cryptography is
+    /// done, so the public key included with a key is trusted to belong to it, and keys which
+    /// do not include their public key, like Ed25519 keys written by `openssl genpkey`, yield
+    /// [`KeyMatch::Indeterminate`] unless their algorithm differs from the certificate's. This
+    /// catches a certificate and key that were mixed up, but not a key that was tampered with.
+    ///
+    /// RSA keys always yield [`KeyMatch::Indeterminate`] for RSA certificates: their modulus
+    /// can be compared, but checking that the private exponent and primes belong to it needs
+    /// arithmetic.
+    pub fn matches_certificate(&self, cert: &CertificateDer<'_>) -> Result<KeyMatch, InvalidDer> {
+        let spans = cert.spans()?;
+        let cert_key = spans.subject_public_key_info();
+        let algorithm = self.algorithm_oid()?;
+        let key = match self.subject_public_key_info() {
+            Ok(_) if algorithm == der::RSA_ENCRYPTION => None,
+            Ok(spki) => Some(spki),
+            Err(KeyConversionError::InvalidKey(err)) => return Err(err),
+            Err(_) => None,
+        };
+
+        let key = match key {
+            Some(key) => key,
+            None => match key_algorithm(&cert_key)?.0 == algorithm {
+                true => return Ok(KeyMatch::Indeterminate),
+                false => return Ok(KeyMatch::Mismatch),
+            },
+        };
+
+        match same_public_key(&key, &cert_key)? {
+            true => Ok(KeyMatch::Matches),
+            false => Ok(KeyMatch::Mismatch),
+        }
+    }
+
+    /// The contents of the `OBJECT IDENTIFIER` of this key's algorithm
+    fn algorithm_oid(&self) -> Result<&[u8], InvalidDer> {
+        match self {
+            Self::Pkcs1(_) => Ok(der::RSA_ENCRYPTION),
+            Self::Sec1(_) => Ok(der::EC_PUBLIC_KEY),
+            Self::Pkcs8(key) => Ok(PrivateKeyInfo::new(key.secret_pkcs8_der())?.algorithm),
+        }
+    }
+}
+
+/// Whether a private key belongs to a certificate, from [`PrivateKeyDer::matches_certificate()`]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyMatch {
+    /// The key's public key is the certificate's
+    Matches,
+    /// The key's public key is not the certificate's
+    Mismatch,
+    /// The key does not include its public key, or is an RSA key, so it could not be compared
+    Indeterminate,
+}
+
+/// Whether `a` and `b` hold the same public key
+///
+/// The algorithm parameters are only compared for EC keys, where they name the curve: for RSA
+/// keys, they may be either absent or `NULL`.
+fn same_public_key(
+    a: &SubjectPublicKeyInfoDer<'_>,
+    b: &SubjectPublicKeyInfoDer<'_>,
+) -> Result<bool, InvalidDer> {
+    let (a_oid, a_parameters) = key_algorithm(a)?;
+    let (b_oid, b_parameters) = key_algorithm(b)?;
+    if a_oid != b_oid || a.subject_public_key()? != b.subject_public_key()? {
+        return Ok(false);
+    }
+
+    Ok(a_oid != der::EC_PUBLIC_KEY || a_parameters == b_parameters)
+}
+
+/// The algorithm `OBJECT IDENTIFIER` of `spki`, and the complete encoding of its parameters
+fn key_algorithm<'a>(
+    spki: &'a SubjectPublicKeyInfoDer<'_>,
+) -> Result<(&'a [u8], Option<&'a [u8]>), InvalidDer> {
+    let mut algorithm = Reader::new(spki.algorithm()?);
+    let oid = algorithm.read(der::OBJECT_IDENTIFIER)?;
+    let parameters = match algorithm.peek_tag() {
+        Some(_) => Some(algorithm.read_any()?.encoded),
+        None => None,
+    };
+    algorithm.finish()?;
+
+    Ok((oid, parameters))
+}
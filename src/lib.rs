@@ -19,7 +19,18 @@
 //! base64-encoded DER, PEM objects are delimited by header and footer lines which indicate the type
 //! of object contained in the PEM blob.
 //!
-//! The [rustls-pemfile](https://docs.rs/rustls-pemfile) crate can be used to parse PEM files.
+//! With the `pem` feature, the `pem::PemObject` trait can be used to load certificates, CRLs
+//! and keys from PEM files. The [rustls-pemfile](https://docs.rs/rustls-pemfile) crate can be
+//! used to parse other PEM files.
+//!
+//! ## Serializing private keys
+//!
+//! The `serde` feature only covers public types. Private keys implement `Serialize` and
+//! `Deserialize` with the separate `serde-secrets` feature, so that secrets are never written out
+//! by accident through a type that derives `Serialize`. Keys are serialized as their DER encoding:
+//! as bytes for binary formats, and as base64 for human-readable formats, with
+//! [`PrivateKeyDer`] tagged by its variant (`{"Pkcs8":"MC4CAQAw..."}` in JSON). They deserialize
+//! into owned `'static` values. Copies of the key made by serializers are not wiped.
 //!
 //! ## Creating new certificates and keys
 //!
@@ -27,6 +38,7 @@
 //! the [rcgen](https://docs.rs/rcgen) crate can be used to create new certificates and keys.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![forbid(unsafe_code)]
 #![warn(unreachable_pub, clippy::use_self)]
 #![deny(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
@@ -34,17 +46,181 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::Deref;
 use core::time::Duration;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+pub mod alg_id;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+
+mod memory;
+pub use memory::MemoryUsage;
+
+mod validity;
+pub use validity::{InvalidValidityPeriod, ValidityPeriod};
+
+mod fingerprint;
+pub use fingerprint::{Fingerprint, InvalidFingerprint};
+
+#[doc(hidden)]
+pub mod embed;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod pool;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use pool::CertificatePool;
+
+#[cfg(feature = "alloc")]
+mod base64;
+#[cfg(all(feature = "alloc", feature = "x509"))]
+pub use base64::Base64DerError;
+#[cfg(feature = "alloc")]
+pub use base64::InvalidBase64;
+#[cfg(feature = "pem")]
+pub mod pem;
+// Without the `pem` feature, the loaders for files and directories still read PEM.
+#[cfg(all(feature = "std", not(feature = "pem")))]
+#[allow(dead_code, unreachable_pub)]
+mod pem;
+#[cfg(all(feature = "pem", feature = "std"))]
+pub mod pemfile;
+
+#[cfg(feature = "alloc")]
+mod chain;
+#[cfg(feature = "alloc")]
+mod convert;
+#[cfg(feature = "alloc")]
+pub use chain::{CertificateChainDer, X5cError};
+#[cfg(all(feature = "x509", feature = "alloc"))]
+pub use chain::{ChainError, ChainNormalization};
+#[cfg(feature = "alloc")]
+pub use convert::{KeyConversionError, Pkcs8Version};
+#[cfg(feature = "alloc")]
+mod key_algorithm;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+mod serde_anchor;
+#[cfg(all(feature = "alloc", any(feature = "serde", feature = "serde-secrets")))]
+mod serde_der;
+#[cfg(feature = "serde-secrets")]
+mod serde_secrets;
+#[cfg(feature = "alloc")]
+pub use key_algorithm::KeyAlgorithm;
+#[cfg(all(feature = "x509", feature = "alloc"))]
+mod key_match;
+#[cfg(all(feature = "x509", feature = "alloc"))]
+pub use key_match::KeyMatch;
+
+#[cfg(feature = "alloc")]
+mod trust_store;
+#[cfg(feature = "alloc")]
+pub use trust_store::TrustStore;
+
+#[cfg(feature = "alloc")]
+mod tlsa;
+#[cfg(feature = "alloc")]
+pub use tlsa::{CertUsage, InvalidTlsaRecord, MatchingType, Selector, TlsaRecord};
+
+#[cfg(feature = "jwk")]
+mod jwk;
+#[cfg(feature = "jwk")]
+pub use jwk::{Jwk, JwkError};
+
+#[cfg(feature = "gzip")]
+mod gzip;
+#[cfg(feature = "gzip")]
+pub use gzip::GzipError;
+
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "std")]
+pub use cache::{CacheError, MAX_CACHED_ENTRY_LEN};
+
+#[cfg(all(feature = "std", feature = "zeroize"))]
+mod encrypt;
+#[cfg(all(feature = "std", feature = "zeroize"))]
+pub use encrypt::{EncryptError, KeyEncryptor};
+
+#[cfg(feature = "std")]
+mod ipnet;
+#[cfg(feature = "std")]
+pub use ipnet::{InvalidIpNet, IpNet};
+
+#[cfg(all(feature = "std", feature = "zeroize"))]
+mod write;
+
+#[cfg(feature = "std")]
+mod load;
+#[cfg(all(feature = "std", feature = "x509"))]
+pub use load::RevocationDirIter;
+#[cfg(feature = "std")]
+pub use load::{load_native_cert_sources, CertificateDirIter, LoadError, NativeCertSources};
+
+#[cfg(any(feature = "x509", feature = "alloc"))]
+pub mod der;
+#[cfg(any(feature = "x509", feature = "alloc"))]
+pub use der::InvalidDer;
+
+#[cfg(any(feature = "x509", feature = "alloc"))]
+mod anchor;
+#[cfg(any(feature = "x509", feature = "alloc"))]
+pub use anchor::InvalidTrustAnchor;
+
+#[cfg(all(feature = "x509", feature = "alloc"))]
+mod crl_set;
+#[cfg(all(feature = "x509", feature = "alloc"))]
+pub use crl_set::CrlSet;
+
+#[cfg(all(feature = "x509", feature = "alloc"))]
+mod diff;
+#[cfg(all(feature = "x509", feature = "alloc"))]
+pub use diff::{diff_anchors, AnchorDiff};
+
+#[cfg(feature = "x509")]
+mod extensions;
+#[cfg(feature = "x509")]
+pub use extensions::{BasicConstraints, ExtendedKeyUsage, KeyPurposes, KeyUsage};
+
+#[cfg(feature = "mozilla-certdata")]
+pub mod mozilla_certdata;
+
+#[cfg(all(feature = "x509", feature = "std"))]
+mod san;
+#[cfg(all(feature = "x509", feature = "std"))]
+pub use san::{SubjectAltName, SubjectAltNames};
+
+#[cfg(feature = "x509")]
+mod sct;
+#[cfg(feature = "x509")]
+pub use sct::{InvalidSct, Sct, SctIter, SctList};
+
+#[cfg(feature = "x509")]
+mod summary;
+#[cfg(feature = "x509")]
+pub use summary::{CertificateSummary, CrlSummary};
+
+#[cfg(feature = "x509")]
+mod x509;
+#[cfg(feature = "x509")]
+pub use x509::{CertificateSpans, CrlSpans, RevokedSerials, SerialNumber};
+
 /// A DER-encoded X.509 private key, in one of several formats
 ///
-/// See variant inner types for more detailed information.
+/// See variant inner types for more detailed information. With the `zeroize` feature, owned
+/// keys are overwritten with zeros when they are dropped. Borrowed keys are not: the memory
+/// they borrow belongs to the caller, who must wipe it.
 #[non_exhaustive]
 #[derive(Debug, PartialEq)]
 pub enum PrivateKeyDer<'a> {
@@ -65,8 +241,134 @@ impl<'a> PrivateKeyDer<'a> {
             PrivateKeyDer::Pkcs8(key) => key.secret_pkcs8_der(),
         }
     }
+
+    /// Show the secret bytes of the key in `Debug` output
+    ///
+    /// The `Debug` output of a private key only gives its type and length, so that keys are
+    /// not written to logs by accident. This is intended for local debugging only: the
+    /// returned value writes the key's type and its bytes.
+    ///
+    /// ```
+    /// use rustls_pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer};
+    ///
+    /// let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(&[0x30, 0x00][..]));
+    /// assert_eq!(
+    ///     format!("{key:?}"),
+    ///     "Pkcs8(PrivatePkcs8KeyDer(2 bytes, secret elided))"
+    /// );
+    /// assert_eq!(
+    ///     format!("{:?}", key.debug_dangerous()),
+    ///     "PrivatePkcs8KeyDer([48, 0])"
+    /// );
+    /// ```
+    pub fn debug_dangerous(&self) -> impl fmt::Debug + '_ {
+        match self {
+            PrivateKeyDer::Pkcs1(key) => {
+                DangerousDebug("PrivatePkcs1KeyDer", key.secret_pkcs1_der())
+            }
+            PrivateKeyDer::Sec1(key) => DangerousDebug("PrivateSec1KeyDer", key.secret_sec1_der()),
+            PrivateKeyDer::Pkcs8(key) => {
+                DangerousDebug("PrivatePkcs8KeyDer", key.secret_pkcs8_der())
+            }
+        }
+    }
+
+    /// Copy the DER-encoded bytes of the private key into a buffer that is wiped when dropped
+    ///
+    /// This is for APIs that need an owned buffer, like FFI layers.
+    ///
+    /// ```
+    /// use rustls_pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer};
+    ///
+    /// let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(&[0x30, 0x00][..]));
+    /// let copy = key.secret_der_owned();
+    /// assert_eq!(&copy[..], key.secret_der());
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub fn secret_der_owned(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.secret_der().to_vec())
+    }
+
+    /// Yield the DER-encoded bytes of the private key, copying them only if they are borrowed
+    ///
+    /// The buffer of an owned key is moved out, not copied, and is no longer wiped by this
+    /// crate: wiping it becomes the caller's responsibility. With the `zeroize` feature, wrap
+    /// it in `zeroize::Zeroizing`, or use `PrivateKeyDer::secret_der_owned()` instead.
+    ///
+    /// ```
+    /// use rustls_pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer};
+    ///
+    /// let der = vec![0x30, 0x00];
+    /// let ptr = der.as_ptr();
+    /// let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(der));
+    /// assert_eq!(key.into_secret_vec().as_ptr(), ptr);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_secret_vec(self) -> Vec<u8> {
+        match self {
+            PrivateKeyDer::Pkcs1(mut key) => key.0.take().into_vec(),
+            PrivateKeyDer::Sec1(mut key) => key.0.take().into_vec(),
+            PrivateKeyDer::Pkcs8(mut key) => key.0.take().into_vec(),
+        }
+    }
+
+    /// Borrow this key without copying the secret
+    ///
+    /// The returned key refers to the same bytes as `self`, so it can be passed to APIs taking
+    /// a `PrivateKeyDer` by value while `self` retains ownership.
+    pub fn borrow_key(&self) -> PrivateKeyDer<'_> {
+        match self {
+            PrivateKeyDer::Pkcs1(key) => {
+                PrivateKeyDer::Pkcs1(PrivatePkcs1KeyDer(key.0.as_borrowed()))
+            }
+            PrivateKeyDer::Sec1(key) => PrivateKeyDer::Sec1(PrivateSec1KeyDer(key.0.as_borrowed())),
+            PrivateKeyDer::Pkcs8(key) => {
+                PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer(key.0.as_borrowed()))
+            }
+        }
+    }
+
+    /// Yield a `'static` lifetime of the key, copying the secret only if it is borrowed
+    ///
+    /// An owned key is moved, not reallocated.
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(self) -> PrivateKeyDer<'static> {
+        match self {
+            PrivateKeyDer::Pkcs1(key) => PrivateKeyDer::Pkcs1(key.into_owned()),
+            PrivateKeyDer::Sec1(key) => PrivateKeyDer::Sec1(key.into_owned()),
+            PrivateKeyDer::Pkcs8(key) => PrivateKeyDer::Pkcs8(key.into_owned()),
+        }
+    }
+
+    /// Make an owned copy of this key
+    ///
+    /// Private keys do not implement `Clone`, so that copies of the secret are not made by
+    /// accident. This allocates a copy of the secret, whether `self` is borrowed or owned.
+    #[cfg(feature = "alloc")]
+    pub fn clone_key(&self) -> PrivateKeyDer<'static> {
+        match self {
+            PrivateKeyDer::Pkcs1(key) => PrivateKeyDer::Pkcs1(key.clone_key()),
+            PrivateKeyDer::Sec1(key) => PrivateKeyDer::Sec1(key.clone_key()),
+            PrivateKeyDer::Pkcs8(key) => PrivateKeyDer::Pkcs8(key.clone_key()),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for PrivateKeyDer<'_> {
+    fn zeroize(&mut self) {
+        match self {
+            PrivateKeyDer::Pkcs1(key) => key.zeroize(),
+            PrivateKeyDer::Sec1(key) => key.zeroize(),
+            PrivateKeyDer::Pkcs8(key) => key.zeroize(),
+        }
+    }
 }
 
+/// Each variant is zeroized when dropped
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for PrivateKeyDer<'_> {}
+
 impl<'a> From<PrivatePkcs1KeyDer<'a>> for PrivateKeyDer<'a> {
     fn from(key: PrivatePkcs1KeyDer<'a>) -> Self {
         Self::Pkcs1(key)
@@ -85,6 +387,96 @@ impl<'a> From<PrivatePkcs8KeyDer<'a>> for PrivateKeyDer<'a> {
     }
 }
 
+impl<'a> TryFrom<PrivateKeyDer<'a>> for PrivatePkcs1KeyDer<'a> {
+    type Error = PrivateKeyDer<'a>;
+
+    /// Yield the key if it is a PKCS#1 key, or else give it back
+    fn try_from(key: PrivateKeyDer<'a>) -> Result<Self, Self::Error> {
+        match key {
+            PrivateKeyDer::Pkcs1(key) => Ok(key),
+            other => Err(other),
+        }
+    }
+}
+
+impl<'a> TryFrom<PrivateKeyDer<'a>> for PrivateSec1KeyDer<'a> {
+    type Error = PrivateKeyDer<'a>;
+
+    /// Yield the key if it is a SEC1 key, or else give it back
+    fn try_from(key: PrivateKeyDer<'a>) -> Result<Self, Self::Error> {
+        match key {
+            PrivateKeyDer::Sec1(key) => Ok(key),
+            other => Err(other),
+        }
+    }
+}
+
+impl<'a> TryFrom<PrivateKeyDer<'a>> for PrivatePkcs8KeyDer<'a> {
+    type Error = PrivateKeyDer<'a>;
+
+    /// Yield the key if it is a PKCS#8 key, or else give it back
+    fn try_from(key: PrivateKeyDer<'a>) -> Result<Self, Self::Error> {
+        match key {
+            PrivateKeyDer::Pkcs8(key) => Ok(key),
+            other => Err(other),
+        }
+    }
+}
+
+#[cfg(any(feature = "x509", feature = "alloc"))]
+impl<'a> TryFrom<&'a [u8]> for PrivateKeyDer<'a> {
+    type Error = InvalidDer;
+
+    /// Classify a DER-encoded private key by its structure
+    ///
+    /// All three formats start with a version `INTEGER`. It is followed by the
+    /// `AlgorithmIdentifier` `SEQUENCE` in PKCS#8 (version 0, or 1 for RFC 5958), by the
+    /// `privateKey` `OCTET STRING` in SEC1 (version 1), and by the modulus `INTEGER` in PKCS#1
+    /// (version 0, or 1 for multi-prime keys). Only the outer `SEQUENCE`, which must span all
+    /// of `der`, and these two fields are decoded.
+    fn try_from(der: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(match key_format(der)? {
+            KeyFormat::Pkcs1 => Self::Pkcs1(PrivatePkcs1KeyDer::from(der)),
+            KeyFormat::Sec1 => Self::Sec1(PrivateSec1KeyDer::from(der)),
+            KeyFormat::Pkcs8 => Self::Pkcs8(PrivatePkcs8KeyDer::from(der)),
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<Vec<u8>> for PrivateKeyDer<'static> {
+    type Error = InvalidDer;
+
+    /// Classify a DER-encoded private key by its structure, as for `&[u8]`
+    fn try_from(der: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(match key_format(&der)? {
+            KeyFormat::Pkcs1 => Self::Pkcs1(PrivatePkcs1KeyDer::from(der)),
+            KeyFormat::Sec1 => Self::Sec1(PrivateSec1KeyDer::from(der)),
+            KeyFormat::Pkcs8 => Self::Pkcs8(PrivatePkcs8KeyDer::from(der)),
+        })
+    }
+}
+
+/// Tell which format a private key is in, from its first two fields
+#[cfg(any(feature = "x509", feature = "alloc"))]
+fn key_format(der: &[u8]) -> Result<KeyFormat, InvalidDer> {
+    let mut key = der::Reader::new(der::expect_single(der, der::SEQUENCE)?);
+    let version = key.read(der::INTEGER)?;
+    match (version, key.read_any()?.tag) {
+        ([0] | [1], der::SEQUENCE) => Ok(KeyFormat::Pkcs8),
+        ([1], der::OCTET_STRING) => Ok(KeyFormat::Sec1),
+        ([0] | [1], der::INTEGER) => Ok(KeyFormat::Pkcs1),
+        _ => Err(InvalidDer::InvalidValue),
+    }
+}
+
+#[cfg(any(feature = "x509", feature = "alloc"))]
+enum KeyFormat {
+    Pkcs1,
+    Sec1,
+    Pkcs8,
+}
+
 /// A DER-encoded plaintext RSA private key; as specified in PKCS#1/RFC 3447
 ///
 /// RSA private keys are identified in PEM context as `RSA PRIVATE KEY` and when stored in a
@@ -93,153 +485,1228 @@ impl<'a> From<PrivatePkcs8KeyDer<'a>> for PrivateKeyDer<'a> {
 #[derive(PartialEq)]
 pub struct PrivatePkcs1KeyDer<'a>(Der<'a>);
 
+impl<'a> PrivatePkcs1KeyDer<'a> {
+    /// Make a key from `bytes`, checking that they are a single DER `SEQUENCE`
+    ///
+    /// See [`CertificateDer::try_from_der()`].
+    #[cfg(any(feature = "x509", feature = "alloc"))]
+    pub fn try_from_der(bytes: &'a [u8]) -> Result<Self, InvalidDer> {
+        der::check_framing(bytes, der::SEQUENCE)?;
+        Ok(Self::from(bytes))
+    }
+}
+
 impl PrivatePkcs1KeyDer<'_> {
     /// Yield the DER-encoded bytes of the private key
     pub fn secret_pkcs1_der(&self) -> &[u8] {
         self.0.as_ref()
     }
+
+    /// Show the secret bytes of the key in `Debug` output
+    ///
+    /// See [`PrivateKeyDer::debug_dangerous()`].
+    pub fn debug_dangerous(&self) -> impl fmt::Debug + '_ {
+        DangerousDebug("PrivatePkcs1KeyDer", self.secret_pkcs1_der())
+    }
+
+    /// Yield a `'static` lifetime of the key, copying the secret only if it is borrowed
+    ///
+    /// See [`PrivateKeyDer::into_owned()`].
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(mut self) -> PrivatePkcs1KeyDer<'static> {
+        PrivatePkcs1KeyDer(self.0.take().into_owned())
+    }
+
+    /// Make an owned copy of this key
+    ///
+    /// See [`PrivateKeyDer::clone_key()`].
+    #[cfg(feature = "alloc")]
+    pub fn clone_key(&self) -> PrivatePkcs1KeyDer<'static> {
+        PrivatePkcs1KeyDer::from(self.secret_pkcs1_der().to_vec())
+    }
+}
+
+impl<'a> From<&'a [u8]> for PrivatePkcs1KeyDer<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self(Der(DerInner::Borrowed(slice)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<u8>> for PrivatePkcs1KeyDer<'static> {
+    fn from(vec: Vec<u8>) -> Self {
+        Self(Der(DerInner::Owned(vec)))
+    }
+}
+
+/// Owned contents are overwritten with zeros; borrowed contents are left alone
+#[cfg(feature = "zeroize")]
+impl Zeroize for PrivatePkcs1KeyDer<'_> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for PrivatePkcs1KeyDer<'_> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for PrivatePkcs1KeyDer<'_> {}
+
+impl fmt::Debug for PrivatePkcs1KeyDer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PrivatePkcs1KeyDer({} bytes, secret elided)",
+            self.secret_pkcs1_der().len()
+        )
+    }
+}
+
+/// A Sec1-encoded plaintext private key; as specified in RFC 5915
+///
+/// Sec1 private keys are identified in PEM context as `EC PRIVATE KEY` and when stored in a
+/// file usually use a `.pem` or `.key` extension. For more on PEM files, refer to the crate
+/// documentation.
+#[derive(PartialEq)]
+pub struct PrivateSec1KeyDer<'a>(Der<'a>);
+
+impl<'a> PrivateSec1KeyDer<'a> {
+    /// Make a key from `bytes`, checking that they are a single DER `SEQUENCE`
+    ///
+    /// See [`CertificateDer::try_from_der()`].
+    #[cfg(any(feature = "x509", feature = "alloc"))]
+    pub fn try_from_der(bytes: &'a [u8]) -> Result<Self, InvalidDer> {
+        der::check_framing(bytes, der::SEQUENCE)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl PrivateSec1KeyDer<'_> {
+    /// Yield the DER-encoded bytes of the private key
+    pub fn secret_sec1_der(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+
+    /// Show the secret bytes of the key in `Debug` output
+    ///
+    /// See [`PrivateKeyDer::debug_dangerous()`].
+    pub fn debug_dangerous(&self) -> impl fmt::Debug + '_ {
+        DangerousDebug("PrivateSec1KeyDer", self.secret_sec1_der())
+    }
+
+    /// Yield a `'static` lifetime of the key, copying the secret only if it is borrowed
+    ///
+    /// See [`PrivateKeyDer::into_owned()`].
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(mut self) -> PrivateSec1KeyDer<'static> {
+        PrivateSec1KeyDer(self.0.take().into_owned())
+    }
+
+    /// Make an owned copy of this key
+    ///
+    /// See [`PrivateKeyDer::clone_key()`].
+    #[cfg(feature = "alloc")]
+    pub fn clone_key(&self) -> PrivateSec1KeyDer<'static> {
+        PrivateSec1KeyDer::from(self.secret_sec1_der().to_vec())
+    }
+}
+
+impl<'a> From<&'a [u8]> for PrivateSec1KeyDer<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self(Der(DerInner::Borrowed(slice)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<u8>> for PrivateSec1KeyDer<'static> {
+    fn from(vec: Vec<u8>) -> Self {
+        Self(Der(DerInner::Owned(vec)))
+    }
+}
+
+/// Owned contents are overwritten with zeros; borrowed contents are left alone
+#[cfg(feature = "zeroize")]
+impl Zeroize for PrivateSec1KeyDer<'_> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for PrivateSec1KeyDer<'_> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for PrivateSec1KeyDer<'_> {}
+
+impl fmt::Debug for PrivateSec1KeyDer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PrivateSec1KeyDer({} bytes, secret elided)",
+            self.secret_sec1_der().len()
+        )
+    }
+}
+
+/// A DER-encoded plaintext private key; as specified in PKCS#8/RFC 5958
+///
+/// PKCS#8 private keys are identified in PEM context as `PRIVATE KEY` and when stored in a
+/// file usually use a `.pem` or `.key` extension. For more on PEM files, refer to the crate
+/// documentation.
+#[derive(PartialEq)]
+pub struct PrivatePkcs8KeyDer<'a>(Der<'a>);
+
+impl<'a> PrivatePkcs8KeyDer<'a> {
+    /// Make a key from `bytes`, checking that they are a single DER `SEQUENCE`
+    ///
+    /// See [`CertificateDer::try_from_der()`].
+    #[cfg(any(feature = "x509", feature = "alloc"))]
+    pub fn try_from_der(bytes: &'a [u8]) -> Result<Self, InvalidDer> {
+        der::check_framing(bytes, der::SEQUENCE)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl PrivatePkcs8KeyDer<'_> {
+    /// Yield the DER-encoded bytes of the private key
+    pub fn secret_pkcs8_der(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+
+    /// Show the secret bytes of the key in `Debug` output
+    ///
+    /// See [`PrivateKeyDer::debug_dangerous()`].
+    pub fn debug_dangerous(&self) -> impl fmt::Debug + '_ {
+        DangerousDebug("PrivatePkcs8KeyDer", self.secret_pkcs8_der())
+    }
+
+    /// Yield a `'static` lifetime of the key, copying the secret only if it is borrowed
+    ///
+    /// See [`PrivateKeyDer::into_owned()`].
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(mut self) -> PrivatePkcs8KeyDer<'static> {
+        PrivatePkcs8KeyDer(self.0.take().into_owned())
+    }
+
+    /// Make an owned copy of this key
+    ///
+    /// See [`PrivateKeyDer::clone_key()`].
+    #[cfg(feature = "alloc")]
+    pub fn clone_key(&self) -> PrivatePkcs8KeyDer<'static> {
+        PrivatePkcs8KeyDer::from(self.secret_pkcs8_der().to_vec())
+    }
+}
+
+impl<'a> From<&'a [u8]> for PrivatePkcs8KeyDer<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self(Der(DerInner::Borrowed(slice)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<u8>> for PrivatePkcs8KeyDer<'static> {
+    fn from(vec: Vec<u8>) -> Self {
+        Self(Der(DerInner::Owned(vec)))
+    }
+}
+
+/// Owned contents are overwritten with zeros; borrowed contents are left alone
+#[cfg(feature = "zeroize")]
+impl Zeroize for PrivatePkcs8KeyDer<'_> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for PrivatePkcs8KeyDer<'_> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for PrivatePkcs8KeyDer<'_> {}
+
+impl fmt::Debug for PrivatePkcs8KeyDer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PrivatePkcs8KeyDer({} bytes, secret elided)",
+            self.secret_pkcs8_der().len()
+        )
+    }
+}
+
+/// Writes the type and the bytes of a private key, from `debug_dangerous()`
+struct DangerousDebug<'a>(&'static str, &'a [u8]);
+
+impl fmt::Debug for DangerousDebug<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(self.0).field(&self.1).finish()
+    }
+}
+
+/// A DER-encoded encrypted private key; as specified in PKCS#8/RFC 5958
+///
+/// This is an `EncryptedPrivateKeyInfo` structure, identified in PEM context as
+/// `ENCRYPTED PRIVATE KEY`. This crate does not encrypt or decrypt keys: to this crate, the
+/// contents are opaque ciphertext, to be passed to something that can decrypt them, like a
+/// KMS. Decrypting them yields a [`PrivatePkcs8KeyDer`]; they are not one themselves.
+#[derive(Clone, PartialEq)]
+pub struct EncryptedPrivateKeyInfoDer<'a>(Der<'a>);
+
+impl EncryptedPrivateKeyInfoDer<'_> {
+    /// Yield a `'static` lifetime of the encrypted key, copying the contents only if they are
+    /// borrowed
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(self) -> EncryptedPrivateKeyInfoDer<'static> {
+        EncryptedPrivateKeyInfoDer(self.0.into_owned())
+    }
+}
+
+impl AsRef<[u8]> for EncryptedPrivateKeyInfoDer<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Deref for EncryptedPrivateKeyInfoDer<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<'a> From<&'a [u8]> for EncryptedPrivateKeyInfoDer<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self(Der::from(slice))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<u8>> for EncryptedPrivateKeyInfoDer<'static> {
+    fn from(vec: Vec<u8>) -> Self {
+        Self(Der::from(vec))
+    }
+}
+
+impl fmt::Debug for EncryptedPrivateKeyInfoDer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EncryptedPrivateKeyInfoDer({} bytes, ciphertext elided)",
+            self.0.as_ref().len()
+        )
+    }
+}
+
+/// A trust anchor (a.k.a. root CA)
+///
+/// Traditionally, certificate verification libraries have represented trust anchors as full X.509
+/// root certificates. However, those certificates contain a lot more data than is needed for
+/// verifying certificates. The [`TrustAnchor`] representation allows an application to store
+/// just the essential elements of trust anchors.
+///
+/// Anchors compare and hash by the bytes of their fields, whether those are owned or borrowed,
+/// so a set of anchors can be used to deduplicate a root store merged from several sources. An
+/// anchor with name constraints is distinct from the same anchor without them:
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use rustls_pki_types::{Der, TrustAnchor};
+///
+/// let anchor = TrustAnchor {
+///     subject: Der::from_slice(&[0x31, 0x00]),
+///     subject_public_key_info: Der::from_slice(&[0x30, 0x00]),
+///     name_constraints: None,
+/// };
+/// let constrained = TrustAnchor {
+///     name_constraints: Some(Der::from_slice(&[0x30, 0x00])),
+///     ..anchor.clone()
+/// };
+/// assert_ne!(anchor, constrained);
+///
+/// let anchors = [&anchor, &constrained, &anchor].into_iter().collect::<HashSet<_>>();
+/// assert_eq!(anchors.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TrustAnchor<'a> {
+    /// Value of the `subject` field of the trust anchor
+    pub subject: Der<'a>,
+    /// Value of the `subjectPublicKeyInfo` field of the trust anchor
+    pub subject_public_key_info: Der<'a>,
+    /// Value of DER-encoded `NameConstraints`, containing name constraints to the trust anchor, if any
+    pub name_constraints: Option<Der<'a>>,
+}
+
+impl<'a> TrustAnchor<'a> {
+    /// A const constructor to create a `TrustAnchor` from borrowed slices
+    ///
+    /// Unlike a struct literal, this keeps compiling if fields are added to `TrustAnchor`, so
+    /// it suits generated root stores that embed their anchors in a `static` array:
+    ///
+    /// ```
+    /// use rustls_pki_types::TrustAnchor;
+    ///
+    /// static ANCHORS: [TrustAnchor<'static>; 2] = [
+    ///     TrustAnchor::from_parts(&[0x30, 0x00], &[0x30, 0x00], None),
+    ///     TrustAnchor::from_parts(&[0x30, 0x00], &[0x30, 0x00], Some(&[0x30, 0x00])),
+    /// ];
+    ///
+    /// assert!(ANCHORS[0].name_constraints.is_none());
+    /// assert_eq!(ANCHORS[1].name_constraints.as_deref(), Some(&[0x30, 0x00][..]));
+    /// ```
+    pub const fn from_parts(
+        subject: &'a [u8],
+        subject_public_key_info: &'a [u8],
+        name_constraints: Option<&'a [u8]>,
+    ) -> Self {
+        Self {
+            subject: Der::from_slice(subject),
+            subject_public_key_info: Der::from_slice(subject_public_key_info),
+            name_constraints: match name_constraints {
+                Some(nc) => Some(Der::from_slice(nc)),
+                None => None,
+            },
+        }
+    }
+}
+
+impl TrustAnchor<'_> {
+    /// Yield a `'static` lifetime of the `TrustAnchor` by allocating owned `Der` variants
+    #[cfg(feature = "alloc")]
+    pub fn to_owned(&self) -> TrustAnchor<'static> {
+        #[cfg(not(feature = "std"))]
+        use alloc::borrow::ToOwned;
+        TrustAnchor {
+            subject: self.subject.as_ref().to_owned().into(),
+            subject_public_key_info: self.subject_public_key_info.as_ref().to_owned().into(),
+            name_constraints: self
+                .name_constraints
+                .as_ref()
+                .map(|nc| nc.as_ref().to_owned().into()),
+        }
+    }
+
+    /// Yield a `'static` lifetime of the `TrustAnchor`, copying only the fields that are borrowed
+    ///
+    /// Owned fields are moved, not reallocated:
+    ///
+    /// ```
+    /// use rustls_pki_types::{Der, TrustAnchor};
+    ///
+    /// let subject = vec![0x31, 0x00];
+    /// let ptr = subject.as_ptr();
+    /// let anchor = TrustAnchor {
+    ///     subject: Der::from(subject),
+    ///     subject_public_key_info: Der::from_slice(&[0x30, 0x00]),
+    ///     name_constraints: None,
+    /// };
+    ///
+    /// assert_eq!(anchor.into_owned().subject.as_ptr(), ptr);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(self) -> TrustAnchor<'static> {
+        TrustAnchor {
+            subject: self.subject.into_owned(),
+            subject_public_key_info: self.subject_public_key_info.into_owned(),
+            name_constraints: self.name_constraints.map(Der::into_owned),
+        }
+    }
+}
+
+/// A [`TrustAnchor`] together with the policy a root program attaches to it
+///
+/// Root programs qualify some of their anchors, for example by distrusting certificates issued
+/// after a given date or by limiting the anchor to certain extended key usages. This type carries
+/// such metadata next to the anchor so that verifiers can apply it, without every root store
+/// inventing its own side-channel format. This crate does not enforce any of these constraints.
+///
+/// With the `serde` feature, it implements `Serialize` and `Deserialize`, as does
+/// [`TrustAnchor`]. With the `std` feature, it can be stored in the compact binary cache format
+/// with `write_cached()` and read back with `read_cached()`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QualifiedTrustAnchor<'a> {
+    /// The trust anchor being qualified
+    pub anchor: TrustAnchor<'a>,
+    /// Certificates issued by this anchor after this time should not be trusted, if set
+    pub distrust_after: Option<UnixTime>,
+    /// Extended key usages this anchor may be used for, if restricted
+    ///
+    /// Each entry is the DER encoding of a `KeyPurposeId` OID, without the tag and length.
+    /// `None` means the anchor is not restricted.
+    pub allowed_ekus: Option<Vec<Der<'a>>>,
+    /// Free-form policy flags, whose meaning is agreed between the root store and the verifier
+    pub flags: Vec<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl QualifiedTrustAnchor<'_> {
+    /// Whether this anchor may be used for the extended key usage `eku`
+    ///
+    /// `eku` is the DER encoding of a `KeyPurposeId` OID, without the tag and length.
+    pub fn permits_eku(&self, eku: &[u8]) -> bool {
+        match &self.allowed_ekus {
+            Some(allowed) => allowed.iter().any(|oid| oid.as_ref() == eku),
+            None => true,
+        }
+    }
+
+    /// Whether a certificate issued at `issued` falls after this anchor's distrust date
+    pub fn is_distrusted_at(&self, issued: UnixTime) -> bool {
+        match self.distrust_after {
+            Some(after) => issued > after,
+            None => false,
+        }
+    }
+
+    /// Yield a `'static` lifetime of the `QualifiedTrustAnchor` by allocating owned `Der` variants
+    pub fn to_owned(&self) -> QualifiedTrustAnchor<'static> {
+        #[cfg(not(feature = "std"))]
+        use alloc::borrow::ToOwned;
+        QualifiedTrustAnchor {
+            anchor: self.anchor.to_owned(),
+            distrust_after: self.distrust_after,
+            allowed_ekus: self.allowed_ekus.as_ref().map(|ekus| {
+                ekus.iter()
+                    .map(|oid| oid.as_ref().to_owned().into())
+                    .collect()
+            }),
+            flags: self.flags.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<TrustAnchor<'a>> for QualifiedTrustAnchor<'a> {
+    fn from(anchor: TrustAnchor<'a>) -> Self {
+        Self {
+            anchor,
+            distrust_after: None,
+            allowed_ekus: None,
+            flags: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<QualifiedTrustAnchor<'a>> for TrustAnchor<'a> {
+    /// Drops all of the metadata, leaving only the anchor
+    fn from(qualified: QualifiedTrustAnchor<'a>) -> Self {
+        qualified.anchor
+    }
+}
+
+/// A Certificate Revocation List; as specified in RFC 5280
+///
+/// Certificate revocation lists are identified in PEM context as `X509 CRL` and when stored in a
+/// file usually use a `.crl` extension. For more on PEM files, refer to the crate documentation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CertificateRevocationListDer<'a>(Der<'a>);
+
+impl<'a> CertificateRevocationListDer<'a> {
+    /// Make a CRL from DER already held in a [`Der`], without copying it
+    ///
+    /// See [`CertificateDer::from_der()`].
+    pub fn from_der(der: Der<'a>) -> Self {
+        Self(der)
+    }
+
+    /// Borrow the [`Der`] holding this CRL
+    pub fn as_der(&self) -> &Der<'a> {
+        &self.0
+    }
+
+    /// Yield the [`Der`] holding this CRL, without copying it
+    pub fn into_der(self) -> Der<'a> {
+        self.0
+    }
+
+    /// Make a CRL from `bytes`, checking that they are a single DER `SEQUENCE`
+    ///
+    /// See [`CertificateDer::try_from_der()`].
+    #[cfg(any(feature = "x509", feature = "alloc"))]
+    pub fn try_from_der(bytes: &'a [u8]) -> Result<Self, InvalidDer> {
+        der::check_framing(bytes, der::SEQUENCE)?;
+        Ok(Self::from(bytes))
+    }
+
+    /// A default limit for `CertificateRevocationListDer::try_from_der_capped()`, of 64 MiB
+    ///
+    /// There is no standard limit on the size of a CRL. The largest public CRLs are tens of
+    /// megabytes, so this leaves room for them while bounding what a hostile source can send.
+    pub const DEFAULT_MAX_LEN: usize = 64 << 20;
+
+    /// As for [`CertificateRevocationListDer::try_from_der()`], but first check that `bytes`
+    /// are at most `max_len` long
+    ///
+    /// See [`CertificateDer::try_from_der_capped()`].
+    #[cfg(any(feature = "x509", feature = "alloc"))]
+    pub fn try_from_der_capped(bytes: &'a [u8], max_len: usize) -> Result<Self, InvalidDer> {
+        der::check_framing_capped(bytes, der::SEQUENCE, max_len)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl CertificateRevocationListDer<'_> {
+    /// Borrow this CRL without copying it
+    ///
+    /// See [`Der::as_borrowed()`].
+    pub fn as_borrowed(&self) -> CertificateRevocationListDer<'_> {
+        CertificateRevocationListDer(self.0.as_borrowed())
+    }
+
+    /// Yield the contents as a `Vec`, copying them only if they are borrowed
+    ///
+    /// See [`Der::into_vec()`].
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0.into_vec()
+    }
+}
+
+impl AsRef<[u8]> for CertificateRevocationListDer<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Borrow<[u8]> for CertificateRevocationListDer<'_> {
+    fn borrow(&self) -> &[u8] {
+        self.as_ref()
+    }
+}
+
+impl Deref for CertificateRevocationListDer<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<'a> From<&'a [u8]> for CertificateRevocationListDer<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self(Der::from(slice))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<Vec<u8>> for CertificateRevocationListDer<'a> {
+    fn from(vec: Vec<u8>) -> Self {
+        Self(Der::from(vec))
+    }
+}
+
+impl<'a> From<CertificateRevocationListDer<'a>> for Der<'a> {
+    fn from(crl: CertificateRevocationListDer<'a>) -> Self {
+        crl.0
+    }
+}
+
+impl fmt::Debug for CertificateRevocationListDer<'_> {
+    /// See [`Der`]'s `Debug` implementation
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_der(f, "CertificateRevocationListDer", self.as_ref())
+    }
+}
+
+/// A DER-encoded OCSP response; as specified in RFC 6960
+///
+/// This is the complete `OCSPResponse` structure, as stapled in TLS handshakes. OCSP responses
+/// are usually transported in binary form, and when stored in a file often use a `.der` or
+/// `.ocsp` extension.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OcspResponseDer<'a>(Der<'a>);
+
+impl OcspResponseDer<'_> {
+    /// Borrow this OCSP response without copying it
+    ///
+    /// See [`Der::as_borrowed()`].
+    pub fn as_borrowed(&self) -> OcspResponseDer<'_> {
+        OcspResponseDer(self.0.as_borrowed())
+    }
+}
+
+impl AsRef<[u8]> for OcspResponseDer<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Deref for OcspResponseDer<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<'a> From<&'a [u8]> for OcspResponseDer<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self(Der::from(slice))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<Vec<u8>> for OcspResponseDer<'a> {
+    fn from(vec: Vec<u8>) -> Self {
+        Self(Der::from(vec))
+    }
+}
+
+impl<'a> OcspResponseDer<'a> {
+    /// Make an OCSP response from DER already held in a [`Der`], without copying it
+    ///
+    /// See [`CertificateDer::from_der()`].
+    pub fn from_der(der: Der<'a>) -> Self {
+        Self(der)
+    }
+
+    /// Borrow the [`Der`] holding this OCSP response
+    pub fn as_der(&self) -> &Der<'a> {
+        &self.0
+    }
+
+    /// Yield the [`Der`] holding this OCSP response, without copying it
+    pub fn into_der(self) -> Der<'a> {
+        self.0
+    }
+}
+
+impl<'a> From<OcspResponseDer<'a>> for Der<'a> {
+    fn from(ocsp: OcspResponseDer<'a>) -> Self {
+        ocsp.0
+    }
+}
+
+/// Evidence of a certificate's revocation status: either a CRL or an OCSP response
+///
+/// This allows verifier configuration to accept whatever revocation evidence is available
+/// through a single collection.
+///
+/// With the `x509` feature, DER-encoded material is classified by `from_der()`, and the files
+/// in a directory are loaded by `from_dir_iter()` (with the `std` feature too). PEM-encoded
+/// `X509 CRL` and `OCSP RESPONSE` sections are decoded with the `PemObject` trait.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RevocationMaterial<'a> {
+    /// A certificate revocation list
+    Crl(CertificateRevocationListDer<'a>),
+    /// An OCSP response
+    Ocsp(OcspResponseDer<'a>),
+}
+
+impl RevocationMaterial<'_> {
+    /// Yield a `'static` lifetime of the `RevocationMaterial`, copying the contents only if
+    /// they are borrowed
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(self) -> RevocationMaterial<'static> {
+        match self {
+            Self::Crl(crl) => {
+                RevocationMaterial::Crl(CertificateRevocationListDer(crl.0.into_owned()))
+            }
+            Self::Ocsp(ocsp) => RevocationMaterial::Ocsp(OcspResponseDer(ocsp.0.into_owned())),
+        }
+    }
+}
+
+impl AsRef<[u8]> for RevocationMaterial<'_> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Crl(crl) => crl.as_ref(),
+            Self::Ocsp(ocsp) => ocsp.as_ref(),
+        }
+    }
+}
+
+impl<'a> From<CertificateRevocationListDer<'a>> for RevocationMaterial<'a> {
+    fn from(crl: CertificateRevocationListDer<'a>) -> Self {
+        Self::Crl(crl)
+    }
+}
+
+impl<'a> From<OcspResponseDer<'a>> for RevocationMaterial<'a> {
+    fn from(ocsp: OcspResponseDer<'a>) -> Self {
+        Self::Ocsp(ocsp)
+    }
+}
+
+/// A DER-encoded X.509 certificate; as specified in RFC 5280
+///
+/// Certificates are identified in PEM context as `CERTIFICATE` and when stored in a
+/// file usually use a `.pem`, `.cer` or `.crt` extension. For more on PEM files, refer to the
+/// crate documentation.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CertificateDer<'a>(Der<'a>);
+
+impl<'a> CertificateDer<'a> {
+    /// Make a certificate from DER already held in a [`Der`], without copying it
+    ///
+    /// Like the `From<&[u8]>` implementation, this does not check the contents. Together with
+    /// [`CertificateDer::into_der()`], this lets code that stores or compares DER handle
+    /// certificates, CRLs and other public objects as [`Der`]:
+    ///
+    /// ```
+    /// use rustls_pki_types::{CertificateDer, CertificateRevocationListDer, Der};
+    ///
+    /// fn fingerprint<'a>(der: impl Into<Der<'a>>) -> u8 {
+    ///     der.into().iter().fold(0, |acc, byte| acc ^ byte)
+    /// }
+    ///
+    /// let (cert, crl) = ([0x30, 0x01, 0x05], [0x30, 0x01, 0x06]);
+    /// assert_eq!(fingerprint(CertificateDer::from(&cert[..])), 0x34);
+    /// assert_eq!(fingerprint(CertificateRevocationListDer::from(&crl[..])), 0x37);
+    ///
+    /// let cert = CertificateDer::from_der(Der::from(&cert[..]));
+    /// assert_eq!(cert.as_der(), &Der::from(&[0x30, 0x01, 0x05][..]));
+    /// ```
+    pub fn from_der(der: Der<'a>) -> Self {
+        Self(der)
+    }
+
+    /// Borrow the [`Der`] holding this certificate
+    pub fn as_der(&self) -> &Der<'a> {
+        &self.0
+    }
+
+    /// Yield the [`Der`] holding this certificate, without copying it
+    pub fn into_der(self) -> Der<'a> {
+        self.0
+    }
+
+    /// A const constructor to create a `CertificateDer` from a borrowed slice
+    ///
+    /// This neither copies nor allocates. A `&[u8]` cannot be cast to a `&CertificateDer`,
+    /// because a `CertificateDer` may own its contents instead, and so is larger than a slice.
+    /// To pass certificates held in other buffers as `&[CertificateDer]`, make one
+    /// `CertificateDer` referring to each buffer. None of the certificates are copied, but the
+    /// `CertificateDer`s themselves need somewhere to live. For a number of certificates known
+    /// in advance, that can be an array on the stack, and nothing is allocated:
+    ///
+    /// ```
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let (root, intermediate) = (vec![0x30, 0x00], vec![0x30, 0x01, 0x00]);
+    /// let certs = [
+    ///     CertificateDer::from_slice(&intermediate),
+    ///     CertificateDer::from_slice(&root),
+    /// ];
+    ///
+    /// let intermediates: &[CertificateDer<'_>] = &certs;
+    /// assert_eq!(intermediates[0].as_ptr(), intermediate.as_ptr());
+    /// ```
+    ///
+    /// Otherwise, collecting them allocates one `Vec` of `CertificateDer`s, each a few words
+    /// long, while the certificates stay in their buffers:
+    ///
+    /// ```
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let arena = vec![vec![0x30, 0x00], vec![0x30, 0x01, 0x00]];
+    /// let certs = arena
+    ///     .iter()
+    ///     .map(|der| CertificateDer::from_slice(der))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let intermediates: &[CertificateDer<'_>] = &certs;
+    /// for (cert, der) in intermediates.iter().zip(&arena) {
+    ///     assert_eq!(cert.as_ptr(), der.as_ptr());
+    /// }
+    /// ```
+    ///
+    /// The certificates cannot outlive the buffers:
+    ///
+    /// ```compile_fail
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let cert = {
+    ///     let der = vec![0x30, 0x00];
+    ///     CertificateDer::from_slice(&der)
+    /// };
+    /// ```
+    pub const fn from_slice(der: &'a [u8]) -> Self {
+        Self(Der::from_slice(der))
+    }
+
+    /// Make a certificate from `bytes`, checking that they are a single DER `SEQUENCE`
+    ///
+    /// Only the outer framing is checked: that `bytes` start with a `SEQUENCE` tag, that its
+    /// length is definite and minimally encoded, and that it ends exactly at the end of `bytes`.
+    /// The contents are not decoded. This catches input that is not DER at all, like an empty
+    /// file, early with a specific error. PEM text, which has to be decoded first, fails with
+    /// [`InvalidDer::LooksLikePem`]:
+    ///
+    /// ```
+    /// use rustls_pki_types::{CertificateDer, InvalidDer};
+    ///
+    /// let pem = b"-----BEGIN CERTIFICATE-----
+    /// MIIBLjCB4aADAgECAhRYJKXT7DL0fUMsMZrSvFZInuBXDzAFBgMrZXAwDTELMAkG
+    /// A1UEAwwCZWQwHhcNMjYxMDE2MTI1MDQ4WhcNMjYxMTE1MTI1MDQ4WjANMQswCQYD
+    /// VQQDDAJlZDAqMAUGAytlcAMhAKkUTq3oA4i1B4wZAIrW2cdlEKscxPrkXeDAKIZ4
+    /// +lTPo1MwUTAdBgNVHQ4EFgQUzbWgURofhZB07RWKg8EGQYKUr3YwHwYDVR0jBBgw
+    /// FoAUzbWgURofhZB07RWKg8EGQYKUr3YwDwYDVR0TAQH/BAUwAwEB/zAFBgMrZXAD
+    /// QQBV0/N0EkH3HrN5qlKpcBA3xv92b11Z+Z1tF/4IJjozWXylKDF+IRaW0cmbdA7W
+    /// RpYRALMv+lW1W2BD9mHFXVQM
+    /// -----END CERTIFICATE-----
+    /// ";
+    /// assert_eq!(CertificateDer::try_from_der(pem), Err(InvalidDer::LooksLikePem));
+    ///
+    /// let with_bom = [&b"\xef\xbb\xbf\r\n"[..], pem].concat();
+    /// assert_eq!(CertificateDer::try_from_der(&with_bom), Err(InvalidDer::LooksLikePem));
+    /// ```
+    ///
+    /// Other errors say which part of the framing is wrong:
+    ///
+    /// ```
+    /// use rustls_pki_types::{CertificateDer, InvalidDer};
+    ///
+    /// assert!(CertificateDer::try_from_der(&[0x30, 0x01, 0x05]).is_ok());
+    /// assert_eq!(
+    ///     CertificateDer::try_from_der(b"\x02\x01\x05"),
+    ///     Err(InvalidDer::UnexpectedTag { expected: 0x30, found: 0x02 })
+    /// );
+    /// let framing = |bytes| CertificateDer::try_from_der(bytes).map(|_| ());
+    /// assert_eq!(framing(&[0x30, 0x80]), Err(InvalidDer::InvalidLength));
+    /// assert_eq!(framing(&[0x30, 0x02, 0x05]), Err(InvalidDer::Truncated));
+    /// assert_eq!(framing(&[0x30, 0x00, 0x00]), Err(InvalidDer::TrailingData));
+    /// assert_eq!(framing(&[]), Err(InvalidDer::Truncated));
+    /// ```
+    #[cfg(any(feature = "x509", feature = "alloc"))]
+    pub fn try_from_der(bytes: &'a [u8]) -> Result<Self, InvalidDer> {
+        der::check_framing(bytes, der::SEQUENCE)?;
+        Ok(Self::from(bytes))
+    }
+
+    /// Split `bytes` into the certificates concatenated in them, without copying them
+    ///
+    /// Some tools and protocols send a chain as DER certificates back to back, without any
+    /// other framing. Each certificate is checked as for [`CertificateDer::try_from_der()`], and
+    /// the first one that is not a complete `SEQUENCE` fails with its offset in `bytes`.
+    ///
+    /// ```
+    /// use rustls_pki_types::der::ConcatenatedDerError;
+    /// use rustls_pki_types::{CertificateDer, InvalidDer};
+    ///
+    /// let chain = [0x30, 0x00, 0x30, 0x01, 0x05, 0x30, 0x02, 0x05, 0x00];
+    /// let certs = CertificateDer::from_concatenated(&chain).unwrap();
+    /// assert_eq!(certs, [&chain[..2], &chain[2..5], &chain[5..]].map(CertificateDer::from));
+    /// assert_eq!(CertificateDer::from_concatenated(&chain[..5]).unwrap().len(), 2);
+    /// assert_eq!(CertificateDer::from_concatenated(&[]).unwrap(), []);
+    ///
+    /// assert_eq!(
+    ///     CertificateDer::from_concatenated(&chain[..8]),
+    ///     Err(ConcatenatedDerError { offset: 5, error: InvalidDer::Truncated })
+    /// );
+    /// let garbage = [&[0x0a, 0x0d][..], &chain].concat();
+    /// assert_eq!(
+    ///     CertificateDer::from_concatenated(&garbage),
+    ///     Err(ConcatenatedDerError {
+    ///         offset: 0,
+    ///         error: InvalidDer::UnexpectedTag { expected: 0x30, found: 0x0a },
+    ///     })
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_concatenated(bytes: &'a [u8]) -> Result<Vec<Self>, der::ConcatenatedDerError> {
+        let (mut certs, mut rest) = (Vec::new(), bytes);
+        while !rest.is_empty() {
+            let offset = bytes.len() - rest.len();
+            let (cert, next) = der::split_framed(rest, der::SEQUENCE)
+                .map_err(|error| der::ConcatenatedDerError { offset, error })?;
+            certs.push(Self::from(cert));
+            rest = next;
+        }
+
+        Ok(certs)
+    }
+
+    /// The largest certificate that TLS can carry, 2<sup>24</sup> - 1 bytes
+    ///
+    /// This is a suitable limit for `CertificateDer::try_from_der_capped()` when the certificate
+    /// comes from a TLS peer.
+    pub const DEFAULT_MAX_LEN: usize = 0xff_ffff;
+
+    /// As for [`CertificateDer::try_from_der()`], but first check that `bytes` are at most
+    /// `max_len` long
+    ///
+    /// Longer input fails with [`InvalidDer::TooLong`] before anything else is looked at. The
+    /// result borrows `bytes`, so nothing is copied either way; to own it, check it first and
+    /// then call `CertificateDer::into_owned()`.
+    ///
+    /// ```
+    /// use rustls_pki_types::{CertificateDer, InvalidDer};
+    ///
+    /// let der = [0x30, 0x01, 0x05];
+    /// assert!(CertificateDer::try_from_der_capped(&der, 3).is_ok());
+    /// assert_eq!(
+    ///     CertificateDer::try_from_der_capped(&der, 2),
+    ///     Err(InvalidDer::TooLong { len: 3, max: 2 })
+    /// );
+    ///
+    /// let max = CertificateDer::DEFAULT_MAX_LEN;
+    /// let mut der = vec![0; max];
+    /// der[..5].copy_from_slice(&[0x30, 0x83, 0xff, 0xff, 0xfa]);
+    /// assert!(CertificateDer::try_from_der_capped(&der, max).is_ok());
+    /// der.push(0);
+    /// der[4] += 1;
+    /// assert_eq!(
+    ///     CertificateDer::try_from_der_capped(&der, max),
+    ///     Err(InvalidDer::TooLong { len: max + 1, max })
+    /// );
+    /// ```
+    #[cfg(any(feature = "x509", feature = "alloc"))]
+    pub fn try_from_der_capped(bytes: &'a [u8], max_len: usize) -> Result<Self, InvalidDer> {
+        der::check_framing_capped(bytes, der::SEQUENCE, max_len)?;
+        Ok(Self::from(bytes))
+    }
 }
 
-impl<'a> From<&'a [u8]> for PrivatePkcs1KeyDer<'a> {
-    fn from(slice: &'a [u8]) -> Self {
-        Self(Der(DerInner::Borrowed(slice)))
+impl CertificateDer<'_> {
+    /// Borrow this certificate without copying it
+    ///
+    /// See [`Der::as_borrowed()`].
+    pub fn as_borrowed(&self) -> CertificateDer<'_> {
+        CertificateDer(self.0.as_borrowed())
     }
-}
 
-#[cfg(feature = "alloc")]
-impl<'a> From<Vec<u8>> for PrivatePkcs1KeyDer<'a> {
-    fn from(vec: Vec<u8>) -> Self {
-        Self(Der(DerInner::Owned(vec)))
+    /// Yield a `'static` lifetime of the certificate, copying the contents only if they are
+    /// borrowed
+    ///
+    /// ```
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let cert = CertificateDer::from(vec![0x30, 0x00]);
+    /// let ptr = cert.as_ptr();
+    /// assert_eq!(cert.into_owned().as_ptr(), ptr);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(self) -> CertificateDer<'static> {
+        CertificateDer(self.0.into_owned())
     }
-}
 
-impl fmt::Debug for PrivatePkcs1KeyDer<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("PrivatePkcs1KeyDer")
-            .field(&"[secret key elided]")
-            .finish()
+    /// Yield the contents as a `Vec`, copying them only if they are borrowed
+    ///
+    /// See [`Der::into_vec()`].
+    ///
+    /// ```
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let vec = vec![0x30, 0x00];
+    /// let ptr = vec.as_ptr();
+    /// assert_eq!(CertificateDer::from(vec).into_vec().as_ptr(), ptr);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0.into_vec()
     }
-}
 
-/// A Sec1-encoded plaintext private key; as specified in RFC 5915
-///
-/// Sec1 private keys are identified in PEM context as `EC PRIVATE KEY` and when stored in a
-/// file usually use a `.pem` or `.key` extension. For more on PEM files, refer to the crate
-/// documentation.
-#[derive(PartialEq)]
-pub struct PrivateSec1KeyDer<'a>(Der<'a>);
+    /// Yield a `'static` lifetime of the certificate by copying the contents
+    ///
+    /// The copy compares, orders and hashes equal to the original, so that certificates can be
+    /// sorted or deduplicated whether they are owned or borrowed.
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::collections::HashSet;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// fn hash(value: &impl Hash) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let bytes = [0x30, 0x03, 0x02, 0x01, 0x05];
+    /// for len in 0..=bytes.len() {
+    ///     let borrowed = CertificateDer::from(&bytes[..len]);
+    ///     let owned = borrowed.to_owned();
+    ///     assert_eq!(owned, borrowed);
+    ///     assert_eq!(owned.cmp(&borrowed), std::cmp::Ordering::Equal);
+    ///     assert_eq!(hash(&owned), hash(&borrowed));
+    ///     assert_eq!(hash(&owned), hash(&&bytes[..len]));
+    /// }
+    ///
+    /// let mut intermediates = HashSet::new();
+    /// assert!(intermediates.insert(CertificateDer::from(&bytes[..])));
+    /// assert!(!intermediates.insert(CertificateDer::from(bytes.to_vec())));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_owned(&self) -> CertificateDer<'static> {
+        CertificateDer(Der::from(self.as_ref().to_vec()))
+    }
 
-impl PrivateSec1KeyDer<'_> {
-    /// Yield the DER-encoded bytes of the private key
-    pub fn secret_sec1_der(&self) -> &[u8] {
-        self.0.as_ref()
+    /// Make a chain of certificates from their DER encodings, without copying them
+    ///
+    /// This converts chains from APIs that use plain byte vectors, like rustls 0.21.
+    ///
+    /// ```
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let chain = vec![vec![0x30, 0x00], vec![0x30, 0x01, 0x00]];
+    /// let ptrs = chain.iter().map(|der| der.as_ptr()).collect::<Vec<_>>();
+    ///
+    /// let chain = CertificateDer::from_vec_chain(chain);
+    /// assert!(chain.iter().map(|cert| cert.as_ptr()).eq(ptrs.iter().copied()));
+    ///
+    /// let chain = CertificateDer::into_vec_chain(chain);
+    /// assert!(chain.iter().map(|der| der.as_ptr()).eq(ptrs.iter().copied()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_vec_chain(chain: Vec<Vec<u8>>) -> Vec<CertificateDer<'static>> {
+        chain.into_iter().map(CertificateDer::from).collect()
     }
-}
 
-impl<'a> From<&'a [u8]> for PrivateSec1KeyDer<'a> {
-    fn from(slice: &'a [u8]) -> Self {
-        Self(Der(DerInner::Borrowed(slice)))
+    /// Yield the DER encodings of a chain of certificates, copying only those that are borrowed
+    ///
+    /// This is the reverse of [`CertificateDer::from_vec_chain()`].
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_chain(chain: Vec<CertificateDer<'_>>) -> Vec<Vec<u8>> {
+        chain.into_iter().map(CertificateDer::into_vec).collect()
     }
 }
 
+/// Yield `'static` lifetimes of `certs`, copying only the certificates that are borrowed
+///
+/// This suits APIs that take an owned chain, like rustls' `ConfigBuilder::with_single_cert()`,
+/// when the certificates are borrowed from a larger buffer.
+///
+/// ```
+/// use rustls_pki_types::{certs_into_owned, CertificateDer};
+///
+/// let bundle = [0x30, 0x00, 0x30, 0x01, 0x00];
+/// let chain = vec![CertificateDer::from(&bundle[..2]), CertificateDer::from(&bundle[2..])];
+/// let chain = certs_into_owned(chain);
+/// assert_eq!(chain[1].as_ref(), &[0x30, 0x01, 0x00]);
+/// ```
 #[cfg(feature = "alloc")]
-impl<'a> From<Vec<u8>> for PrivateSec1KeyDer<'a> {
-    fn from(vec: Vec<u8>) -> Self {
-        Self(Der(DerInner::Owned(vec)))
+pub fn certs_into_owned(certs: Vec<CertificateDer<'_>>) -> Vec<CertificateDer<'static>> {
+    certs.into_iter().map(CertificateDer::into_owned).collect()
+}
+
+impl AsRef<[u8]> for CertificateDer<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
     }
 }
 
-impl fmt::Debug for PrivateSec1KeyDer<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("PrivatePkcs1KeyDer")
-            .field(&"[secret key elided]")
-            .finish()
+impl Borrow<[u8]> for CertificateDer<'_> {
+    /// Borrow the DER encoding of the certificate
+    ///
+    /// Certificates compare, order and hash exactly as their encodings do, so maps keyed by
+    /// certificates can be searched with a `&[u8]`, whatever the lifetime of the keys.
+    ///
+    /// A `CertificateDer` is already `ToOwned` through `Clone`, so it works with `Cow`, but
+    /// the owned form has the same lifetime. Use `CertificateDer::into_owned()` to get a
+    /// `'static` certificate out of a `Cow`.
+    ///
+    /// ```
+    /// use std::borrow::{Borrow, Cow};
+    /// use std::collections::HashMap;
+    ///
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let mut names: HashMap<CertificateDer<'static>, &str> = HashMap::new();
+    /// names.insert(CertificateDer::from_slice(&[0x30, 0x01, 0x05]), "intermediate");
+    ///
+    /// // Look up certificates that borrow from a short-lived buffer, without copying them.
+    /// let received = vec![0x30, 0x01, 0x05];
+    /// let cert = CertificateDer::from(&received[..]);
+    /// assert_eq!(names.get(cert.as_ref()), Some(&"intermediate"));
+    /// assert_eq!(names.get::<[u8]>(cert.borrow()), Some(&"intermediate"));
+    ///
+    /// let cached: Cow<'_, CertificateDer<'_>> = Cow::Borrowed(&cert);
+    /// assert_eq!(names.get(cached.as_ref().as_ref()), Some(&"intermediate"));
+    /// # #[cfg(feature = "alloc")] {
+    /// let owned: CertificateDer<'static> = cached.into_owned().into_owned();
+    /// assert!(names.contains_key(&owned));
+    /// # }
+    /// ```
+    fn borrow(&self) -> &[u8] {
+        self.as_ref()
     }
 }
 
-/// A DER-encoded plaintext private key; as specified in PKCS#8/RFC 5958
-///
-/// PKCS#8 private keys are identified in PEM context as `PRIVATE KEY` and when stored in a
-/// file usually use a `.pem` or `.key` extension. For more on PEM files, refer to the crate
-/// documentation.
-#[derive(PartialEq)]
-pub struct PrivatePkcs8KeyDer<'a>(Der<'a>);
+impl Deref for CertificateDer<'_> {
+    type Target = [u8];
 
-impl PrivatePkcs8KeyDer<'_> {
-    /// Yield the DER-encoded bytes of the private key
-    pub fn secret_pkcs8_der(&self) -> &[u8] {
-        self.0.as_ref()
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
     }
 }
 
-impl<'a> From<&'a [u8]> for PrivatePkcs8KeyDer<'a> {
+impl<'a> From<&'a [u8]> for CertificateDer<'a> {
     fn from(slice: &'a [u8]) -> Self {
-        Self(Der(DerInner::Borrowed(slice)))
+        Self(Der::from(slice))
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<'a> From<Vec<u8>> for PrivatePkcs8KeyDer<'a> {
+impl<'a> From<Vec<u8>> for CertificateDer<'a> {
     fn from(vec: Vec<u8>) -> Self {
-        Self(Der(DerInner::Owned(vec)))
+        Self(Der::from(vec))
     }
 }
 
-impl fmt::Debug for PrivatePkcs8KeyDer<'_> {
+impl<'a> From<CertificateDer<'a>> for Der<'a> {
+    fn from(cert: CertificateDer<'a>) -> Self {
+        cert.0
+    }
+}
+
+impl fmt::Debug for CertificateDer<'_> {
+    /// See [`Der`]'s `Debug` implementation
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("PrivatePkcs1KeyDer")
-            .field(&"[secret key elided]")
-            .finish()
+        debug_der(f, "CertificateDer", self.as_ref())
     }
 }
 
-/// A trust anchor (a.k.a. root CA)
+/// A Certificate Signing Request; as specified in RFC 2986
 ///
-/// Traditionally, certificate verification libraries have represented trust anchors as full X.509
-/// root certificates. However, those certificates contain a lot more data than is needed for
-/// verifying certificates. The [`TrustAnchor`] representation allows an application to store
-/// just the essential elements of trust anchors.
+/// Certificate signing requests are identified in PEM context as `CERTIFICATE REQUEST` and when
+/// stored in a file usually use a `.csr` extension. For more on PEM files, refer to the crate
+/// documentation.
 #[derive(Clone, Debug, PartialEq)]
-pub struct TrustAnchor<'a> {
-    /// Value of the `subject` field of the trust anchor
-    pub subject: Der<'a>,
-    /// Value of the `subjectPublicKeyInfo` field of the trust anchor
-    pub subject_public_key_info: Der<'a>,
-    /// Value of DER-encoded `NameConstraints`, containing name constraints to the trust anchor, if any
-    pub name_constraints: Option<Der<'a>>,
-}
+pub struct CertificateSigningRequestDer<'a>(Der<'a>);
 
-impl TrustAnchor<'_> {
-    /// Yield a `'static` lifetime of the `TrustAnchor` by allocating owned `Der` variants
+impl CertificateSigningRequestDer<'_> {
+    /// Borrow this certificate signing request without copying it
+    ///
+    /// See [`Der::as_borrowed()`].
+    pub fn as_borrowed(&self) -> CertificateSigningRequestDer<'_> {
+        CertificateSigningRequestDer(self.0.as_borrowed())
+    }
+
+    /// Yield a `'static` lifetime of the certificate signing request, copying the contents
+    /// only if they are borrowed
     #[cfg(feature = "alloc")]
-    pub fn to_owned(&self) -> TrustAnchor<'static> {
-        #[cfg(not(feature = "std"))]
-        use alloc::borrow::ToOwned;
-        TrustAnchor {
-            subject: self.subject.as_ref().to_owned().into(),
-            subject_public_key_info: self.subject_public_key_info.as_ref().to_owned().into(),
-            name_constraints: self
-                .name_constraints
-                .as_ref()
-                .map(|nc| nc.as_ref().to_owned().into()),
-        }
+    pub fn into_owned(self) -> CertificateSigningRequestDer<'static> {
+        CertificateSigningRequestDer(self.0.into_owned())
     }
 }
 
-/// A Certificate Revocation List; as specified in RFC 5280
-///
-/// Certificate revocation lists are identified in PEM context as `X509 CRL` and when stored in a
-/// file usually use a `.crl` extension. For more on PEM files, refer to the crate documentation.
-#[derive(Clone, Debug, PartialEq)]
-pub struct CertificateRevocationListDer<'a>(Der<'a>);
-
-impl AsRef<[u8]> for CertificateRevocationListDer<'_> {
+impl AsRef<[u8]> for CertificateSigningRequestDer<'_> {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
     }
 }
 
-impl Deref for CertificateRevocationListDer<'_> {
+impl Deref for CertificateSigningRequestDer<'_> {
     type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
@@ -247,34 +1714,67 @@ impl Deref for CertificateRevocationListDer<'_> {
     }
 }
 
-impl<'a> From<&'a [u8]> for CertificateRevocationListDer<'a> {
+impl<'a> From<&'a [u8]> for CertificateSigningRequestDer<'a> {
     fn from(slice: &'a [u8]) -> Self {
         Self(Der::from(slice))
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<'a> From<Vec<u8>> for CertificateRevocationListDer<'a> {
+impl<'a> From<Vec<u8>> for CertificateSigningRequestDer<'a> {
     fn from(vec: Vec<u8>) -> Self {
         Self(Der::from(vec))
     }
 }
 
-/// A DER-encoded X.509 certificate; as specified in RFC 5280
+impl<'a> CertificateSigningRequestDer<'a> {
+    /// Make a certificate signing request from DER already held in a [`Der`], without copying it
+    ///
+    /// See [`CertificateDer::from_der()`].
+    pub fn from_der(der: Der<'a>) -> Self {
+        Self(der)
+    }
+
+    /// Borrow the [`Der`] holding this certificate signing request
+    pub fn as_der(&self) -> &Der<'a> {
+        &self.0
+    }
+
+    /// Yield the [`Der`] holding this certificate signing request, without copying it
+    pub fn into_der(self) -> Der<'a> {
+        self.0
+    }
+}
+
+impl<'a> From<CertificateSigningRequestDer<'a>> for Der<'a> {
+    fn from(csr: CertificateSigningRequestDer<'a>) -> Self {
+        csr.0
+    }
+}
+
+/// A DER-encoded SubjectPublicKeyInfo (SPKI), as specified in RFC 5280
 ///
-/// Certificates are identified in PEM context as `CERTIFICATE` and when stored in a
-/// file usually use a `.pem`, `.cer` or `.crt` extension. For more on PEM files, refer to the
-/// crate documentation.
+/// This is the complete `SubjectPublicKeyInfo` structure, including the outer `SEQUENCE` tag
+/// and length, as found in certificates and certificate signing requests.
 #[derive(Clone, Debug, PartialEq)]
-pub struct CertificateDer<'a>(Der<'a>);
+pub struct SubjectPublicKeyInfoDer<'a>(Der<'a>);
 
-impl AsRef<[u8]> for CertificateDer<'_> {
+impl SubjectPublicKeyInfoDer<'_> {
+    /// Borrow this public key without copying it
+    ///
+    /// See [`Der::as_borrowed()`].
+    pub fn as_borrowed(&self) -> SubjectPublicKeyInfoDer<'_> {
+        SubjectPublicKeyInfoDer(self.0.as_borrowed())
+    }
+}
+
+impl AsRef<[u8]> for SubjectPublicKeyInfoDer<'_> {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
     }
 }
 
-impl Deref for CertificateDer<'_> {
+impl Deref for SubjectPublicKeyInfoDer<'_> {
     type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
@@ -282,19 +1782,44 @@ impl Deref for CertificateDer<'_> {
     }
 }
 
-impl<'a> From<&'a [u8]> for CertificateDer<'a> {
+impl<'a> From<&'a [u8]> for SubjectPublicKeyInfoDer<'a> {
     fn from(slice: &'a [u8]) -> Self {
         Self(Der::from(slice))
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<'a> From<Vec<u8>> for CertificateDer<'a> {
+impl<'a> From<Vec<u8>> for SubjectPublicKeyInfoDer<'a> {
     fn from(vec: Vec<u8>) -> Self {
         Self(Der::from(vec))
     }
 }
 
+impl<'a> SubjectPublicKeyInfoDer<'a> {
+    /// Make a public key from DER already held in a [`Der`], without copying it
+    ///
+    /// See [`CertificateDer::from_der()`].
+    pub fn from_der(der: Der<'a>) -> Self {
+        Self(der)
+    }
+
+    /// Borrow the [`Der`] holding this public key
+    pub fn as_der(&self) -> &Der<'a> {
+        &self.0
+    }
+
+    /// Yield the [`Der`] holding this public key, without copying it
+    pub fn into_der(self) -> Der<'a> {
+        self.0
+    }
+}
+
+impl<'a> From<SubjectPublicKeyInfoDer<'a>> for Der<'a> {
+    fn from(spki: SubjectPublicKeyInfoDer<'a>) -> Self {
+        spki.0
+    }
+}
+
 /// An abstract signature verification algorithm.
 ///
 /// One of these is needed per supported pair of public key type (identified
@@ -327,6 +1852,82 @@ pub trait SignatureVerificationAlgorithm: Send + Sync {
         signature: &[u8],
     ) -> Result<(), InvalidSignature>;
 
+    /// Verify a signature over a message given as several fragments.
+    ///
+    /// The message is the concatenation of the `message` fragments, in order, and the
+    /// result must be the same as from `verify_signature()` with the concatenated message,
+    /// however it is split. This lets callers avoid copying messages that are assembled from
+    /// several parts, like a context string and a transcript hash.
+    ///
+    /// The default implementation concatenates the fragments and calls `verify_signature()`.
+    /// Implementations backed by a streaming digest can override it to hash the fragments
+    /// directly:
+    ///
+    /// ```
+    /// use rustls_pki_types::{AlgorithmIdentifier, InvalidSignature, SignatureVerificationAlgorithm};
+    ///
+    /// // A stand-in for a real algorithm, whose "digest" is the sum of the message octets.
+    /// struct MockSum;
+    ///
+    /// impl MockSum {
+    ///     fn check(&self, digest: u8, signature: &[u8]) -> Result<(), InvalidSignature> {
+    ///         match signature == [digest] {
+    ///             true => Ok(()),
+    ///             false => Err(InvalidSignature),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl SignatureVerificationAlgorithm for MockSum {
+    ///     fn verify_signature(
+    ///         &self,
+    ///         _public_key: &[u8],
+    ///         message: &[u8],
+    ///         signature: &[u8],
+    ///     ) -> Result<(), InvalidSignature> {
+    ///         self.check(message.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)), signature)
+    ///     }
+    ///
+    ///     fn verify_signature_vectored(
+    ///         &self,
+    ///         _public_key: &[u8],
+    ///         message: &[&[u8]],
+    ///         signature: &[u8],
+    ///     ) -> Result<(), InvalidSignature> {
+    ///         let digest = message
+    ///             .iter()
+    ///             .flat_map(|fragment| fragment.iter())
+    ///             .fold(0u8, |sum, b| sum.wrapping_add(*b));
+    ///         self.check(digest, signature)
+    ///     }
+    ///
+    ///     fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+    ///         AlgorithmIdentifier::from_slice(&[])
+    ///     }
+    ///
+    ///     fn signature_alg_id(&self) -> AlgorithmIdentifier {
+    ///         AlgorithmIdentifier::from_slice(&[])
+    ///     }
+    /// }
+    ///
+    /// let message = b"TLS 1.3, server CertificateVerify\x00transcript hash";
+    /// let fragments = message.chunks(3).collect::<Vec<_>>();
+    /// for (signature, valid) in [(&[0x8e][..], true), (&[0x8f], false), (&[], false)] {
+    ///     assert_eq!(MockSum.verify_signature(b"", message, signature).is_ok(), valid);
+    ///     assert_eq!(MockSum.verify_signature_vectored(b"", &[message], signature).is_ok(), valid);
+    ///     assert_eq!(MockSum.verify_signature_vectored(b"", &fragments, signature).is_ok(), valid);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn verify_signature_vectored(
+        &self,
+        public_key: &[u8],
+        message: &[&[u8]],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        self.verify_signature(public_key, &message.concat(), signature)
+    }
+
     /// Return the `AlgorithmIdentifier` that must equal a public key's
     /// `subjectPublicKeyInfo` value for this `SignatureVerificationAlgorithm`
     /// to be used for signature verification.
@@ -430,7 +2031,7 @@ impl UnixTime {
 /// This wrapper type is used to represent DER-encoded data in a way that is agnostic to whether
 /// the data is owned (by a `Vec<u8>`) or borrowed (by a `&[u8]`). Support for the owned
 /// variant is only available when the `alloc` feature is enabled.
-#[derive(Clone, PartialEq)]
+#[derive(Clone)]
 pub struct Der<'a>(DerInner<'a>);
 
 impl<'a> Der<'a> {
@@ -438,6 +2039,66 @@ impl<'a> Der<'a> {
     pub const fn from_slice(der: &'a [u8]) -> Self {
         Self(DerInner::Borrowed(der))
     }
+
+    /// Move the contents out, leaving `self` empty
+    ///
+    /// The private key types implement `Drop` with the `zeroize` feature, so their contents
+    /// cannot be moved out directly.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn take(&mut self) -> Self {
+        core::mem::replace(self, Self::from_slice(&[]))
+    }
+}
+
+impl Der<'_> {
+    /// Borrow the contents of this `Der` without copying them
+    ///
+    /// The returned value refers to the same bytes as `self`, and compares equal to it. This
+    /// allows an owned value to be lent repeatedly to APIs that take a `Der` by value. It is not
+    /// called `borrow()`, so that it does not hide [`Borrow::borrow()`] in method calls.
+    pub fn as_borrowed(&self) -> Der<'_> {
+        Der(DerInner::Borrowed(self.as_ref()))
+    }
+
+    /// Overwrite owned contents with zeros, and empty them
+    ///
+    /// Borrowed contents are left alone.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn zeroize(&mut self) {
+        if let DerInner::Owned(vec) = &mut self.0 {
+            vec.zeroize();
+        }
+    }
+
+    /// Yield a `'static` lifetime of the `Der`, copying the contents only if they are borrowed
+    #[cfg(feature = "alloc")]
+    pub(crate) fn into_owned(self) -> Der<'static> {
+        Der(DerInner::Owned(self.into_vec()))
+    }
+
+    /// Yield the contents as a `Vec`, copying them only if they are borrowed
+    ///
+    /// Owned contents are moved out in their existing allocation, which suits handing them to
+    /// code that takes ownership, like a C library. Borrowed contents are copied into a new
+    /// `Vec`.
+    ///
+    /// ```
+    /// use rustls_pki_types::Der;
+    ///
+    /// let vec = vec![0x30, 0x00];
+    /// let ptr = vec.as_ptr();
+    /// assert_eq!(Der::from(vec).into_vec().as_ptr(), ptr);
+    ///
+    /// let slice = &[0x30, 0x00][..];
+    /// assert_ne!(Der::from(slice).into_vec().as_ptr(), slice.as_ptr());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<u8> {
+        match self.0 {
+            DerInner::Owned(vec) => vec,
+            DerInner::Borrowed(slice) => slice.to_vec(),
+        }
+    }
 }
 
 impl AsRef<[u8]> for Der<'_> {
@@ -450,6 +2111,12 @@ impl AsRef<[u8]> for Der<'_> {
     }
 }
 
+impl Borrow<[u8]> for Der<'_> {
+    fn borrow(&self) -> &[u8] {
+        self.as_ref()
+    }
+}
+
 impl Deref for Der<'_> {
     type Target = [u8];
 
@@ -471,13 +2138,106 @@ impl From<Vec<u8>> for Der<'static> {
     }
 }
 
+impl PartialEq for Der<'_> {
+    /// Compares the contents, regardless of whether they are owned or borrowed
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for Der<'_> {}
+
+impl PartialOrd for Der<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Der<'_> {
+    /// Orders the contents lexicographically as bytes, regardless of whether they are owned or
+    /// borrowed
+    ///
+    /// This is a byte ordering, for canonical output like a sorted trust store, not a semantic
+    /// one: it does not order certificates by subject, issuer or validity.
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    ///
+    /// use rustls_pki_types::CertificateDer;
+    ///
+    /// let (a, b, c): (&[u8], &[u8], &[u8]) = (&[0x30, 0x00], &[0x30, 0x01, 0x00], &[0x31, 0x00]);
+    /// let mut certs = [c, a, b].map(CertificateDer::from);
+    /// certs.sort_unstable();
+    /// assert_eq!(certs, [a, b, c].map(CertificateDer::from));
+    ///
+    /// let set = certs.iter().chain(&certs).cloned().collect::<BTreeSet<_>>();
+    /// assert_eq!(set.len(), 3);
+    /// assert!(certs.iter().all(|cert| cert.cmp(&cert.as_borrowed()).is_eq()));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl Hash for Der<'_> {
+    /// Hashes the contents exactly as `[u8]` does, regardless of whether they are owned or
+    /// borrowed
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
 impl fmt::Debug for Der<'_> {
+    /// Writes the length and the first and last few octets, or every octet with `{:#?}`
+    ///
+    /// [`CertificateDer`] and [`CertificateRevocationListDer`] are written the same way.
+    ///
+    /// ```
+    /// use rustls_pki_types::{CertificateDer, Der};
+    ///
+    /// let der = [0x30, 0x82, 0x05, 0x7b, 0x30, 0x82, 0x04, 0x63, 0x9f, 0x1c];
+    /// let cert = CertificateDer::from(&der[..]);
+    /// assert_eq!(format!("{cert:?}"), "CertificateDer(10 bytes, 30 82 05 7b .. 9f 1c)");
+    /// assert_eq!(
+    ///     format!("{cert:#?}"),
+    ///     "CertificateDer(10 bytes, 30 82 05 7b 30 82 04 63 9f 1c)"
+    /// );
+    /// assert_eq!(format!("{:?}", Der::from(&der[..6])), "Der(6 bytes, 30 82 05 7b 30 82)");
+    /// assert_eq!(format!("{:?}", Der::from(&der[..0])), "Der(0 bytes)");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("Der").field(&self.as_ref()).finish()
+        debug_der(f, "Der", self.as_ref())
     }
 }
 
-#[derive(Clone, PartialEq)]
+/// Write `bytes` for `Debug` as `name(N bytes, 30 82 05 7b .. 9f 1c)`
+///
+/// Only the first and last octets are written, so that logging a certificate stays readable,
+/// unless all of them fit or the alternate form `{:#?}` is used.
+fn debug_der(f: &mut fmt::Formatter<'_>, name: &str, bytes: &[u8]) -> fmt::Result {
+    const PREFIX: usize = 4;
+    const SUFFIX: usize = 2;
+
+    write!(f, "{name}({} bytes", bytes.len())?;
+    let (head, tail) = match f.alternate() || bytes.len() <= PREFIX + SUFFIX {
+        true => (bytes, &[][..]),
+        false => (&bytes[..PREFIX], &bytes[bytes.len() - SUFFIX..]),
+    };
+
+    for (i, byte) in head.iter().enumerate() {
+        let separator = if i == 0 { ", " } else { " " };
+        write!(f, "{separator}{byte:02x}")?;
+    }
+
+    if !tail.is_empty() {
+        f.write_str(" ..")?;
+        tail.iter().try_for_each(|byte| write!(f, " {byte:02x}"))?;
+    }
+
+    f.write_str(")")
+}
+
+#[derive(Clone)]
 enum DerInner<'a> {
     #[cfg(feature = "alloc")]
     Owned(Vec<u8>),
@@ -0,0 +1,344 @@
+//! Loading of certificates from the file system.
+
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::{env, fmt, fs, io, vec};
+
+use crate::pem::{self, PemObject};
+use crate::{der, CertificateDer};
+#[cfg(feature = "x509")]
+use crate::{CertificateRevocationListDer, OcspResponseDer, RevocationMaterial};
+
+impl CertificateDer<'static> {
+    /// Load certificates from each file in the directory at `path`
+    ///
+    /// This is intended for OpenSSL-style `CApath` directories, like `/etc/ssl/certs`, which
+    /// hold one certificate per file, often as symbolic links named after a hash of the
+    /// certificate's subject. The directory is not walked recursively.
+    ///
+    /// Files are visited in order of their file names. Each file may hold any number of
+    /// PEM-encoded certificates, or a single DER-encoded certificate; the encoding is detected
+    /// from the contents. PEM sections are decoded as by `PemObject::from_pem()`, so the
+    /// legacy `X509 CERTIFICATE` and `TRUSTED CERTIFICATE` labels are accepted, and sections
+    /// with other labels are skipped. Entries which are not regular files (after following
+    /// symbolic links) are skipped, as are further links to a file that was already visited.
+    ///
+    /// The returned iterator yields the certificates from each file, or the reason the file
+    /// could not be loaded, so that one bad file does not prevent loading the others.
+    pub fn from_dir_iter(path: impl AsRef<Path>) -> Result<CertificateDirIter, LoadError> {
+        Ok(CertificateDirIter {
+            files: DirFiles::new(path.as_ref())?,
+        })
+    }
+}
+
+/// An iterator over the certificates in a directory, file by file
+///
+/// Obtained from [`CertificateDer::from_dir_iter()`].
+#[derive(Debug)]
+pub struct CertificateDirIter {
+    files: DirFiles,
+}
+
+impl Iterator for CertificateDirIter {
+    type Item = Result<Vec<CertificateDer<'static>>, LoadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.files.next()?.and_then(|path| load_file(&path)))
+    }
+}
+
+#[cfg(feature = "x509")]
+impl RevocationMaterial<'static> {
+    /// Load CRLs and OCSP responses from each file in the directory at `path`
+    ///
+    /// Files are visited as by [`CertificateDer::from_dir_iter()`]. Each file may hold any
+    /// number of PEM-encoded `X509 CRL` and `OCSP RESPONSE` sections, or a single DER-encoded
+    /// CRL or OCSP response, classified as by [`RevocationMaterial::from_der()`]. Sections with
+    /// other labels are skipped. A bundle in a single file can be loaded with
+    /// `PemObject::pem_file_iter()` with the `pem` feature.
+    ///
+    /// ```no_run
+    /// use rustls_pki_types::RevocationMaterial;
+    ///
+    /// let mut material = Vec::new();
+    /// for file in RevocationMaterial::from_dir_iter("/etc/revocation").unwrap() {
+    ///     material.extend(file.unwrap());
+    /// }
+    /// ```
+    pub fn from_dir_iter(path: impl AsRef<Path>) -> Result<RevocationDirIter, LoadError> {
+        Ok(RevocationDirIter {
+            files: DirFiles::new(path.as_ref())?,
+        })
+    }
+}
+
+/// An iterator over the CRLs and OCSP responses in a directory, file by file
+///
+/// Obtained from [`RevocationMaterial::from_dir_iter()`].
+#[cfg(feature = "x509")]
+#[derive(Debug)]
+pub struct RevocationDirIter {
+    files: DirFiles,
+}
+
+#[cfg(feature = "x509")]
+impl Iterator for RevocationDirIter {
+    type Item = Result<Vec<RevocationMaterial<'static>>, LoadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(
+            self.files
+                .next()?
+                .and_then(|path| load_objects(&path, revocation_from_der)),
+        )
+    }
+}
+
+/// Classify a DER-encoded CRL or OCSP response, without copying it
+#[cfg(feature = "x509")]
+fn revocation_from_der(der: Vec<u8>) -> Option<RevocationMaterial<'static>> {
+    let is_crl = matches!(
+        RevocationMaterial::from_der(&der).ok()?,
+        RevocationMaterial::Crl(_)
+    );
+    Some(if is_crl {
+        RevocationMaterial::Crl(CertificateRevocationListDer::from(der))
+    } else {
+        RevocationMaterial::Ocsp(OcspResponseDer::from(der))
+    })
+}
+
+/// The regular files in a directory, in order of their file names
+///
+/// Entries which are not regular files (after following symbolic links) are skipped, as are
+/// further links to a file that was already visited.
+#[derive(Debug)]
+struct DirFiles {
+    paths: vec::IntoIter<PathBuf>,
+    /// Canonical paths of the files visited so far
+    seen: HashSet<PathBuf>,
+}
+
+impl DirFiles {
+    fn new(path: &Path) -> Result<Self, LoadError> {
+        let io_error = |error| LoadError::Io {
+            path: path.to_path_buf(),
+            error,
+        };
+
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(path).map_err(io_error)? {
+            paths.push(entry.map_err(io_error)?.path());
+        }
+        paths.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+
+        Ok(Self {
+            paths: paths.into_iter(),
+            seen: HashSet::new(),
+        })
+    }
+}
+
+impl Iterator for DirFiles {
+    type Item = Result<PathBuf, LoadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for path in self.paths.by_ref() {
+            let io_error = |error| LoadError::Io {
+                path: path.clone(),
+                error,
+            };
+
+            // Follows symbolic links, so that links to regular files are visited.
+            let metadata = match fs::metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(error) => return Some(Err(io_error(error))),
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+
+            let canonical = match fs::canonicalize(&path) {
+                Ok(canonical) => canonical,
+                Err(error) => return Some(Err(io_error(error))),
+            };
+            if !self.seen.insert(canonical) {
+                continue;
+            }
+
+            return Some(Ok(path));
+        }
+
+        None
+    }
+}
+
+/// Load the certificates named by the `SSL_CERT_FILE` and `SSL_CERT_DIR` environment variables
+///
+/// These variables are how OpenSSL-based tools are pointed at custom trust roots, for example
+/// in containers and CI environments. As in OpenSSL, both are used when both are set:
+/// `SSL_CERT_FILE` names a file of PEM- or DER-encoded certificates, and `SSL_CERT_DIR` names
+/// one or more `CApath`-style directories, separated like the `PATH` variable, which are loaded
+/// as by [`CertificateDer::from_dir_iter()`]. The certificates from the file come first.
+///
+/// Returns [`LoadError::NoSourcesConfigured`] if neither variable is set (or both are empty).
+/// This does not fall back to any platform-specific locations; see
+/// [rustls-native-certs](https://docs.rs/rustls-native-certs) for that.
+///
+/// Failing to read the file or a directory is an error, whereas files within a directory
+/// which cannot be loaded are reported in [`NativeCertSources::errors`].
+pub fn load_native_cert_sources() -> Result<NativeCertSources, LoadError> {
+    let file = non_empty_var(SSL_CERT_FILE).map(PathBuf::from);
+    let dirs = non_empty_var(SSL_CERT_DIR)
+        .map(|dirs| env::split_paths(&dirs).collect::<Vec<_>>())
+        .unwrap_or_default();
+    if file.is_none() && dirs.is_empty() {
+        return Err(LoadError::NoSourcesConfigured);
+    }
+
+    let mut sources = NativeCertSources {
+        certs: Vec::new(),
+        file: None,
+        dirs: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    if let Some(file) = file {
+        sources.certs = load_file(&file)?;
+        sources.file = Some(file);
+    }
+
+    for dir in dirs {
+        for result in CertificateDer::from_dir_iter(&dir)? {
+            match result {
+                Ok(certs) => sources.certs.extend(certs),
+                Err(err) => sources.errors.push(err),
+            }
+        }
+        sources.dirs.push(dir);
+    }
+
+    Ok(sources)
+}
+
+fn non_empty_var(name: &str) -> Option<OsString> {
+    env::var_os(name).filter(|value| !value.is_empty())
+}
+
+const SSL_CERT_FILE: &str = "SSL_CERT_FILE";
+const SSL_CERT_DIR: &str = "SSL_CERT_DIR";
+
+/// Certificates loaded by [`load_native_cert_sources()`], and where they came from
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct NativeCertSources {
+    /// The certificates from all sources
+    pub certs: Vec<CertificateDer<'static>>,
+    /// The file named by `SSL_CERT_FILE`, if it was set
+    pub file: Option<PathBuf>,
+    /// The directories named by `SSL_CERT_DIR`, if it was set
+    pub dirs: Vec<PathBuf>,
+    /// Errors loading individual files within the directories
+    pub errors: Vec<LoadError>,
+}
+
+/// Load the certificates in the file at `path`, which may be PEM- or DER-encoded
+fn load_file(path: &Path) -> Result<Vec<CertificateDer<'static>>, LoadError> {
+    // A DER-encoded certificate is a single `SEQUENCE`.
+    load_objects(path, |der| {
+        der::expect_single(&der, der::SEQUENCE)
+            .is_ok()
+            .then(|| CertificateDer::from(der))
+    })
+}
+
+/// Load the objects in the file at `path`
+///
+/// The file may hold PEM sections, of which those for `T` are decoded, or a single
+/// DER-encoded object, which `from_der` makes into a `T` if it is one.
+fn load_objects<T: PemObject>(
+    path: &Path,
+    from_der: impl FnOnce(Vec<u8>) -> Option<T>,
+) -> Result<Vec<T>, LoadError> {
+    let contents = fs::read(path).map_err(|error| LoadError::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+
+    if !pem::is_pem(&contents) {
+        return match from_der(contents) {
+            Some(object) => Ok(vec![object]),
+            None => Err(LoadError::UnrecognizedFormat {
+                path: path.to_path_buf(),
+            }),
+        };
+    }
+
+    let mut objects = Vec::new();
+    for section in pem::sections(&contents) {
+        match section {
+            Ok(section) => objects.extend(T::from_pem(&section.label, section.der)),
+            Err(_) => {
+                return Err(LoadError::InvalidPem {
+                    path: path.to_path_buf(),
+                })
+            }
+        }
+    }
+
+    Ok(objects)
+}
+
+/// An error loading certificates from the file system
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum LoadError {
+    /// A file or directory could not be read
+    Io {
+        /// The path of the file or directory
+        path: PathBuf,
+        /// The underlying error
+        error: io::Error,
+    },
+    /// A file looked like PEM, but could not be decoded
+    InvalidPem {
+        /// The path of the file
+        path: PathBuf,
+    },
+    /// A file was neither PEM- nor DER-encoded
+    UnrecognizedFormat {
+        /// The path of the file
+        path: PathBuf,
+    },
+    /// Neither `SSL_CERT_FILE` nor `SSL_CERT_DIR` was set
+    ///
+    /// Returned by [`load_native_cert_sources()`].
+    NoSourcesConfigured,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::InvalidPem { path } => write!(f, "invalid PEM in {}", path.display()),
+            Self::UnrecognizedFormat { path } => {
+                write!(f, "{} is neither PEM- nor DER-encoded", path.display())
+            }
+            Self::NoSourcesConfigured => write!(
+                f,
+                "neither {SSL_CERT_FILE} nor {SSL_CERT_DIR} is set in the environment"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
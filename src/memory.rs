@@ -0,0 +1,149 @@
+//! Approximate accounting of the heap memory held by the types in this crate.
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::mem;
+
+#[cfg(feature = "alloc")]
+use crate::{CertificateChainDer, QualifiedTrustAnchor};
+use crate::{
+    CertificateDer, CertificateRevocationListDer, CertificateSigningRequestDer, Der, DerInner,
+    OcspResponseDer, PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer,
+    RevocationMaterial, SubjectPublicKeyInfoDer, TrustAnchor,
+};
+
+/// Approximate accounting of the heap memory held by a value
+///
+/// This is intended for caches that enforce a budget in bytes. The figures are approximate:
+/// they count the allocations owned by a value (including unused capacity), but not the
+/// allocator's own overhead, nor the inline size of the value itself.
+pub trait MemoryUsage {
+    /// Approximate number of bytes of heap memory owned by this value
+    ///
+    /// Borrowed data is not owned, and so counts as zero.
+    fn approx_heap_size(&self) -> usize;
+}
+
+impl MemoryUsage for Der<'_> {
+    fn approx_heap_size(&self) -> usize {
+        match &self.0 {
+            #[cfg(feature = "alloc")]
+            DerInner::Owned(vec) => vec.capacity(),
+            DerInner::Borrowed(_) => 0,
+        }
+    }
+}
+
+impl MemoryUsage for CertificateDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for SubjectPublicKeyInfoDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for CertificateSigningRequestDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for CertificateRevocationListDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for OcspResponseDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for RevocationMaterial<'_> {
+    fn approx_heap_size(&self) -> usize {
+        match self {
+            Self::Crl(crl) => crl.approx_heap_size(),
+            Self::Ocsp(ocsp) => ocsp.approx_heap_size(),
+        }
+    }
+}
+
+impl MemoryUsage for TrustAnchor<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.subject.approx_heap_size()
+            + self.subject_public_key_info.approx_heap_size()
+            + self.name_constraints.approx_heap_size()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl MemoryUsage for QualifiedTrustAnchor<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.anchor.approx_heap_size()
+            + self.allowed_ekus.approx_heap_size()
+            + self.flags.capacity() * mem::size_of::<String>()
+            + self.flags.iter().map(|f| f.capacity()).sum::<usize>()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl MemoryUsage for CertificateChainDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for PrivateKeyDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        match self {
+            Self::Pkcs1(key) => key.approx_heap_size(),
+            Self::Sec1(key) => key.approx_heap_size(),
+            Self::Pkcs8(key) => key.approx_heap_size(),
+        }
+    }
+}
+
+impl MemoryUsage for PrivatePkcs1KeyDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for PrivateSec1KeyDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl MemoryUsage for PrivatePkcs8KeyDer<'_> {
+    fn approx_heap_size(&self) -> usize {
+        self.0.approx_heap_size()
+    }
+}
+
+impl<T: MemoryUsage> MemoryUsage for Option<T> {
+    fn approx_heap_size(&self) -> usize {
+        self.as_ref().map_or(0, T::approx_heap_size)
+    }
+}
+
+/// The sum over the elements, such as the certificates in a chain
+impl<T: MemoryUsage> MemoryUsage for [T] {
+    fn approx_heap_size(&self) -> usize {
+        self.iter().map(T::approx_heap_size).sum()
+    }
+}
+
+/// The vector's own allocation, plus the sum over the elements
+#[cfg(feature = "alloc")]
+impl<T: MemoryUsage> MemoryUsage for Vec<T> {
+    fn approx_heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.as_slice().approx_heap_size()
+    }
+}
@@ -0,0 +1,313 @@
+//! Parsing of the `certdata.txt` file of Mozilla's root store.
+//!
+//! `certdata.txt` is the source from which NSS builds its built-in root certificates. It holds
+//! certificate objects and trust objects, written as lists of PKCS#11 `CKA_*` attributes.
+//! [`parse()`] pairs each certificate with the trust Mozilla places in it for TLS server
+//! authentication, so that callers can decide which anchors to keep:
+//!
+//! ```
+//! use rustls_pki_types::mozilla_certdata::{parse, ServerAuthTrust};
+//!
+//! let certdata = r#"
+//! BEGINDATA
+//! CKA_CLASS CK_OBJECT_CLASS CKO_CERTIFICATE
+//! CKA_LABEL UTF8 "Example Root"
+//! CKA_ISSUER MULTILINE_OCTAL
+//! \060\000
+//! END
+//! CKA_SERIAL_NUMBER MULTILINE_OCTAL
+//! \002\001\001
+//! END
+//! CKA_VALUE MULTILINE_OCTAL
+//! \060\113\060\074\002\001\001\060\005\006\003\053\145\160\060\000
+//! \060\036\027\015\062\065\060\061\060\061\060\060\060\060\060\060
+//! \132\027\015\063\065\060\061\060\061\060\060\060\060\060\060\132
+//! \060\000\060\014\060\005\006\003\053\145\160\003\003\000\160\153
+//! \060\005\006\003\053\145\160\003\004\000\163\151\147
+//! END
+//! CKA_NSS_SERVER_DISTRUST_AFTER CK_BBOOL CK_FALSE
+//!
+//! CKA_CLASS CK_OBJECT_CLASS CKO_NSS_TRUST
+//! CKA_LABEL UTF8 "Example Root"
+//! CKA_ISSUER MULTILINE_OCTAL
+//! \060\000
+//! END
+//! CKA_SERIAL_NUMBER MULTILINE_OCTAL
+//! \002\001\001
+//! END
+//! CKA_TRUST_SERVER_AUTH CK_TRUST CKT_NSS_TRUSTED_DELEGATOR
+//! "#;
+//!
+//! let anchors = parse(certdata)
+//!     .unwrap()
+//!     .into_iter()
+//!     .filter(|(_, trust)| trust.server_auth == ServerAuthTrust::Trusted)
+//!     .map(|(anchor, _)| anchor)
+//!     .collect::<Vec<_>>();
+//! assert_eq!(anchors.len(), 1);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::der::{self, InvalidDer, Reader};
+use crate::{CertificateDer, TrustAnchor, UnixTime};
+
+/// Parse the certificates in `certdata`, with their trust for TLS server authentication
+///
+/// Every certificate object is returned, in the order of the file, including those which
+/// Mozilla distrusts: filtering is left to the caller's policy. Trust objects are matched to
+/// certificates by issuer and serial number; trust objects without a certificate, which
+/// Mozilla uses to distrust certificates it does not ship, are not returned.
+///
+/// Only the attributes needed for this are interpreted, but the syntax of every attribute is
+/// checked.
+pub fn parse(certdata: &str) -> Result<Vec<(TrustAnchor<'static>, TrustBits)>, CertdataError> {
+    let objects = objects(certdata)?;
+
+    let mut anchors = Vec::new();
+    for certificate in objects.iter().filter(|o| o.class == CERTIFICATE) {
+        let (line, value) = certificate.octets("CKA_VALUE")?;
+        let cert = CertificateDer::from(value);
+        let anchor = cert
+            .spans()
+            .and_then(|spans| spans.trust_anchor())
+            .map_err(|error| CertdataError::InvalidCertificate { line, error })?;
+
+        let server_distrust_after = match certificate.attribute("CKA_NSS_SERVER_DISTRUST_AFTER") {
+            Some((line, Value::Octets(time))) => {
+                Some(distrust_after(time).ok_or(CertdataError::InvalidDistrustAfter { line })?)
+            }
+            _ => None,
+        };
+
+        let issuer = certificate.octets("CKA_ISSUER")?.1;
+        let serial = certificate.octets("CKA_SERIAL_NUMBER")?.1;
+        let trust = objects.iter().find(|o| {
+            o.class == TRUST
+                && matches!(o.attribute("CKA_ISSUER"), Some((_, Value::Octets(v))) if v == issuer)
+                && matches!(o.attribute("CKA_SERIAL_NUMBER"), Some((_, Value::Octets(v))) if v == serial)
+        });
+
+        let server_auth = match trust.and_then(|t| t.attribute("CKA_TRUST_SERVER_AUTH")) {
+            Some((_, Value::Text("CK_TRUST CKT_NSS_TRUSTED_DELEGATOR"))) => {
+                ServerAuthTrust::Trusted
+            }
+            Some((_, Value::Text("CK_TRUST CKT_NSS_NOT_TRUSTED"))) => ServerAuthTrust::Distrusted,
+            _ => ServerAuthTrust::MustVerify,
+        };
+
+        anchors.push((
+            anchor.to_owned(),
+            TrustBits {
+                server_auth,
+                server_distrust_after,
+            },
+        ));
+    }
+
+    Ok(anchors)
+}
+
+/// Split `certdata` into objects, each starting with a `CKA_CLASS` attribute
+fn objects(certdata: &str) -> Result<Vec<Object<'_>>, CertdataError> {
+    let mut objects = Vec::<Object<'_>>::new();
+    let mut lines = certdata
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()));
+    while let Some((line, text)) = lines.next() {
+        if text.is_empty() || text.starts_with('#') || text == "BEGINDATA" {
+            continue;
+        }
+
+        let (name, rest) = match text.split_once(' ') {
+            Some((name, rest)) if name.starts_with("CKA_") => (name, rest.trim()),
+            _ => return Err(CertdataError::InvalidSyntax { line }),
+        };
+
+        let value = match rest {
+            "MULTILINE_OCTAL" => {
+                let mut octets = Vec::new();
+                loop {
+                    match lines.next() {
+                        Some((_, "END")) => break,
+                        Some((line, text)) => decode_octal(text, &mut octets)
+                            .ok_or(CertdataError::InvalidSyntax { line })?,
+                        None => return Err(CertdataError::InvalidSyntax { line }),
+                    }
+                }
+                Value::Octets(octets)
+            }
+            "" => return Err(CertdataError::InvalidSyntax { line }),
+            text => Value::Text(text),
+        };
+
+        match (name, &value, objects.last_mut()) {
+            ("CKA_CLASS", Value::Text(class), _) => objects.push(Object {
+                class: class.strip_prefix("CK_OBJECT_CLASS ").unwrap_or(class),
+                attributes: Vec::new(),
+            }),
+            ("CKA_CLASS", _, _) | (_, _, None) => {
+                return Err(CertdataError::InvalidSyntax { line })
+            }
+            (_, _, Some(object)) => object.attributes.push((name, line, value)),
+        }
+    }
+
+    Ok(objects)
+}
+
+/// Append the octets of a `MULTILINE_OCTAL` line like `\060\202` to `out`
+fn decode_octal(text: &str, out: &mut Vec<u8>) -> Option<()> {
+    let mut escapes = text.split('\\');
+    if escapes.next() != Some("") {
+        return None;
+    }
+
+    for digits in escapes {
+        if digits.len() != 3 {
+            return None;
+        }
+        out.push(u8::from_str_radix(digits, 8).ok()?);
+    }
+
+    Some(())
+}
+
+/// Decode a distrust date, which is written as the contents of a `UTCTime`
+fn distrust_after(time: &[u8]) -> Option<UnixTime> {
+    if time.len() >= 0x80 {
+        return None;
+    }
+
+    let mut encoded = Vec::with_capacity(time.len() + 2);
+    encoded.push(der::UTC_TIME);
+    encoded.push(time.len() as u8);
+    encoded.extend_from_slice(time);
+
+    let mut reader = Reader::new(&encoded);
+    let time = reader.read_time().ok()?;
+    reader.finish().ok()?;
+    Some(time)
+}
+
+struct Object<'a> {
+    /// The `CKO_*` class of the object
+    class: &'a str,
+    /// The name, line number and value of each attribute besides `CKA_CLASS`
+    attributes: Vec<(&'a str, usize, Value<'a>)>,
+}
+
+impl<'a> Object<'a> {
+    fn attribute(&self, name: &str) -> Option<(usize, &Value<'a>)> {
+        self.attributes
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|(_, line, value)| (*line, value))
+    }
+
+    fn octets(&self, name: &'static str) -> Result<(usize, &[u8]), CertdataError> {
+        match self.attribute(name) {
+            Some((line, Value::Octets(octets))) => Ok((line, octets)),
+            Some((line, Value::Text(_))) => Err(CertdataError::InvalidSyntax { line }),
+            None => Err(CertdataError::MissingAttribute(name)),
+        }
+    }
+}
+
+enum Value<'a> {
+    /// The type and value of a single-line attribute, like `CK_BBOOL CK_TRUE`
+    Text(&'a str),
+    /// The value of a `MULTILINE_OCTAL` attribute
+    Octets(Vec<u8>),
+}
+
+/// The trust Mozilla places in a certificate, from [`parse()`]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrustBits {
+    /// Whether the certificate is a trust anchor for TLS server authentication
+    ///
+    /// This is the `CKA_TRUST_SERVER_AUTH` attribute of the certificate's trust object.
+    pub server_auth: ServerAuthTrust,
+    /// Certificates issued by this anchor after this time should not be trusted for TLS server
+    /// authentication, if set
+    ///
+    /// This is the `CKA_NSS_SERVER_DISTRUST_AFTER` attribute of the certificate object. It can
+    /// be carried into [`QualifiedTrustAnchor::distrust_after`](crate::QualifiedTrustAnchor).
+    pub server_distrust_after: Option<UnixTime>,
+}
+
+/// The trust in a certificate for TLS server authentication
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ServerAuthTrust {
+    /// The certificate is a trust anchor (`CKT_NSS_TRUSTED_DELEGATOR`)
+    Trusted,
+    /// The certificate must not be trusted, even if it chains to a trust anchor
+    /// (`CKT_NSS_NOT_TRUSTED`)
+    Distrusted,
+    /// The certificate is not a trust anchor, but may be trusted if it chains to one
+    ///
+    /// This is `CKT_NSS_MUST_VERIFY_TRUST` and any other value, and is also used for
+    /// certificates without a trust object.
+    MustVerify,
+}
+
+/// An error from parsing `certdata.txt` with [`parse()`]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CertdataError {
+    /// An attribute was malformed
+    InvalidSyntax {
+        /// The line number of the attribute, starting from 1
+        line: usize,
+    },
+    /// A certificate object lacked a required attribute
+    MissingAttribute(&'static str),
+    /// A certificate could not be decoded
+    InvalidCertificate {
+        /// The line number of the `CKA_VALUE` attribute, starting from 1
+        line: usize,
+        /// The error from decoding the certificate
+        error: InvalidDer,
+    },
+    /// A `CKA_NSS_SERVER_DISTRUST_AFTER` date was not a valid `UTCTime`
+    InvalidDistrustAfter {
+        /// The line number of the attribute, starting from 1
+        line: usize,
+    },
+}
+
+impl fmt::Display for CertdataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax { line } => {
+                write!(f, "malformed certdata attribute at line {line}")
+            }
+            Self::MissingAttribute(name) => {
+                write!(f, "certdata certificate object has no {name} attribute")
+            }
+            Self::InvalidCertificate { line, error } => {
+                write!(f, "invalid certdata certificate at line {line}: {error}")
+            }
+            Self::InvalidDistrustAfter { line } => {
+                write!(f, "invalid certdata distrust date at line {line}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CertdataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidCertificate { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+const CERTIFICATE: &str = "CKO_CERTIFICATE";
+const TRUST: &str = "CKO_NSS_TRUST";
@@ -0,0 +1,100 @@
+//! Interning of certificates, so that repeated copies share one allocation.
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::{CertificateDer, MemoryUsage};
+
+/// A pool of certificates, which lets repeated copies of a certificate share one allocation
+///
+/// Applications holding many certificate chains often hold many copies of the same few
+/// intermediate certificates. Interning each certificate through a `CertificatePool` keeps a
+/// single copy of each distinct certificate, handing out cheap shared handles to it.
+///
+/// The handles are `Arc<CertificateDer<'static>>`, rather than `CertificateDer`s: a
+/// `CertificateDer` either borrows its contents or owns them outright, so one that outlives the
+/// pool would need its own copy, which is what interning avoids. The handles dereference to
+/// [`CertificateDer`], so interning does not affect comparisons, hashing or any other use of
+/// the certificate. Where a `CertificateDer` is needed by value,
+/// [`CertificateDer::as_borrowed()`] lends one without copying.
+///
+/// ```
+/// use rustls_pki_types::{CertificateDer, CertificatePool};
+///
+/// let mut pool = CertificatePool::new();
+/// let first = pool.intern(CertificateDer::from(vec![0x30, 0x00]));
+/// let second = pool.intern(CertificateDer::from(vec![0x30, 0x00]));
+/// assert_eq!(pool.len(), 1);
+/// assert_eq!(first.as_ptr(), second.as_ptr());
+///
+/// fn consume(cert: CertificateDer<'_>) -> usize {
+///     cert.len()
+/// }
+/// assert_eq!(consume(first.as_borrowed()), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CertificatePool {
+    /// Sorted by the certificates' contents
+    entries: Vec<Arc<CertificateDer<'static>>>,
+}
+
+impl CertificatePool {
+    /// Make an empty `CertificatePool`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Yield a shared handle to a certificate with the same contents as `cert`
+    ///
+    /// If the pool already holds such a certificate, `cert` is dropped and a handle to the
+    /// existing one is returned. Otherwise `cert` is added to the pool, which copies its
+    /// contents only if they are borrowed. The handle is an `Arc`, not a `CertificateDer`, as
+    /// explained in the [type documentation](CertificatePool).
+    pub fn intern(&mut self, cert: CertificateDer<'_>) -> Arc<CertificateDer<'static>> {
+        match self
+            .entries
+            .binary_search_by(|entry| entry.as_ref().as_ref().cmp(cert.as_ref()))
+        {
+            Ok(i) => self.entries[i].clone(),
+            Err(i) => {
+                let entry = Arc::new(CertificateDer(cert.0.into_owned()));
+                self.entries.insert(i, entry.clone());
+                entry
+            }
+        }
+    }
+
+    /// Remove the certificates for which the pool holds the only handle
+    ///
+    /// Returns the number of certificates removed.
+    pub fn evict_idle(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| Arc::strong_count(entry) > 1);
+        before - self.entries.len()
+    }
+
+    /// The number of distinct certificates in the pool
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the pool is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Each certificate is counted once, however many handles to it exist
+impl MemoryUsage for CertificatePool {
+    fn approx_heap_size(&self) -> usize {
+        // Each `Arc` allocation holds the strong and weak counts next to the value.
+        let arc_allocation = 2 * mem::size_of::<usize>() + mem::size_of::<CertificateDer<'_>>();
+        self.entries.capacity() * mem::size_of::<Arc<CertificateDer<'_>>>()
+            + self
+                .entries
+                .iter()
+                .map(|entry| arc_allocation + entry.approx_heap_size())
+                .sum::<usize>()
+    }
+}
@@ -0,0 +1,106 @@
+//! Subject alternative names of certificates.
+
+use core::str;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::der::{self, InvalidDer, Reader};
+use crate::{CertificateDer, CertificateSpans};
+
+impl CertificateDer<'_> {
+    /// Iterate over the names in this certificate's subject alternative name extension
+    ///
+    /// See [`CertificateSpans::subject_alternative_names()`].
+    pub fn subject_alternative_names(&self) -> Result<SubjectAltNames<'_>, InvalidDer> {
+        self.spans()?.subject_alternative_names()
+    }
+}
+
+impl<'a> CertificateSpans<'a> {
+    /// Iterate over the names in the subject alternative name extension
+    ///
+    /// A certificate without the extension (or without any extensions) yields an empty
+    /// iterator. This lists the names a certificate covers, for diagnostics and routing; it is
+    /// not a substitute for verifying that a certificate is valid for a name.
+    pub fn subject_alternative_names(&self) -> Result<SubjectAltNames<'a>, InvalidDer> {
+        let names = match self.extension(SUBJECT_ALT_NAME)? {
+            Some(extension) => der::expect_single(extension.value, der::SEQUENCE)?,
+            None => &[],
+        };
+
+        Ok(SubjectAltNames {
+            reader: Reader::new(names),
+        })
+    }
+}
+
+/// An iterator over the subject alternative names of a certificate
+///
+/// Obtained from [`CertificateSpans::subject_alternative_names()`]. After yielding an error, the
+/// iterator yields nothing further.
+#[derive(Clone, Debug)]
+pub struct SubjectAltNames<'a> {
+    reader: Reader<'a>,
+}
+
+impl<'a> Iterator for SubjectAltNames<'a> {
+    type Item = Result<SubjectAltName<'a>, InvalidDer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.peek_tag()?;
+
+        let name = self.reader.read_any().and_then(|name| match name.tag {
+            DNS_NAME => match name.contents.is_ascii() {
+                // ASCII is always valid UTF-8.
+                true => Ok(SubjectAltName::DnsName(
+                    str::from_utf8(name.contents).unwrap(),
+                )),
+                false => Err(InvalidDer::InvalidValue),
+            },
+            IP_ADDRESS => match name.contents.len() {
+                4 => Ok(SubjectAltName::IpAddress(IpAddr::V4(Ipv4Addr::from(
+                    <[u8; 4]>::try_from(name.contents).unwrap(),
+                )))),
+                16 => Ok(SubjectAltName::IpAddress(IpAddr::V6(Ipv6Addr::from(
+                    <[u8; 16]>::try_from(name.contents).unwrap(),
+                )))),
+                _ => Err(InvalidDer::InvalidValue),
+            },
+            tag => Ok(SubjectAltName::Other {
+                tag,
+                value: name.contents,
+            }),
+        });
+
+        if name.is_err() {
+            self.reader = Reader::new(&[]);
+        }
+
+        Some(name)
+    }
+}
+
+/// A subject alternative name, as a `GeneralName` (RFC 5280 section 4.2.1.6)
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubjectAltName<'a> {
+    /// A `dNSName`
+    ///
+    /// This is only checked to be ASCII: it may be a wildcard like `*.example.com`, or not be
+    /// a valid DNS name at all.
+    DnsName(&'a str),
+    /// An `iPAddress`
+    IpAddress(IpAddr),
+    /// Any other kind of name, like a `uniformResourceIdentifier` or `rfc822Name`
+    Other {
+        /// The context-specific tag of the `GeneralName` choice, like `0x86` for a URI
+        tag: u8,
+        /// The contents of the element, without its tag and length
+        value: &'a [u8],
+    },
+}
+
+const DNS_NAME: u8 = der::context_primitive(2);
+const IP_ADDRESS: u8 = der::context_primitive(7);
+
+/// `id-ce-subjectAltName`, 2.5.29.17
+const SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1d, 0x11];
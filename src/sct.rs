@@ -0,0 +1,235 @@
+//! Signed certificate timestamps for Certificate Transparency, as specified in RFC 6962.
+
+use core::fmt;
+use core::time::Duration;
+
+use crate::der::{self, InvalidDer};
+use crate::{CertificateDer, CertificateSpans, UnixTime};
+
+impl CertificateDer<'_> {
+    /// The SCTs embedded in the certificate, if it has the SCT extension
+    ///
+    /// See [`CertificateSpans::sct_list()`].
+    pub fn sct_list(&self) -> Result<Option<SctList<'_>>, InvalidDer> {
+        self.spans()?.sct_list()
+    }
+}
+
+impl<'a> CertificateSpans<'a> {
+    /// The SCTs embedded in the certificate, if it has the SCT extension
+    ///
+    /// This is the extension added by certificate authorities to certificates logged with a
+    /// precertificate (RFC 6962 section 3.3).
+    pub fn sct_list(&self) -> Result<Option<SctList<'a>>, InvalidDer> {
+        match self.extension(SCT_LIST)? {
+            Some(extension) => SctList::from_extension_value(extension.value).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A TLS-encoded `SignedCertificateTimestampList`
+///
+/// This is the form found in the `signed_certificate_timestamp` TLS extension and in stapled
+/// OCSP responses. The list is decoded lazily by [`SctList::iter()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SctList<'a>(&'a [u8]);
+
+impl<'a> SctList<'a> {
+    /// Use the TLS-encoded list in `bytes`
+    ///
+    /// The encoding is not checked until the list is iterated over.
+    pub const fn from_slice(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    /// Use the list embedded in a certificate's SCT extension
+    ///
+    /// `extn_value` is the contents of the extension's `extnValue`, which holds the TLS-encoded
+    /// list in a DER `OCTET STRING` (RFC 6962 section 3.3).
+    pub fn from_extension_value(extn_value: &'a [u8]) -> Result<Self, InvalidDer> {
+        Ok(Self(der::expect_single(extn_value, der::OCTET_STRING)?))
+    }
+
+    /// Iterate over the SCTs in the list
+    ///
+    /// An SCT with an unsupported version yields [`InvalidSct::UnsupportedVersion`], and the
+    /// iterator carries on with the next one. If the framing of the list itself is broken, the
+    /// iterator yields an error and then nothing further.
+    pub fn iter(&self) -> SctIter<'a> {
+        SctIter {
+            input: match split_u16_prefixed(self.0) {
+                Ok((list, [])) if !list.is_empty() => Ok(list),
+                Ok((_, [])) => Err(InvalidSct::Truncated),
+                Ok(_) => Err(InvalidSct::TrailingData),
+                Err(err) => Err(err),
+            },
+        }
+    }
+
+    /// The TLS-encoded list
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a> IntoIterator for &SctList<'a> {
+    type Item = Result<Sct<'a>, InvalidSct>;
+    type IntoIter = SctIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the SCTs in an [`SctList`]
+#[derive(Clone, Debug)]
+pub struct SctIter<'a> {
+    /// The remaining serialized SCTs, or the error to yield next
+    input: Result<&'a [u8], InvalidSct>,
+}
+
+impl<'a> Iterator for SctIter<'a> {
+    type Item = Result<Sct<'a>, InvalidSct>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = match self.input {
+            Ok([]) => return None,
+            Ok(input) => input,
+            Err(err) => {
+                self.input = Ok(&[]);
+                return Some(Err(err));
+            }
+        };
+
+        match split_u16_prefixed(input) {
+            Ok((sct, rest)) => {
+                self.input = Ok(rest);
+                Some(Sct::parse(sct))
+            }
+            Err(err) => {
+                self.input = Ok(&[]);
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// A signed certificate timestamp
+///
+/// This is a view of the fields of a version 1 `SignedCertificateTimestamp`. The signature is
+/// not verified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sct<'a> {
+    log_id: &'a [u8; 32],
+    timestamp: u64,
+    extensions: &'a [u8],
+    signature: &'a [u8],
+}
+
+impl<'a> Sct<'a> {
+    /// Decode a single TLS-encoded `SignedCertificateTimestamp`
+    ///
+    /// Only version 1 (RFC 6962) is supported.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, InvalidSct> {
+        let (&version, rest) = bytes.split_first().ok_or(InvalidSct::Truncated)?;
+        if version != 0 {
+            return Err(InvalidSct::UnsupportedVersion(version));
+        }
+
+        if rest.len() < 40 {
+            return Err(InvalidSct::Truncated);
+        }
+
+        let (log_id, rest) = rest.split_at(32);
+        let (timestamp, rest) = rest.split_at(8);
+        let (extensions, signature) = split_u16_prefixed(rest)?;
+
+        // The signature is a `DigitallySigned`: the hash and signature algorithms, followed by
+        // the signature itself.
+        match signature.get(2..).map(split_u16_prefixed) {
+            Some(Ok((_, []))) => {}
+            Some(Ok(_)) => return Err(InvalidSct::TrailingData),
+            Some(Err(err)) => return Err(err),
+            None => return Err(InvalidSct::Truncated),
+        }
+
+        let mut millis = [0; 8];
+        millis.copy_from_slice(timestamp);
+        Ok(Self {
+            log_id: <&[u8; 32]>::try_from(log_id).unwrap(),
+            timestamp: u64::from_be_bytes(millis),
+            extensions,
+            signature,
+        })
+    }
+
+    /// The SHA-256 hash of the log's public key
+    pub fn log_id(&self) -> &'a [u8; 32] {
+        self.log_id
+    }
+
+    /// The time at which the SCT was issued, in milliseconds since the Unix epoch
+    pub fn timestamp_millis(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// The time at which the SCT was issued, truncated to whole seconds
+    pub fn timestamp(&self) -> UnixTime {
+        UnixTime::since_unix_epoch(Duration::from_millis(self.timestamp))
+    }
+
+    /// The contents of the `extensions` field, without its length prefix
+    pub fn extensions(&self) -> &'a [u8] {
+        self.extensions
+    }
+
+    /// The TLS-encoded `DigitallySigned` signature, including the algorithms
+    pub fn signature(&self) -> &'a [u8] {
+        self.signature
+    }
+}
+
+/// Split a TLS-encoded vector with a 16-bit length prefix from the start of `input`
+fn split_u16_prefixed(input: &[u8]) -> Result<(&[u8], &[u8]), InvalidSct> {
+    match input {
+        [hi, lo, rest @ ..] => {
+            let len = usize::from(u16::from_be_bytes([*hi, *lo]));
+            match len <= rest.len() {
+                true => Ok(rest.split_at(len)),
+                false => Err(InvalidSct::Truncated),
+            }
+        }
+        _ => Err(InvalidSct::Truncated),
+    }
+}
+
+/// The OID of the embedded SCT extension, 1.3.6.1.4.1.11129.2.4.2
+const SCT_LIST: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0xd6, 0x79, 0x02, 0x04, 0x02];
+
+/// An error decoding a signed certificate timestamp or a list of them
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidSct {
+    /// The SCT has a version other than 1
+    UnsupportedVersion(u8),
+    /// The input ended before the structure was complete
+    Truncated,
+    /// Unexpected data followed the end of the structure
+    TrailingData,
+}
+
+impl fmt::Display for InvalidSct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported SCT version {}", u16::from(*version) + 1)
+            }
+            Self::Truncated => f.write_str("SCT is truncated"),
+            Self::TrailingData => f.write_str("unexpected data after the end of an SCT"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidSct {}
@@ -0,0 +1,254 @@
+//! Serialization of trust anchors, with the `serde` feature.
+//!
+//! [`TrustAnchor`] is serialized as a struct of its DER-encoded parts, and
+//! [`QualifiedTrustAnchor`] as a struct holding the anchor and its metadata, with the distrust
+//! date in seconds since the Unix epoch. Both deserialize into owned `'static` values. Optional
+//! fields may be left out of self-describing formats like JSON.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::serde_der::{deserialize_der, serialize_der};
+use crate::{Der, QualifiedTrustAnchor, TrustAnchor, UnixTime};
+
+impl Serialize for TrustAnchor<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut anchor = serializer.serialize_struct(ANCHOR, ANCHOR_FIELDS.len())?;
+        anchor.serialize_field(SUBJECT, &DerField(&self.subject))?;
+        anchor.serialize_field(SPKI, &DerField(&self.subject_public_key_info))?;
+        anchor.serialize_field(
+            NAME_CONSTRAINTS,
+            &self.name_constraints.as_deref().map(DerField),
+        )?;
+        anchor.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TrustAnchor<'static> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TrustAnchor<'static>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a trust anchor")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let subject = next_element::<OwnedDer, _>(&mut seq, 0, &self)?;
+                let spki = next_element::<OwnedDer, _>(&mut seq, 1, &self)?;
+                let name_constraints = next_element::<Option<OwnedDer>, _>(&mut seq, 2, &self)?;
+                Ok(TrustAnchor {
+                    subject: subject.into(),
+                    subject_public_key_info: spki.into(),
+                    name_constraints: name_constraints.map(Der::from),
+                })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let (mut subject, mut spki, mut name_constraints) = (None, None, None);
+                while let Some(field) = map.next_key_seed(Field(ANCHOR_FIELDS))? {
+                    match field {
+                        0 => set(&mut subject, map.next_value::<OwnedDer>()?, SUBJECT)?,
+                        1 => set(&mut spki, map.next_value::<OwnedDer>()?, SPKI)?,
+                        _ => set(
+                            &mut name_constraints,
+                            map.next_value::<Option<OwnedDer>>()?,
+                            NAME_CONSTRAINTS,
+                        )?,
+                    }
+                }
+
+                Ok(TrustAnchor {
+                    subject: subject
+                        .ok_or_else(|| de::Error::missing_field(SUBJECT))?
+                        .into(),
+                    subject_public_key_info: spki
+                        .ok_or_else(|| de::Error::missing_field(SPKI))?
+                        .into(),
+                    name_constraints: name_constraints.flatten().map(Der::from),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(ANCHOR, ANCHOR_FIELDS, Visitor)
+    }
+}
+
+impl Serialize for QualifiedTrustAnchor<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut qualified = serializer.serialize_struct(QUALIFIED, QUALIFIED_FIELDS.len())?;
+        qualified.serialize_field(ANCHOR_FIELD, &self.anchor)?;
+        qualified.serialize_field(
+            DISTRUST_AFTER,
+            &self.distrust_after.map(|after| after.as_secs()),
+        )?;
+        qualified.serialize_field(ALLOWED_EKUS, &self.allowed_ekus.as_deref().map(DerFields))?;
+        qualified.serialize_field(FLAGS, &self.flags)?;
+        qualified.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for QualifiedTrustAnchor<'static> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = QualifiedTrustAnchor<'static>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a qualified trust anchor")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                Ok(qualified(
+                    next_element(&mut seq, 0, &self)?,
+                    next_element(&mut seq, 1, &self)?,
+                    next_element(&mut seq, 2, &self)?,
+                    next_element(&mut seq, 3, &self)?,
+                ))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let (mut anchor, mut distrust_after) = (None, None);
+                let (mut allowed_ekus, mut flags) = (None, None);
+                while let Some(field) = map.next_key_seed(Field(QUALIFIED_FIELDS))? {
+                    match field {
+                        0 => set(&mut anchor, map.next_value()?, ANCHOR_FIELD)?,
+                        1 => set(&mut distrust_after, map.next_value()?, DISTRUST_AFTER)?,
+                        2 => set(&mut allowed_ekus, map.next_value()?, ALLOWED_EKUS)?,
+                        _ => set(&mut flags, map.next_value()?, FLAGS)?,
+                    }
+                }
+
+                Ok(qualified(
+                    anchor.ok_or_else(|| de::Error::missing_field(ANCHOR_FIELD))?,
+                    distrust_after.flatten(),
+                    allowed_ekus.flatten(),
+                    flags.unwrap_or_default(),
+                ))
+            }
+        }
+
+        deserializer.deserialize_struct(QUALIFIED, QUALIFIED_FIELDS, Visitor)
+    }
+}
+
+fn qualified(
+    anchor: TrustAnchor<'static>,
+    distrust_after: Option<u64>,
+    allowed_ekus: Option<Vec<OwnedDer>>,
+    flags: Vec<String>,
+) -> QualifiedTrustAnchor<'static> {
+    QualifiedTrustAnchor {
+        anchor,
+        distrust_after: distrust_after
+            .map(|secs| UnixTime::since_unix_epoch(Duration::from_secs(secs))),
+        allowed_ekus: allowed_ekus.map(|ekus| ekus.into_iter().map(Der::from).collect()),
+        flags,
+    }
+}
+
+/// The next element of a struct serialized as a sequence, which has the field `index`
+fn next_element<'de, T: Deserialize<'de>, A: SeqAccess<'de>>(
+    seq: &mut A,
+    index: usize,
+    expected: &dyn de::Expected,
+) -> Result<T, A::Error> {
+    seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(index, expected))
+}
+
+/// Store the value of the field `name` in `slot`, unless it was already given
+fn set<T, E: de::Error>(slot: &mut Option<T>, value: T, name: &'static str) -> Result<(), E> {
+    match slot.replace(value) {
+        Some(_) => Err(E::duplicate_field(name)),
+        None => Ok(()),
+    }
+}
+
+/// Serializes a DER-encoded part, as bytes or base64
+struct DerField<'a>(&'a [u8]);
+
+impl Serialize for DerField<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_der(self.0, serializer)
+    }
+}
+
+/// Serializes a list of DER-encoded parts, each as bytes or base64
+struct DerFields<'a>(&'a [Der<'a>]);
+
+impl Serialize for DerFields<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|der| DerField(der)))
+    }
+}
+
+/// Deserializes a DER-encoded part, from bytes or base64
+struct OwnedDer(Vec<u8>);
+
+impl<'de> Deserialize<'de> for OwnedDer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_der(deserializer, "DER-encoded bytes").map(Self)
+    }
+}
+
+impl From<OwnedDer> for Der<'static> {
+    fn from(der: OwnedDer) -> Self {
+        Der::from(der.0)
+    }
+}
+
+/// Deserializes the name or index of one of the given fields, yielding its index
+struct Field(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for Field {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl de::Visitor<'_> for Field {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a field name")
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<usize, E> {
+        match usize::try_from(index) {
+            Ok(index) if index < self.0.len() => Ok(index),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(index), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|field| *field == name)
+            .ok_or_else(|| E::unknown_field(name, self.0))
+    }
+}
+
+const ANCHOR: &str = "TrustAnchor";
+const SUBJECT: &str = "subject";
+const SPKI: &str = "subject_public_key_info";
+const NAME_CONSTRAINTS: &str = "name_constraints";
+const ANCHOR_FIELDS: &[&str] = &[SUBJECT, SPKI, NAME_CONSTRAINTS];
+
+const QUALIFIED: &str = "QualifiedTrustAnchor";
+const ANCHOR_FIELD: &str = "anchor";
+const DISTRUST_AFTER: &str = "distrust_after";
+const ALLOWED_EKUS: &str = "allowed_ekus";
+const FLAGS: &str = "flags";
+const QUALIFIED_FIELDS: &[&str] = &[ANCHOR_FIELD, DISTRUST_AFTER, ALLOWED_EKUS, FLAGS];
@@ -0,0 +1,68 @@
+//! Serialization of DER-encoded values, shared by the `serde` and `serde-secrets` features.
+//!
+//! Values are serialized as bytes for binary formats, and as padded standard base64 for
+//! human-readable formats.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, Deserializer, SeqAccess};
+use serde::ser::Serializer;
+
+use crate::base64::{self, STANDARD};
+
+pub(crate) fn serialize_der<S: Serializer>(der: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.collect_str(&Base64(der)),
+        false => serializer.serialize_bytes(der),
+    }
+}
+
+/// Writes its contents as padded standard base64, without an intermediate copy
+struct Base64<'a>(&'a [u8]);
+
+impl fmt::Display for Base64<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        base64::write(self.0, STANDARD, true, f)
+    }
+}
+
+pub(crate) fn deserialize_der<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    expecting: &'static str,
+) -> Result<Vec<u8>, D::Error> {
+    struct Visitor(&'static str);
+
+    impl<'de> de::Visitor<'de> for Visitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+
+        fn visit_str<E: de::Error>(self, encoded: &str) -> Result<Vec<u8>, E> {
+            base64::decode(encoded.as_bytes()).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, der: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(der.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, der: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(der)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut der = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                der.push(byte);
+            }
+            Ok(der)
+        }
+    }
+
+    match deserializer.is_human_readable() {
+        true => deserializer.deserialize_str(Visitor(expecting)),
+        false => deserializer.deserialize_byte_buf(Visitor(expecting)),
+    }
+}
@@ -0,0 +1,119 @@
+//! Serialization of private keys, with the `serde-secrets` feature.
+//!
+//! Keys are serialized as their DER encoding: as bytes for binary formats, and as padded
+//! standard base64 for human-readable formats. Anyone who can read the output can use the key,
+//! so only serialize keys into storage that is itself protected, like an encrypted database.
+//! Copies of the key made by serializers and deserializers are not wiped.
+
+use core::fmt;
+
+use serde::de::{self, Deserializer, EnumAccess, VariantAccess};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::serde_der::{deserialize_der, serialize_der};
+use crate::{PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer};
+
+/// Serialized as a newtype variant named for the format, holding the key
+///
+/// For example, a PKCS#8 key is serialized to JSON as `{"Pkcs8":"MC4CAQAw..."}`.
+impl Serialize for PrivateKeyDer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Pkcs1(key) => serializer.serialize_newtype_variant(NAME, 0, PKCS1, key),
+            Self::Sec1(key) => serializer.serialize_newtype_variant(NAME, 1, SEC1, key),
+            Self::Pkcs8(key) => serializer.serialize_newtype_variant(NAME, 2, PKCS8, key),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PrivateKeyDer<'static> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PrivateKeyDer<'static>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a PKCS#1, SEC1 or PKCS#8 private key")
+            }
+
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+                Ok(match data.variant()? {
+                    (Format::Pkcs1, key) => PrivateKeyDer::Pkcs1(key.newtype_variant()?),
+                    (Format::Sec1, key) => PrivateKeyDer::Sec1(key.newtype_variant()?),
+                    (Format::Pkcs8, key) => PrivateKeyDer::Pkcs8(key.newtype_variant()?),
+                })
+            }
+        }
+
+        deserializer.deserialize_enum(NAME, &[PKCS1, SEC1, PKCS8], Visitor)
+    }
+}
+
+/// The variant of a serialized [`PrivateKeyDer`]
+enum Format {
+    Pkcs1,
+    Sec1,
+    Pkcs8,
+}
+
+impl<'de> Deserialize<'de> for Format {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = Format;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a private key format")
+            }
+
+            fn visit_u64<E: de::Error>(self, index: u64) -> Result<Format, E> {
+                match index {
+                    0 => Ok(Format::Pkcs1),
+                    1 => Ok(Format::Sec1),
+                    2 => Ok(Format::Pkcs8),
+                    _ => Err(E::invalid_value(de::Unexpected::Unsigned(index), &self)),
+                }
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<Format, E> {
+                match name {
+                    PKCS1 => Ok(Format::Pkcs1),
+                    SEC1 => Ok(Format::Sec1),
+                    PKCS8 => Ok(Format::Pkcs8),
+                    _ => Err(E::unknown_variant(name, &[PKCS1, SEC1, PKCS8])),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(Visitor)
+    }
+}
+
+macro_rules! key_serde {
+    ($ty:ident, $secret_der:ident, $expecting:literal) => {
+        /// Serialized as the DER encoding: as bytes, or as base64 for human-readable formats
+        impl Serialize for $ty<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_der(self.$secret_der(), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty<'static> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_der(deserializer, $expecting).map(Self::from)
+            }
+        }
+    };
+}
+
+key_serde!(PrivatePkcs1KeyDer, secret_pkcs1_der, "a PKCS#1 private key");
+key_serde!(PrivateSec1KeyDer, secret_sec1_der, "a SEC1 private key");
+key_serde!(PrivatePkcs8KeyDer, secret_pkcs8_der, "a PKCS#8 private key");
+
+const NAME: &str = "PrivateKeyDer";
+const PKCS1: &str = "Pkcs1";
+const SEC1: &str = "Sec1";
+const PKCS8: &str = "Pkcs8";
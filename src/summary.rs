@@ -0,0 +1,153 @@
+//! One-line summaries of certificates and CRLs, for logs.
+
+use core::fmt;
+
+use crate::der::{self, InvalidDer, Reader};
+use crate::validity::Rfc3339;
+use crate::x509::NameSummary;
+use crate::{CertificateDer, CertificateRevocationListDer, SubjectPublicKeyInfoDer};
+
+impl CertificateDer<'_> {
+    /// A one-line summary of this certificate for humans, for use in logs
+    ///
+    /// The summary names the subject by its common name (or its first attribute), and gives the
+    /// expiry time and the public key algorithm, like
+    /// `CN=api.example.com, expires 2025-09-01T00:00:00Z, 2048-bit RSA`. If the certificate
+    /// cannot be decoded, its length and first octets are written instead. The format is meant
+    /// for people, not for parsing, but it will only change in minor releases.
+    pub fn display_summary(&self) -> CertificateSummary<'_> {
+        CertificateSummary(self)
+    }
+}
+
+/// Writes a one-line summary of a certificate, from [`CertificateDer::display_summary()`]
+#[derive(Clone, Copy, Debug)]
+pub struct CertificateSummary<'a>(&'a CertificateDer<'a>);
+
+impl fmt::Display for CertificateSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (spans, not_after) = match self
+            .0
+            .spans()
+            .and_then(|s| Ok((s.validity()?.not_after(), s)))
+        {
+            Ok((not_after, spans)) => (spans, not_after),
+            Err(_) => return write_malformed(f, "certificate", self.0),
+        };
+
+        write!(
+            f,
+            "{}, expires {}, ",
+            NameSummary(spans.subject()),
+            Rfc3339(not_after)
+        )?;
+        write_key_algorithm(f, &spans.subject_public_key_info())
+    }
+}
+
+impl CertificateRevocationListDer<'_> {
+    /// A one-line summary of this CRL for humans, for use in logs
+    ///
+    /// The summary names the issuer as for [`CertificateDer::display_summary()`], and gives the
+    /// time the CRL was issued and the number of revoked certificates, like
+    /// `CN=Example CA, updated 2025-06-01T00:00:00Z, 12 revoked`. If the CRL cannot be decoded,
+    /// its length and first octets are written instead.
+    pub fn display_summary(&self) -> CrlSummary<'_> {
+        CrlSummary(self)
+    }
+}
+
+/// Writes a one-line summary of a CRL, from [`CertificateRevocationListDer::display_summary()`]
+#[derive(Clone, Copy, Debug)]
+pub struct CrlSummary<'a>(&'a CertificateRevocationListDer<'a>);
+
+impl fmt::Display for CrlSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spans = match self.0.spans() {
+            Ok(spans) => spans,
+            Err(_) => return write_malformed(f, "CRL", self.0),
+        };
+
+        write!(
+            f,
+            "{}, updated {}, ",
+            NameSummary(spans.issuer()),
+            Rfc3339(spans.this_update())
+        )?;
+
+        match spans
+            .revoked_serials()
+            .try_fold(0usize, |count, entry| entry.map(|_| count + 1))
+        {
+            Ok(count) => write!(f, "{count} revoked"),
+            Err(_) => f.write_str("malformed revoked certificates"),
+        }
+    }
+}
+
+/// Write a name for the algorithm of `spki`, with the key size or curve where known
+fn write_key_algorithm(
+    f: &mut fmt::Formatter<'_>,
+    spki: &SubjectPublicKeyInfoDer<'_>,
+) -> fmt::Result {
+    let (oid, parameters) = match key_algorithm(spki) {
+        Ok(algorithm) => algorithm,
+        Err(_) => return f.write_str("malformed public key"),
+    };
+
+    match oid {
+        der::RSA_ENCRYPTION => match rsa_modulus_bits(spki) {
+            Ok(bits) => write!(f, "{bits}-bit RSA"),
+            Err(_) => f.write_str("RSA"),
+        },
+        der::EC_PUBLIC_KEY => match parameters {
+            Some(der::SECP256R1) => f.write_str("ECDSA P-256"),
+            Some(der::SECP384R1) => f.write_str("ECDSA P-384"),
+            Some(der::SECP521R1) => f.write_str("ECDSA P-521"),
+            Some(curve) => {
+                f.write_str("ECDSA ")?;
+                der::write_oid(f, curve)
+            }
+            None => f.write_str("ECDSA"),
+        },
+        der::ED25519 => f.write_str("Ed25519"),
+        der::ED448 => f.write_str("Ed448"),
+        der::X25519 => f.write_str("X25519"),
+        der::X448 => f.write_str("X448"),
+        _ => der::write_oid(f, oid),
+    }
+}
+
+/// The algorithm `OBJECT IDENTIFIER` of `spki`, and the curve for EC keys
+fn key_algorithm<'a>(
+    spki: &'a SubjectPublicKeyInfoDer<'_>,
+) -> Result<(&'a [u8], Option<&'a [u8]>), InvalidDer> {
+    let mut algorithm = Reader::new(spki.algorithm()?);
+    let oid = algorithm.read(der::OBJECT_IDENTIFIER)?;
+    Ok((oid, algorithm.read_optional(der::OBJECT_IDENTIFIER)?))
+}
+
+/// The number of significant bits in the modulus of an RSA key
+fn rsa_modulus_bits(spki: &SubjectPublicKeyInfoDer<'_>) -> Result<usize, InvalidDer> {
+    let mut key = Reader::new(der::expect_single(
+        spki.subject_public_key()?,
+        der::SEQUENCE,
+    )?);
+    let modulus = key.read(der::INTEGER)?;
+    let modulus = match modulus.iter().position(|&b| b != 0) {
+        Some(start) => &modulus[start..],
+        None => return Err(InvalidDer::InvalidValue),
+    };
+
+    Ok(modulus.len() * 8 - modulus[0].leading_zeros() as usize)
+}
+
+/// Write the length and the first octets of an object that could not be decoded
+fn write_malformed(f: &mut fmt::Formatter<'_>, kind: &str, der: &[u8]) -> fmt::Result {
+    write!(f, "malformed {kind} ({} bytes: ", der.len())?;
+    der.iter().take(16).try_for_each(|b| write!(f, "{b:02x}"))?;
+    match der.len() > 16 {
+        true => f.write_str("...)"),
+        false => f.write_str(")"),
+    }
+}
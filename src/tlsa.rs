@@ -0,0 +1,361 @@
+//! TLSA resource records for DANE, as specified in RFC 6698.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::fingerprint::hex_value;
+#[cfg(feature = "x509")]
+use crate::{CertificateDer, InvalidDer};
+
+/// A TLSA resource record, which associates a certificate or public key with a TLS server
+///
+/// This only carries the record: verifiers select the relevant part of a certificate with
+/// [`Selector`], and compare it (or its digest, according to [`MatchingType`]) to
+/// [`TlsaRecord::data`]. No hashing is done by this crate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TlsaRecord {
+    /// How the certificate association is used
+    pub usage: CertUsage,
+    /// Which part of the certificate is matched
+    pub selector: Selector,
+    /// How the selected part is compared to `data`
+    pub matching_type: MatchingType,
+    /// The certificate association data
+    pub data: Vec<u8>,
+}
+
+impl TlsaRecord {
+    /// Decode a record from its wire format RDATA
+    ///
+    /// The certificate association data must not be empty, and must have the digest length
+    /// for the SHA-256 and SHA-512 matching types.
+    pub fn from_rdata(rdata: &[u8]) -> Result<Self, InvalidTlsaRecord> {
+        match rdata {
+            [usage, selector, matching_type, data @ ..] => Self::new(
+                CertUsage::from(*usage),
+                Selector::from(*selector),
+                MatchingType::from(*matching_type),
+                data.to_vec(),
+            ),
+            _ => Err(InvalidTlsaRecord::Truncated),
+        }
+    }
+
+    /// Decode a record from its presentation format
+    ///
+    /// This is the format of the record's RDATA in zone files, like
+    /// `3 1 1 0D6FCE13243AA7...`: the three parameters in decimal, followed by the certificate
+    /// association data in hex, which may be split by whitespace. The mnemonics from RFC 7218,
+    /// like `DANE-EE SPKI SHA2-256`, are accepted in place of the numbers.
+    pub fn from_presentation(text: &str) -> Result<Self, InvalidTlsaRecord> {
+        let mut fields = text.split_ascii_whitespace();
+        let mut parameter = |mnemonics: &[(&str, u8)]| {
+            let field = fields.next().ok_or(InvalidTlsaRecord::InvalidText)?;
+            match mnemonics
+                .iter()
+                .find(|(m, _)| m.eq_ignore_ascii_case(field))
+            {
+                Some((_, value)) => Ok(*value),
+                None => field.parse().map_err(|_| InvalidTlsaRecord::InvalidText),
+            }
+        };
+
+        let usage = CertUsage::from(parameter(CertUsage::MNEMONICS)?);
+        let selector = Selector::from(parameter(Selector::MNEMONICS)?);
+        let matching_type = MatchingType::from(parameter(MatchingType::MNEMONICS)?);
+
+        let mut data = Vec::new();
+        for field in fields {
+            if field.len() % 2 != 0 || !field.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(InvalidTlsaRecord::InvalidText);
+            }
+
+            for pair in field.as_bytes().chunks(2) {
+                data.push(hex_value(pair[0]) << 4 | hex_value(pair[1]));
+            }
+        }
+
+        Self::new(usage, selector, matching_type, data)
+    }
+
+    /// Make a record from its parts, checking the length of `data`
+    pub fn new(
+        usage: CertUsage,
+        selector: Selector,
+        matching_type: MatchingType,
+        data: Vec<u8>,
+    ) -> Result<Self, InvalidTlsaRecord> {
+        if data.is_empty() {
+            return Err(InvalidTlsaRecord::Truncated);
+        }
+
+        if let Some(len) = matching_type.digest_len() {
+            if data.len() != len {
+                return Err(InvalidTlsaRecord::InvalidDigestLength);
+            }
+        }
+
+        Ok(Self {
+            usage,
+            selector,
+            matching_type,
+            data,
+        })
+    }
+
+    /// Encode this record as wire format RDATA
+    pub fn to_rdata(&self) -> Vec<u8> {
+        let mut rdata = Vec::with_capacity(3 + self.data.len());
+        rdata.push(u8::from(self.usage));
+        rdata.push(u8::from(self.selector));
+        rdata.push(u8::from(self.matching_type));
+        rdata.extend_from_slice(&self.data);
+        rdata
+    }
+}
+
+/// Writes the record in presentation format, with numeric parameters and uppercase hex
+impl fmt::Display for TlsaRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} ",
+            u8::from(self.usage),
+            u8::from(self.selector),
+            u8::from(self.matching_type)
+        )?;
+        self.data.iter().try_for_each(|b| write!(f, "{b:02X}"))
+    }
+}
+
+/// Serialized in presentation format, as by the `Display` implementation
+#[cfg(feature = "serde")]
+impl serde::Serialize for TlsaRecord {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialized from presentation format, as by [`TlsaRecord::from_presentation()`]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TlsaRecord {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = TlsaRecord;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a TLSA record in presentation format")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<TlsaRecord, E> {
+                TlsaRecord::from_presentation(text).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// The certificate usage field of a TLSA record
+///
+/// Values are from the IANA "TLSA Certificate Usages" registry.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CertUsage {
+    /// `PKIX-TA` (0): a CA certificate, which must also pass PKIX validation
+    PkixTa,
+    /// `PKIX-EE` (1): the end-entity certificate, which must also pass PKIX validation
+    PkixEe,
+    /// `DANE-TA` (2): a trust anchor for the server's certificate
+    DaneTa,
+    /// `DANE-EE` (3): the end-entity certificate, without PKIX validation
+    DaneEe,
+    /// `PrivCert` (255): reserved for private use
+    PrivCert,
+    /// Any other value
+    Unknown(u8),
+}
+
+impl CertUsage {
+    const MNEMONICS: &'static [(&'static str, u8)] = &[
+        ("PKIX-TA", 0),
+        ("PKIX-EE", 1),
+        ("DANE-TA", 2),
+        ("DANE-EE", 3),
+        ("PrivCert", 255),
+    ];
+}
+
+impl From<u8> for CertUsage {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::PkixTa,
+            1 => Self::PkixEe,
+            2 => Self::DaneTa,
+            3 => Self::DaneEe,
+            255 => Self::PrivCert,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<CertUsage> for u8 {
+    fn from(usage: CertUsage) -> Self {
+        match usage {
+            CertUsage::PkixTa => 0,
+            CertUsage::PkixEe => 1,
+            CertUsage::DaneTa => 2,
+            CertUsage::DaneEe => 3,
+            CertUsage::PrivCert => 255,
+            CertUsage::Unknown(other) => other,
+        }
+    }
+}
+
+/// The selector field of a TLSA record: which part of a certificate is matched
+///
+/// Values are from the IANA "TLSA Selectors" registry.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Selector {
+    /// `Cert` (0): the full DER-encoded certificate
+    FullCertificate,
+    /// `SPKI` (1): the DER-encoded `SubjectPublicKeyInfo` of the certificate
+    SubjectPublicKeyInfo,
+    /// `PrivSel` (255): reserved for private use
+    PrivSel,
+    /// Any other value
+    Unknown(u8),
+}
+
+impl Selector {
+    /// Select the bytes of `cert` that are to be matched, or their digest
+    ///
+    /// Yields `None` for selectors whose meaning is not known.
+    #[cfg(feature = "x509")]
+    pub fn select<'c>(&self, cert: &'c CertificateDer<'_>) -> Result<Option<&'c [u8]>, InvalidDer> {
+        Ok(match self {
+            Self::FullCertificate => Some(cert.as_ref()),
+            Self::SubjectPublicKeyInfo => Some(cert.spans()?.spki),
+            Self::PrivSel | Self::Unknown(_) => None,
+        })
+    }
+
+    const MNEMONICS: &'static [(&'static str, u8)] = &[("Cert", 0), ("SPKI", 1), ("PrivSel", 255)];
+}
+
+impl From<u8> for Selector {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::FullCertificate,
+            1 => Self::SubjectPublicKeyInfo,
+            255 => Self::PrivSel,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<Selector> for u8 {
+    fn from(selector: Selector) -> Self {
+        match selector {
+            Selector::FullCertificate => 0,
+            Selector::SubjectPublicKeyInfo => 1,
+            Selector::PrivSel => 255,
+            Selector::Unknown(other) => other,
+        }
+    }
+}
+
+/// The matching type field of a TLSA record: how the selected bytes are compared
+///
+/// Values are from the IANA "TLSA Matching Types" registry.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatchingType {
+    /// `Full` (0): the selected bytes are compared directly
+    Full,
+    /// `SHA2-256` (1): the SHA-256 digest of the selected bytes is compared
+    Sha256,
+    /// `SHA2-512` (2): the SHA-512 digest of the selected bytes is compared
+    Sha512,
+    /// `PrivMatch` (255): reserved for private use
+    PrivMatch,
+    /// Any other value
+    Unknown(u8),
+}
+
+impl MatchingType {
+    /// Whether the selected bytes are hashed before comparison
+    pub fn is_hashed(&self) -> bool {
+        self.digest_len().is_some()
+    }
+
+    /// The length of the digest compared, for the known hashed matching types
+    pub fn digest_len(&self) -> Option<usize> {
+        match self {
+            Self::Sha256 => Some(32),
+            Self::Sha512 => Some(64),
+            Self::Full | Self::PrivMatch | Self::Unknown(_) => None,
+        }
+    }
+
+    const MNEMONICS: &'static [(&'static str, u8)] = &[
+        ("Full", 0),
+        ("SHA2-256", 1),
+        ("SHA2-512", 2),
+        ("PrivMatch", 255),
+    ];
+}
+
+impl From<u8> for MatchingType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Full,
+            1 => Self::Sha256,
+            2 => Self::Sha512,
+            255 => Self::PrivMatch,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<MatchingType> for u8 {
+    fn from(matching_type: MatchingType) -> Self {
+        match matching_type {
+            MatchingType::Full => 0,
+            MatchingType::Sha256 => 1,
+            MatchingType::Sha512 => 2,
+            MatchingType::PrivMatch => 255,
+            MatchingType::Unknown(other) => other,
+        }
+    }
+}
+
+/// An error decoding a TLSA record
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidTlsaRecord {
+    /// The record was too short, or had no certificate association data
+    Truncated,
+    /// The certificate association data did not have the length of the matching type's digest
+    InvalidDigestLength,
+    /// The presentation format text was malformed
+    InvalidText,
+}
+
+impl fmt::Display for InvalidTlsaRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("TLSA record is truncated"),
+            Self::InvalidDigestLength => {
+                f.write_str("TLSA certificate association data has the wrong digest length")
+            }
+            Self::InvalidText => f.write_str("malformed TLSA record presentation format"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidTlsaRecord {}
@@ -0,0 +1,211 @@
+//! A deduplicated collection of trust anchors, indexed by subject.
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::TrustAnchor;
+
+/// A collection of trust anchors, without duplicates, that can be searched by subject
+///
+/// Anchors are identified by their subject and public key together, as by `diff_anchors()`.
+/// Adding an anchor that is already present leaves the store unchanged. The first anchor added
+/// is kept, with its name constraints.
+///
+/// Anchors stay in the order they were first added. The store dereferences to a slice of
+/// them, so it can be passed to APIs that take `&[TrustAnchor]`, and iterated over.
+///
+/// ```
+/// use rustls_pki_types::{TrustAnchor, TrustStore};
+///
+/// let subject: &[u8] = &[0x31, 0x00];
+/// let old = TrustAnchor::from_parts(subject, &[0x30, 0x01, 0x01], None);
+/// let new = TrustAnchor::from_parts(subject, &[0x30, 0x01, 0x02], None);
+/// let other = TrustAnchor::from_parts(&[0x31, 0x01, 0x00], &[0x30, 0x01, 0x03], None);
+///
+/// let store = [&old, &other, &new, &old]
+///     .into_iter()
+///     .cloned()
+///     .collect::<TrustStore<'_>>();
+/// assert_eq!(&store[..], [old.clone(), other, new.clone()]);
+///
+/// let issuers = store.find_by_subject(subject).collect::<Vec<_>>();
+/// assert_eq!(issuers, [&old, &new]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TrustStore<'a> {
+    anchors: Vec<TrustAnchor<'a>>,
+    /// Indices into `anchors`, ordered by subject, then by the order they were added
+    by_subject: Vec<usize>,
+}
+
+impl<'a> TrustStore<'a> {
+    /// Make an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `anchor`, unless an anchor with the same subject and public key is present
+    ///
+    /// Returns whether `anchor` was added.
+    pub fn insert(&mut self, anchor: TrustAnchor<'a>) -> bool {
+        let (start, end) = self.subject_range(&anchor.subject);
+        if self.by_subject[start..end]
+            .iter()
+            .any(|&i| self.anchors[i].subject_public_key_info == anchor.subject_public_key_info)
+        {
+            return false;
+        }
+
+        self.by_subject.insert(end, self.anchors.len());
+        self.anchors.push(anchor);
+        true
+    }
+
+    /// The anchors with the subject `subject`, in the order they were added
+    ///
+    /// `subject` has the same form as [`TrustAnchor::subject`], so when looking for the
+    /// issuer of a certificate, it is the `CertificateSpans::issuer()` of the certificate.
+    /// Anchors are found by a binary search, rather than a scan of the whole store.
+    pub fn find_by_subject<'s>(
+        &'s self,
+        subject: &[u8],
+    ) -> impl Iterator<Item = &'s TrustAnchor<'a>> + 's {
+        let (start, end) = self.subject_range(subject);
+        self.by_subject[start..end]
+            .iter()
+            .map(move |&i| &self.anchors[i])
+    }
+
+    /// Yield the anchors, in the order they were added
+    pub fn into_inner(self) -> Vec<TrustAnchor<'a>> {
+        self.anchors
+    }
+
+    /// The range of `by_subject` holding the anchors with the subject `subject`
+    fn subject_range(&self, subject: &[u8]) -> (usize, usize) {
+        let start = self
+            .by_subject
+            .partition_point(|&i| self.anchors[i].subject.as_ref() < subject);
+        let len = self.by_subject[start..]
+            .partition_point(|&i| self.anchors[i].subject.as_ref() == subject);
+        (start, start + len)
+    }
+}
+
+impl<'a> Deref for TrustStore<'a> {
+    type Target = [TrustAnchor<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.anchors
+    }
+}
+
+impl<'a> Extend<TrustAnchor<'a>> for TrustStore<'a> {
+    fn extend<I: IntoIterator<Item = TrustAnchor<'a>>>(&mut self, iter: I) {
+        for anchor in iter {
+            self.insert(anchor);
+        }
+    }
+}
+
+impl<'a> FromIterator<TrustAnchor<'a>> for TrustStore<'a> {
+    fn from_iter<I: IntoIterator<Item = TrustAnchor<'a>>>(iter: I) -> Self {
+        let mut store = Self::new();
+        store.extend(iter);
+        store
+    }
+}
+
+impl<'s, 'a> IntoIterator for &'s TrustStore<'a> {
+    type Item = &'s TrustAnchor<'a>;
+    type IntoIter = core::slice::Iter<'s, TrustAnchor<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.anchors.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicates_are_not_added() {
+        let mut store = TrustStore::new();
+        assert!(store.insert(anchor(b"a", b"1", None)));
+        assert!(!store.insert(anchor(b"a", b"1", None)));
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.find_by_subject(b"a").count(), 1);
+
+        // The first anchor is kept, with its name constraints.
+        assert!(!store.insert(anchor(b"a", b"1", Some(b"nc"))));
+        assert_eq!(store[0].name_constraints, None);
+
+        let mut store = TrustStore::new();
+        store.insert(anchor(b"a", b"1", Some(b"nc")));
+        store.insert(anchor(b"a", b"1", None));
+        assert_eq!(store[0].name_constraints.as_deref(), Some(&b"nc"[..]));
+    }
+
+    #[test]
+    fn one_subject_under_two_keys() {
+        let store = [
+            anchor(b"a", b"1", None),
+            anchor(b"b", b"1", None),
+            anchor(b"a", b"2", None),
+        ]
+        .into_iter()
+        .collect::<TrustStore<'_>>();
+        assert_eq!(store.len(), 3);
+
+        let keys = store
+            .find_by_subject(b"a")
+            .map(|anchor| anchor.subject_public_key_info.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, [b"1", b"2"]);
+        assert_eq!(store.find_by_subject(b"b").count(), 1);
+        assert_eq!(store.find_by_subject(b"c").count(), 0);
+        // Subjects are matched whole, not by prefix.
+        assert_eq!(store.find_by_subject(b"").count(), 0);
+        assert_eq!(store.find_by_subject(b"aa").count(), 0);
+    }
+
+    #[test]
+    fn iteration_order() {
+        // Subjects added in an order unrelated to their sort order.
+        let subjects: [&[u8]; 6] = [b"m", b"c", b"x", b"a", b"c", b"m"];
+        let keys = [[0], [1], [2], [3], [4], [5]];
+        let mut store = TrustStore::new();
+        for (subject, key) in subjects.iter().zip(&keys) {
+            store.insert(anchor(subject, key, None));
+        }
+        store.insert(anchor(b"x", &keys[2], None));
+
+        let added = store
+            .iter()
+            .map(|anchor| (anchor.subject.as_ref(), anchor.subject_public_key_info[0]))
+            .collect::<Vec<_>>();
+        let expected = subjects
+            .iter()
+            .enumerate()
+            .map(|(i, subject)| (*subject, i as u8))
+            .collect::<Vec<_>>();
+        assert_eq!(added, expected);
+        assert_eq!((&store).into_iter().count(), 6);
+        assert_eq!(store.clone().into_inner(), &store[..]);
+
+        let found = store
+            .find_by_subject(b"m")
+            .map(|anchor| anchor.subject_public_key_info[0])
+            .collect::<Vec<_>>();
+        assert_eq!(found, [0, 5]);
+    }
+
+    fn anchor<'a>(
+        subject: &'a [u8],
+        spki: &'a [u8],
+        name_constraints: Option<&'a [u8]>,
+    ) -> TrustAnchor<'a> {
+        TrustAnchor::from_parts(subject, spki, name_constraints)
+    }
+}
@@ -0,0 +1,143 @@
+//! Validity periods: checking whether a time falls in one, with or without clock skew.
+
+use core::fmt;
+use core::time::Duration;
+
+use crate::UnixTime;
+
+/// The period during which something, like a certificate, is valid
+///
+/// As for certificates (RFC 5280 section 4.1.2.5), the period includes both its first and its
+/// last second:
+///
+/// ```
+/// use core::time::Duration;
+/// use rustls_pki_types::{UnixTime, ValidityPeriod};
+///
+/// let at = |secs| UnixTime::since_unix_epoch(Duration::from_secs(secs));
+/// let period = ValidityPeriod::new(at(1_000), at(2_000)).unwrap();
+///
+/// assert!(!period.contains(at(999)));
+/// assert!(period.contains(at(1_000)));
+/// assert!(period.contains(at(2_000)));
+/// assert!(!period.contains(at(2_001)));
+///
+/// assert!(period.contains_with_skew(at(995), Duration::from_secs(5)));
+/// assert!(!period.contains_with_skew(at(2_006), Duration::from_secs(5)));
+///
+/// assert_eq!(period.remaining(at(2_000)), Some(Duration::ZERO));
+/// assert_eq!(period.remaining(at(2_001)), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidityPeriod {
+    not_before: UnixTime,
+    not_after: UnixTime,
+}
+
+impl ValidityPeriod {
+    /// Make a period from its first and last second
+    ///
+    /// A period that ends before it starts is rejected; one that starts and ends at the same
+    /// second is accepted, and contains only that second.
+    pub fn new(not_before: UnixTime, not_after: UnixTime) -> Result<Self, InvalidValidityPeriod> {
+        match not_before.as_secs() <= not_after.as_secs() {
+            true => Ok(Self {
+                not_before,
+                not_after,
+            }),
+            false => Err(InvalidValidityPeriod),
+        }
+    }
+
+    /// Whether `at` falls within the period, including its first and last second
+    pub fn contains(&self, at: UnixTime) -> bool {
+        self.not_before.as_secs() <= at.as_secs() && at.as_secs() <= self.not_after.as_secs()
+    }
+
+    /// Whether `at` falls within the period once extended by `skew` at both ends
+    ///
+    /// This tolerates clocks that differ by up to `skew`, which is truncated to whole seconds.
+    pub fn contains_with_skew(&self, at: UnixTime, skew: Duration) -> bool {
+        let skew = skew.as_secs();
+        self.not_before.as_secs().saturating_sub(skew) <= at.as_secs()
+            && at.as_secs() <= self.not_after.as_secs().saturating_add(skew)
+    }
+
+    /// The time left from `at` until the end of the period, if `at` falls within it
+    ///
+    /// At the last second of the period, this is zero.
+    pub fn remaining(&self, at: UnixTime) -> Option<Duration> {
+        match self.contains(at) {
+            true => Some(Duration::from_secs(self.not_after.as_secs() - at.as_secs())),
+            false => None,
+        }
+    }
+
+    /// The first second of the period
+    pub fn not_before(&self) -> UnixTime {
+        self.not_before
+    }
+
+    /// The last second of the period
+    pub fn not_after(&self) -> UnixTime {
+        self.not_after
+    }
+}
+
+impl fmt::Display for ValidityPeriod {
+    /// Writes the period as an interval of RFC 3339 times, like
+    /// `2025-01-01T00:00:00Z/2025-12-31T23:59:59Z`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            Rfc3339(self.not_before),
+            Rfc3339(self.not_after)
+        )
+    }
+}
+
+/// An error indicating that a validity period would end before it starts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidValidityPeriod;
+
+impl fmt::Display for InvalidValidityPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("validity period ends before it starts")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidValidityPeriod {}
+
+/// Writes a time in the format of RFC 3339, like `2024-02-29T12:00:00Z`
+pub(crate) struct Rfc3339(pub(crate) UnixTime);
+
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.as_secs();
+        let (days, secs) = (secs / 86_400, secs % 86_400);
+
+        // Shift the epoch to 0000-03-01, so that leap days fall at the end of each year, and
+        // count in 400-year eras of 146 097 days.
+        let days = days + 719_468;
+        let (era, day_of_era) = (days / 146_097, days % 146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let (year, month) = match shifted_month {
+            0..=9 => (era * 400 + year_of_era, shifted_month + 3),
+            _ => (era * 400 + year_of_era + 1, shifted_month - 9),
+        };
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+            secs / 3_600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    }
+}
@@ -0,0 +1,632 @@
+//! Minimal, structural access to fields of X.509 certificates and CRLs.
+//!
+//! The accessors in this module locate fields within the DER encoding of a certificate or
+//! certificate revocation list and yield them as borrowed values. They check that the encoding
+//! has the expected structure, but do not otherwise validate it, and in particular do not
+//! verify signatures.
+
+use core::fmt;
+
+use crate::der::{self, InvalidDer, Reader};
+#[cfg(feature = "mozilla-certdata")]
+use crate::TrustAnchor;
+use crate::{
+    CertificateDer, CertificateRevocationListDer, CertificateSigningRequestDer, OcspResponseDer,
+    RevocationMaterial, SubjectPublicKeyInfoDer, UnixTime, ValidityPeriod,
+};
+
+impl CertificateDer<'_> {
+    /// Locate the top-level fields of this certificate
+    ///
+    /// This decodes the certificate's structure once; prefer calling several accessors on the
+    /// returned [`CertificateSpans`] over calling the equivalent methods on `CertificateDer`,
+    /// which each decode the structure again.
+    pub fn spans(&self) -> Result<CertificateSpans<'_>, InvalidDer> {
+        CertificateSpans::new(self.as_ref())
+    }
+
+    /// The encoded `tbsCertificate`, the part of the certificate covered by its signature
+    ///
+    /// See [`CertificateSpans::tbs_certificate()`].
+    pub fn tbs_certificate(&self) -> Result<&[u8], InvalidDer> {
+        Ok(self.spans()?.tbs_certificate())
+    }
+
+    /// The `AlgorithmIdentifier` of this certificate's signature
+    ///
+    /// See [`CertificateSpans::signature_algorithm()`].
+    pub fn signature_algorithm(&self) -> Result<&[u8], InvalidDer> {
+        Ok(self.spans()?.signature_algorithm())
+    }
+
+    /// The signature over this certificate, without the unused bits octet
+    ///
+    /// See [`CertificateSpans::signature_value()`].
+    pub fn signature_value(&self) -> Result<&[u8], InvalidDer> {
+        self.spans()?.signature_value()
+    }
+
+    /// The period during which this certificate is valid
+    ///
+    /// See [`CertificateSpans::validity()`].
+    pub fn validity(&self) -> Result<ValidityPeriod, InvalidDer> {
+        self.spans()?.validity()
+    }
+
+    /// Whether this certificate's subject and issuer are the same name
+    ///
+    /// See [`CertificateSpans::is_self_issued()`].
+    pub fn is_self_issued(&self) -> Result<bool, InvalidDer> {
+        Ok(self.spans()?.is_self_issued())
+    }
+}
+
+/// The locations of the fields of a DER-encoded X.509 certificate
+///
+/// Obtained from [`CertificateDer::spans()`], which decodes the structure of the certificate
+/// a single time.
+#[derive(Clone, Debug)]
+pub struct CertificateSpans<'a> {
+    tbs_certificate: &'a [u8],
+    signature_algorithm: &'a [u8],
+    /// The contents of the `signatureValue` `BIT STRING`, including the unused bits octet
+    signature_value: &'a [u8],
+    serial: SerialNumber<'a>,
+    issuer: &'a [u8],
+    validity: &'a [u8],
+    subject: &'a [u8],
+    pub(crate) spki: &'a [u8],
+    /// The contents of the `Extensions` `SEQUENCE`, empty if there are none
+    extensions: &'a [u8],
+}
+
+impl<'a> CertificateSpans<'a> {
+    fn new(cert: &'a [u8]) -> Result<Self, InvalidDer> {
+        let mut outer = Reader::new(der::expect_single(cert, der::SEQUENCE)?);
+        let tbs_certificate = outer.read_element(der::SEQUENCE)?;
+        let signature_algorithm = outer.read(der::SEQUENCE)?;
+        let signature_value = outer.read(der::BIT_STRING)?;
+        outer.finish()?;
+
+        let mut tbs = Reader::new(tbs_certificate.contents);
+        let _version = tbs.read_optional(der::context_constructed(0))?;
+        let serial = SerialNumber(tbs.read(der::INTEGER)?);
+        let _signature = tbs.read(der::SEQUENCE)?;
+        let issuer = tbs.read(der::SEQUENCE)?;
+        let validity = tbs.read(der::SEQUENCE)?;
+        let subject = tbs.read(der::SEQUENCE)?;
+        let spki = tbs.read_element(der::SEQUENCE)?.encoded;
+        let _issuer_unique_id = tbs.read_optional(der::context_primitive(1))?;
+        let _subject_unique_id = tbs.read_optional(der::context_primitive(2))?;
+        let extensions = match tbs.read_optional(der::context_constructed(3))? {
+            Some(extensions) => der::expect_single(extensions, der::SEQUENCE)?,
+            None => &[],
+        };
+        tbs.finish()?;
+
+        Ok(Self {
+            tbs_certificate: tbs_certificate.encoded,
+            signature_algorithm,
+            signature_value,
+            serial,
+            issuer,
+            validity,
+            subject,
+            spki,
+            extensions,
+        })
+    }
+
+    /// The encoded `tbsCertificate`, including its `SEQUENCE` tag and length
+    ///
+    /// These are the exact octets covered by the certificate's signature, as passed to
+    /// [`SignatureVerificationAlgorithm::verify_signature()`][verify].
+    ///
+    /// [verify]: crate::SignatureVerificationAlgorithm::verify_signature
+    pub fn tbs_certificate(&self) -> &'a [u8] {
+        self.tbs_certificate
+    }
+
+    /// The `AlgorithmIdentifier` of the certificate's signature, without the outer `SEQUENCE`
+    /// tag and length
+    ///
+    /// This is the `signatureAlgorithm` field following the `tbsCertificate`. It has the same
+    /// form as [`AlgorithmIdentifier`](crate::AlgorithmIdentifier), so it can be compared to the
+    /// constants in [`alg_id`](crate::alg_id), or to a
+    /// [`SignatureVerificationAlgorithm::signature_alg_id()`][alg].
+    ///
+    /// [alg]: crate::SignatureVerificationAlgorithm::signature_alg_id
+    pub fn signature_algorithm(&self) -> &'a [u8] {
+        self.signature_algorithm
+    }
+
+    /// The contents of the `signatureValue` `BIT STRING`, without the unused bits octet
+    ///
+    /// Every signature algorithm in use produces a whole number of octets, so a signature with
+    /// unused bits is rejected.
+    ///
+    /// Together with [`tbs_certificate()`](Self::tbs_certificate) and
+    /// [`signature_algorithm()`](Self::signature_algorithm), this is what is needed to check
+    /// the signature with a [`SignatureVerificationAlgorithm`](crate::SignatureVerificationAlgorithm):
+    ///
+    /// ```
+    /// use rustls_pki_types::{
+    ///     AlgorithmIdentifier, CertificateDer, InvalidSignature, SignatureVerificationAlgorithm,
+    /// };
+    ///
+    /// // A stand-in for a real Ed25519 implementation.
+    /// struct MockEd25519;
+    ///
+    /// impl SignatureVerificationAlgorithm for MockEd25519 {
+    ///     fn verify_signature(
+    ///         &self,
+    ///         public_key: &[u8],
+    ///         message: &[u8],
+    ///         signature: &[u8],
+    ///     ) -> Result<(), InvalidSignature> {
+    ///         match (public_key, message.first(), signature) {
+    ///             (b"pk", Some(0x30), b"sig") => Ok(()),
+    ///             _ => Err(InvalidSignature),
+    ///         }
+    ///     }
+    ///
+    ///     fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+    ///         AlgorithmIdentifier::from_slice(&[0x06, 0x03, 0x2b, 0x65, 0x70])
+    ///     }
+    ///
+    ///     fn signature_alg_id(&self) -> AlgorithmIdentifier {
+    ///         AlgorithmIdentifier::from_slice(&[0x06, 0x03, 0x2b, 0x65, 0x70])
+    ///     }
+    /// }
+    ///
+    /// let ed25519: &[u8] = &[0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70];
+    /// let der = [
+    ///     &[0x30, 0x4b, 0x30, 0x3c][..],
+    ///     &[0x02, 0x01, 0x01], // serialNumber
+    ///     ed25519,             // signature
+    ///     &[0x30, 0x00],       // issuer
+    ///     &[0x30, 0x1e, 0x17, 0x0d],
+    ///     b"250101000000Z", // notBefore
+    ///     &[0x17, 0x0d],
+    ///     b"350101000000Z", // notAfter
+    ///     &[0x30, 0x00],    // subject
+    ///     &[0x30, 0x0c],    // subjectPublicKeyInfo
+    ///     ed25519,
+    ///     &[0x03, 0x03, 0x00],
+    ///     b"pk",
+    ///     ed25519, // signatureAlgorithm
+    ///     &[0x03, 0x04, 0x00],
+    ///     b"sig", // signatureValue
+    /// ]
+    /// .concat();
+    ///
+    /// let cert = CertificateDer::from(der);
+    /// let spans = cert.spans().unwrap();
+    /// let spki = spans.subject_public_key_info();
+    ///
+    /// let verifier = MockEd25519;
+    /// assert_eq!(spans.signature_algorithm(), &*verifier.signature_alg_id());
+    /// assert_eq!(spki.algorithm().unwrap(), &*verifier.public_key_alg_id());
+    /// verifier
+    ///     .verify_signature(
+    ///         spki.subject_public_key().unwrap(),
+    ///         spans.tbs_certificate(),
+    ///         spans.signature_value().unwrap(),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn signature_value(&self) -> Result<&'a [u8], InvalidDer> {
+        match self.signature_value.split_first() {
+            Some((0, signature)) => Ok(signature),
+            _ => Err(InvalidDer::InvalidValue),
+        }
+    }
+
+    /// The certificate's serial number
+    pub fn serial(&self) -> SerialNumber<'a> {
+        self.serial
+    }
+
+    /// The issuer's `Name`, without the outer `SEQUENCE` tag and length
+    ///
+    /// This has the same form as [`TrustAnchor::subject`](crate::TrustAnchor::subject).
+    pub fn issuer(&self) -> &'a [u8] {
+        self.issuer
+    }
+
+    /// The subject's `Name`, without the outer `SEQUENCE` tag and length
+    ///
+    /// This has the same form as [`TrustAnchor::subject`](crate::TrustAnchor::subject).
+    pub fn subject(&self) -> &'a [u8] {
+        self.subject
+    }
+
+    /// The subject's public key
+    pub fn subject_public_key_info(&self) -> SubjectPublicKeyInfoDer<'a> {
+        SubjectPublicKeyInfoDer::from(self.spki)
+    }
+
+    /// The extension with the given `oid`, if present
+    ///
+    /// This yields the first matching extension; RFC 5280 does not allow more than one.
+    pub(crate) fn extension(&self, oid: &[u8]) -> Result<Option<Extension<'a>>, InvalidDer> {
+        let mut extensions = Reader::new(self.extensions);
+        while extensions.peek_tag().is_some() {
+            let mut extension = Reader::new(extensions.read(der::SEQUENCE)?);
+            let id = extension.read(der::OBJECT_IDENTIFIER)?;
+            let critical = match extension.read_optional(der::BOOLEAN)? {
+                None | Some([0x00]) => false,
+                Some([0xff]) => true,
+                Some(_) => return Err(InvalidDer::InvalidValue),
+            };
+            let value = extension.read(der::OCTET_STRING)?;
+            extension.finish()?;
+
+            if id == oid {
+                return Ok(Some(Extension { critical, value }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The validity period, from `notBefore` through `notAfter`
+    ///
+    /// A period whose `notAfter` is earlier than its `notBefore` is rejected as invalid.
+    pub fn validity(&self) -> Result<ValidityPeriod, InvalidDer> {
+        let mut validity = Reader::new(self.validity);
+        let not_before = validity.read_time()?;
+        let not_after = validity.read_time()?;
+        validity.finish()?;
+        ValidityPeriod::new(not_before, not_after).map_err(|_| InvalidDer::InvalidValue)
+    }
+
+    /// Whether the subject and issuer are the same name
+    ///
+    /// This is a byte-for-byte comparison of the encoded names, and is a purely structural
+    /// check: it does not say anything about whether the certificate is self-signed, as the
+    /// signature is not verified. Self-issued certificates include root certificates as well
+    /// as certificates issued during key rollover.
+    pub fn is_self_issued(&self) -> bool {
+        self.issuer == self.subject
+    }
+
+    /// The parts of this certificate needed to use it as a [`TrustAnchor`]
+    ///
+    /// Like the other fields of the anchor, the name constraints are taken without their
+    /// outer `SEQUENCE` tag and length.
+    #[cfg(feature = "mozilla-certdata")]
+    pub(crate) fn trust_anchor(&self) -> Result<TrustAnchor<'a>, InvalidDer> {
+        let name_constraints = match self.extension(NAME_CONSTRAINTS)? {
+            Some(extension) => Some(der::expect_single(extension.value, der::SEQUENCE)?),
+            None => None,
+        };
+
+        Ok(TrustAnchor {
+            subject: self.subject.into(),
+            subject_public_key_info: der::expect_single(self.spki, der::SEQUENCE)?.into(),
+            name_constraints: name_constraints.map(Into::into),
+        })
+    }
+}
+
+/// `id-ce-nameConstraints`, 2.5.29.30
+#[cfg(feature = "mozilla-certdata")]
+const NAME_CONSTRAINTS: &[u8] = &[0x55, 0x1d, 0x1e];
+
+/// A certificate extension
+pub(crate) struct Extension<'a> {
+    pub(crate) critical: bool,
+    /// The contents of the `extnValue` `OCTET STRING`
+    pub(crate) value: &'a [u8],
+}
+
+impl CertificateSigningRequestDer<'_> {
+    /// The public key which the certificate is requested for
+    ///
+    /// The signature over the request, which proves possession of the private key, is not
+    /// verified.
+    pub fn subject_public_key_info(&self) -> Result<SubjectPublicKeyInfoDer<'_>, InvalidDer> {
+        let mut outer = Reader::new(der::expect_single(self.as_ref(), der::SEQUENCE)?);
+        let mut info = Reader::new(outer.read(der::SEQUENCE)?);
+        let _signature_algorithm = outer.read(der::SEQUENCE)?;
+        let _signature = outer.read(der::BIT_STRING)?;
+        outer.finish()?;
+
+        let _version = info.read(der::INTEGER)?;
+        let _subject = info.read(der::SEQUENCE)?;
+        let spki = info.read_element(der::SEQUENCE)?.encoded;
+        Ok(SubjectPublicKeyInfoDer::from(spki))
+    }
+}
+
+impl SubjectPublicKeyInfoDer<'_> {
+    /// The key's `AlgorithmIdentifier`, without the outer `SEQUENCE` tag and length
+    ///
+    /// This has the same form as [`AlgorithmIdentifier`](crate::AlgorithmIdentifier), so it
+    /// can be compared to the constants in [`alg_id`](crate::alg_id), or to a
+    /// [`SignatureVerificationAlgorithm::public_key_alg_id()`][alg].
+    ///
+    /// [alg]: crate::SignatureVerificationAlgorithm::public_key_alg_id
+    pub fn algorithm(&self) -> Result<&[u8], InvalidDer> {
+        Ok(self.split()?.0)
+    }
+
+    /// The contents of the `subjectPublicKey` `BIT STRING`, without the unused bits octet
+    ///
+    /// Every public key type in use encodes its key as a whole number of octets, so a key
+    /// with unused bits is rejected.
+    pub fn subject_public_key(&self) -> Result<&[u8], InvalidDer> {
+        Ok(self.split()?.1)
+    }
+
+    fn split(&self) -> Result<(&[u8], &[u8]), InvalidDer> {
+        let mut spki = Reader::new(der::expect_single(self.as_ref(), der::SEQUENCE)?);
+        let algorithm = spki.read(der::SEQUENCE)?;
+        let key = spki.read(der::BIT_STRING)?;
+        spki.finish()?;
+
+        match key.split_first() {
+            Some((0, key)) => Ok((algorithm, key)),
+            _ => Err(InvalidDer::InvalidValue),
+        }
+    }
+}
+
+impl CertificateRevocationListDer<'_> {
+    /// Locate the top-level fields of this CRL
+    ///
+    /// This decodes the CRL's structure once, without walking the list of revoked certificates.
+    pub fn spans(&self) -> Result<CrlSpans<'_>, InvalidDer> {
+        CrlSpans::new(self.as_ref())
+    }
+
+    /// Iterate over the serial numbers and revocation dates of the revoked certificates
+    ///
+    /// See [`CrlSpans::revoked_serials()`].
+    pub fn revoked_serials(&self) -> Result<RevokedSerials<'_>, InvalidDer> {
+        Ok(self.spans()?.revoked_serials())
+    }
+
+    /// Whether `serial` is listed as revoked in this CRL
+    ///
+    /// See [`CrlSpans::contains_serial()`].
+    pub fn contains_serial(&self, serial: &SerialNumber<'_>) -> Result<bool, InvalidDer> {
+        self.spans()?.contains_serial(serial)
+    }
+}
+
+/// The locations of the fields of a DER-encoded certificate revocation list
+///
+/// Obtained from [`CertificateRevocationListDer::spans()`].
+#[derive(Clone, Debug)]
+pub struct CrlSpans<'a> {
+    issuer: &'a [u8],
+    this_update: UnixTime,
+    next_update: Option<UnixTime>,
+    revoked: &'a [u8],
+}
+
+impl<'a> CrlSpans<'a> {
+    fn new(crl: &'a [u8]) -> Result<Self, InvalidDer> {
+        let mut outer = Reader::new(der::expect_single(crl, der::SEQUENCE)?);
+        let mut tbs = Reader::new(outer.read(der::SEQUENCE)?);
+        let _signature_algorithm = outer.read(der::SEQUENCE)?;
+        let _signature_value = outer.read(der::BIT_STRING)?;
+        outer.finish()?;
+
+        let _version = tbs.read_optional(der::INTEGER)?;
+        let _signature = tbs.read(der::SEQUENCE)?;
+        let issuer = tbs.read(der::SEQUENCE)?;
+        let this_update = tbs.read_time()?;
+        let next_update = match tbs.peek_tag() {
+            Some(der::UTC_TIME | der::GENERALIZED_TIME) => Some(tbs.read_time()?),
+            _ => None,
+        };
+        // The revoked certificates are skipped over without being walked, so this stays
+        // cheap for large CRLs.
+        let revoked = tbs.read_optional(der::SEQUENCE)?.unwrap_or_default();
+        let _extensions = tbs.read_optional(der::context_constructed(0))?;
+        tbs.finish()?;
+
+        Ok(Self {
+            issuer,
+            this_update,
+            next_update,
+            revoked,
+        })
+    }
+
+    /// The issuer's `Name`, without the outer `SEQUENCE` tag and length
+    pub fn issuer(&self) -> &'a [u8] {
+        self.issuer
+    }
+
+    /// The time at which this CRL was issued
+    pub fn this_update(&self) -> UnixTime {
+        self.this_update
+    }
+
+    /// The time by which the next CRL will be issued, if specified
+    pub fn next_update(&self) -> Option<UnixTime> {
+        self.next_update
+    }
+
+    /// Iterate over the serial numbers and revocation dates of the revoked certificates
+    ///
+    /// The list is decoded lazily as the iterator advances, so memory use does not depend on
+    /// the size of the CRL. Entry extensions (such as the revocation reason) are skipped. A CRL
+    /// without any revoked certificates yields an empty iterator.
+    ///
+    /// After yielding an error, the iterator yields nothing further.
+    pub fn revoked_serials(&self) -> RevokedSerials<'a> {
+        RevokedSerials {
+            reader: Reader::new(self.revoked),
+        }
+    }
+
+    /// Whether `serial` is listed as revoked in this CRL
+    ///
+    /// This stops walking the list as soon as `serial` is found.
+    pub fn contains_serial(&self, serial: &SerialNumber<'_>) -> Result<bool, InvalidDer> {
+        for entry in self.revoked_serials() {
+            if entry?.0 == *serial {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// An iterator over the revoked certificates in a CRL
+///
+/// Yields the serial number and revocation date of each entry. Obtained from
+/// [`CrlSpans::revoked_serials()`].
+#[derive(Clone, Debug)]
+pub struct RevokedSerials<'a> {
+    reader: Reader<'a>,
+}
+
+impl<'a> Iterator for RevokedSerials<'a> {
+    type Item = Result<(SerialNumber<'a>, UnixTime), InvalidDer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.peek_tag()?;
+
+        let entry = (|| {
+            let mut entry = Reader::new(self.reader.read(der::SEQUENCE)?);
+            let serial = SerialNumber(entry.read(der::INTEGER)?);
+            let revocation_date = entry.read_time()?;
+            let _extensions = entry.read_optional(der::SEQUENCE)?;
+            entry.finish()?;
+            Ok((serial, revocation_date))
+        })();
+
+        if entry.is_err() {
+            self.reader = Reader::new(&[]);
+        }
+
+        Some(entry)
+    }
+}
+
+impl<'a> RevocationMaterial<'a> {
+    /// Classify DER-encoded revocation material by its structure
+    ///
+    /// An `OCSPResponse` starts with its `responseStatus`, an `ENUMERATED`, whereas a
+    /// `CertificateList` starts with its `tbsCertList`, a `SEQUENCE`. Other structures start
+    /// with a `SEQUENCE` too, certificates among them, so a CRL must also have the structure
+    /// checked by [`CertificateRevocationListDer::spans()`]. For an OCSP response, only the
+    /// framing needed to tell the two apart is checked.
+    pub fn from_der(der: &'a [u8]) -> Result<Self, InvalidDer> {
+        let mut inner = Reader::new(der::expect_single(der, der::SEQUENCE)?);
+        match inner.read_any()?.tag {
+            der::SEQUENCE => {
+                CrlSpans::new(der)?;
+                Ok(Self::Crl(CertificateRevocationListDer::from(der)))
+            }
+            der::ENUMERATED => Ok(Self::Ocsp(OcspResponseDer::from(der))),
+            found => Err(InvalidDer::UnexpectedTag {
+                expected: der::SEQUENCE,
+                found,
+            }),
+        }
+    }
+}
+
+/// A certificate serial number
+///
+/// This holds the contents of the DER-encoded `INTEGER`: a big-endian, two's complement
+/// integer in its minimal encoding. In particular, serial numbers whose first byte would have
+/// the high bit set start with a zero byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SerialNumber<'a>(&'a [u8]);
+
+impl<'a> SerialNumber<'a> {
+    /// Make a `SerialNumber` from the contents of a DER-encoded `INTEGER`
+    ///
+    /// This does not validate the contents of the slice.
+    pub const fn from_slice(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    /// The contents of the DER-encoded `INTEGER`
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+/// Writes a short summary of a `Name` for humans: its common name, or its first attribute
+///
+/// This holds the contents of the `Name`, without the outer `SEQUENCE` tag and length. An
+/// empty name is written as `(empty)`, and a malformed one as hex.
+pub(crate) struct NameSummary<'a>(pub(crate) &'a [u8]);
+
+impl NameSummary<'_> {
+    fn attributes(&self) -> Result<Option<(&[u8], der::Element<'_>)>, InvalidDer> {
+        let mut rdns = Reader::new(self.0);
+        let mut first = None;
+        while rdns.peek_tag().is_some() {
+            let mut rdn = Reader::new(rdns.read(der::SET)?);
+            while rdn.peek_tag().is_some() {
+                let mut attribute = Reader::new(rdn.read(der::SEQUENCE)?);
+                let oid = attribute.read(der::OBJECT_IDENTIFIER)?;
+                let value = attribute.read_any()?;
+                attribute.finish()?;
+
+                let found = (oid, value);
+                if oid == COMMON_NAME {
+                    return Ok(Some(found));
+                }
+                first = first.or(Some(found));
+            }
+        }
+
+        Ok(first)
+    }
+}
+
+impl fmt::Display for NameSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (oid, value) = match self.attributes() {
+            Ok(Some(attribute)) => attribute,
+            Ok(None) => return f.write_str("(empty)"),
+            Err(_) => return self.0.iter().try_for_each(|b| write!(f, "{b:02x}")),
+        };
+
+        match ATTRIBUTE_NAMES.iter().find(|(known, _)| *known == oid) {
+            Some((_, name)) => f.write_str(name)?,
+            None => der::write_oid(f, oid)?,
+        }
+
+        // Values which are not text are written in the hex form of RFC 4514 section 2.4.
+        match (value.tag, core::str::from_utf8(value.contents)) {
+            (UTF8_STRING | PRINTABLE_STRING | IA5_STRING | T61_STRING, Ok(text)) => {
+                write!(f, "={}", text.escape_debug())
+            }
+            _ => {
+                f.write_str("=#")?;
+                value.encoded.iter().try_for_each(|b| write!(f, "{b:02x}"))
+            }
+        }
+    }
+}
+
+const UTF8_STRING: u8 = 0x0c;
+const PRINTABLE_STRING: u8 = 0x13;
+const T61_STRING: u8 = 0x14;
+const IA5_STRING: u8 = 0x16;
+
+/// `id-at-commonName`, 2.5.4.3
+const COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+
+/// Short names of common attribute types, as used in RFC 4514
+const ATTRIBUTE_NAMES: &[(&[u8], &str)] = &[
+    (COMMON_NAME, "CN"),
+    (&[0x55, 0x04, 0x06], "C"),
+    (&[0x55, 0x04, 0x07], "L"),
+    (&[0x55, 0x04, 0x08], "ST"),
+    (&[0x55, 0x04, 0x0a], "O"),
+    (&[0x55, 0x04, 0x0b], "OU"),
+];
@@ -0,0 +1,419 @@
+#!/bin/sh
+# Regenerate the fixtures in this directory with OpenSSL 3, ssh-keygen for `pem/openssh.pem`
+# and Node.js for `jwk/`.
+#
+# Keys, serial numbers and signatures are random, so the output differs on every run. Tests
+# compare fixtures with each other, or with the `.txt` files written here from OpenSSL's own
+# view of them, never with bytes produced by this crate. `ed25519.pem` and `ed25519.der` are
+# also spelled out in doc examples, which need updating if they are regenerated.
+set -e
+cd "$(dirname "$0")"
+
+# A self-signed Ed25519 certificate and its PKCS#8 v1 key.
+openssl genpkey -algorithm ed25519 -outform DER -out ed25519.pkcs8.der
+openssl req -x509 -keyform DER -key ed25519.pkcs8.der -subj /CN=ed -days 30 -out ed25519.pem
+openssl x509 -in ed25519.pem -outform DER -out ed25519.der
+
+# An RSA key as PKCS#1 and as PKCS#8, and P-256 and P-384 keys as SEC1 (naming their curves)
+# and as PKCS#8.
+openssl genrsa -traditional -out rsa2048.pem 2048
+openssl rsa -in rsa2048.pem -traditional -outform DER -out rsa2048.pkcs1.der
+openssl pkcs8 -topk8 -nocrypt -in rsa2048.pem -outform DER -out rsa2048.pkcs8.der
+rm rsa2048.pem
+openssl ecparam -name prime256v1 -genkey -noout -outform DER -out p256.sec1.der
+openssl pkcs8 -topk8 -nocrypt -inform DER -in p256.sec1.der -outform DER -out p256.pkcs8.der
+openssl ecparam -name secp384r1 -genkey -noout -outform DER -out p384.sec1.der
+openssl pkcs8 -topk8 -nocrypt -inform DER -in p384.sec1.der -outform DER -out p384.pkcs8.der
+
+# The Ed25519 certificate with OpenSSL trust settings, as a `TRUSTED CERTIFICATE` section.
+openssl x509 -in ed25519.pem -trustout -addtrust serverAuth -out ed25519.trusted.pem
+
+# A small PKI. `root` (RSA) is a CA without a path length constraint, `int` (P-256) is issued
+# by it with pathLen 0 and name constraints, and `int-cross` is the same intermediate issued
+# by `root2`. `leaf` (Ed25519) has SANs, key usage and extended key usage but no basic
+# constraints, `leaf-any-eku` has only anyExtendedKeyUsage, and `rsaleaf` is a v1 certificate
+# without extensions. `chain.pem` holds leaf, int and root, in that order.
+openssl req -x509 -newkey rsa:2048 -nodes -keyout root.key -days 36500 \
+  -subj "/C=US/O=Test Org/CN=Test Root CA" \
+  -addext "basicConstraints=critical,CA:TRUE" -addext "keyUsage=critical,keyCertSign,cRLSign" \
+  -out root.pem
+openssl req -x509 -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes -keyout root2.key \
+  -days 36500 -subj "/CN=Other Root" -addext "basicConstraints=critical,CA:TRUE" -out root2.pem
+openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256 -out int.key
+openssl req -new -key int.key -subj "/O=Test Org/CN=Test Intermediate" -out int.csr
+cat > int.ext <<END
+basicConstraints=critical,CA:TRUE,pathlen:0
+keyUsage=critical,keyCertSign,cRLSign
+nameConstraints=critical,permitted;DNS:example.com
+END
+openssl x509 -req -in int.csr -CA root.pem -CAkey root.key -set_serial 2 -days 36500 \
+  -extfile int.ext -out int.pem
+openssl x509 -req -in int.csr -CA root2.pem -CAkey root2.key -set_serial 3 -days 36500 \
+  -extfile int.ext -out int-cross.pem
+openssl genpkey -algorithm ed25519 -out leaf.key
+openssl req -new -key leaf.key -subj "/CN=www.example.com" -out leaf.csr
+cat > leaf.ext <<END
+subjectAltName=DNS:www.example.com,DNS:*.example.com,IP:192.0.2.1,IP:2001:db8::1,URI:https://example.com/id
+extendedKeyUsage=serverAuth,clientAuth
+keyUsage=critical,digitalSignature
+END
+openssl x509 -req -in leaf.csr -CA int.pem -CAkey int.key -set_serial 4 -days 36500 \
+  -extfile leaf.ext -out leaf.pem
+echo "extendedKeyUsage=anyExtendedKeyUsage" > any.ext
+openssl x509 -req -in leaf.csr -CA int.pem -CAkey int.key -set_serial 5 -days 36500 \
+  -extfile any.ext -out leaf-any-eku.pem
+openssl req -new -newkey rsa:2048 -nodes -keyout rsaleaf.key -subj "/CN=rsa.example.com" \
+  -out rsaleaf.csr
+openssl x509 -req -in rsaleaf.csr -CA root.pem -CAkey root.key -set_serial 6 -days 36500 \
+  -out rsaleaf.pem
+for cert in root root2 int int-cross leaf leaf-any-eku rsaleaf; do
+  openssl x509 -in $cert.pem -outform DER -out $cert.der
+done
+cat leaf.pem int.pem root.pem > chain.pem
+rm root.key root2.key int.key leaf.key rsaleaf.key *.csr *.ext
+rm root.pem root2.pem int.pem int-cross.pem leaf.pem leaf-any-eku.pem rsaleaf.pem
+
+# A CRL revoking 3000 certificates, with a reason code on every third entry, and one revoking
+# nothing, which leaves out the revokedCertificates field. `large.crl.txt` lists the serial
+# numbers of the revoked certificates as OpenSSL decodes them.
+mkdir crl-ca
+openssl req -x509 -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes -keyout crl-ca/key.pem \
+  -days 36500 -subj "/CN=CRL Test CA" -out crl-ca/cert.pem
+cat > crl-ca/ca.cnf <<END
+[ca]
+default_ca = test
+[test]
+database = crl-ca/index.txt
+certificate = crl-ca/cert.pem
+private_key = crl-ca/key.pem
+crlnumber = crl-ca/crlnumber
+default_md = sha256
+default_crl_days = 36500
+END
+echo 01 > crl-ca/crlnumber
+touch crl-ca/index.txt
+openssl ca -config crl-ca/ca.cnf -gencrl -out crl-ca/empty.pem
+openssl crl -in crl-ca/empty.pem -outform DER -out empty.crl.der
+for i in $(seq 1 3000); do
+  case $((i % 3)) in
+    0) reason=",keyCompromise" ;;
+    *) reason="" ;;
+  esac
+  # Every fifth serial number has its high bit set, so it is encoded with a leading zero.
+  case $((i % 5)) in
+    0) serial=$((0x80000000 + i)) ;;
+    *) serial=$((i * 7919 + 65536)) ;;
+  esac
+  printf 'R\t491231235959Z\t240101000000Z%s\t%08X\tunknown\t/CN=revoked %d\n' "$reason" \
+    "$serial" "$i"
+done > crl-ca/index.txt
+openssl ca -config crl-ca/ca.cnf -gencrl -out crl-ca/large.pem
+openssl crl -in crl-ca/large.pem -outform DER -out large.crl.der
+openssl crl -inform DER -in large.crl.der -noout -text \
+  | sed -n 's/^ *Serial Number: *//p' > large.crl.txt
+rm -r crl-ca
+
+# Gzip-compressed CRLs: the empty CRL, and a "bomb" of 64 MiB of zeros in 64 KiB.
+gzip -9 -n -c empty.crl.der > empty.crl.der.gz
+head -c 67108864 /dev/zero | gzip -9 -n -c > bomb.gz
+
+# Self-signed certificates for the RSA and P-256 keys above.
+openssl req -x509 -keyform DER -key rsa2048.pkcs8.der -subj /CN=rsa -days 36500 -outform DER \
+  -out rsa2048.der
+openssl req -x509 -keyform DER -key p256.pkcs8.der -subj /CN=p256 -days 36500 -outform DER \
+  -out p256.der
+
+# Public keys and certificates whose algorithm identifiers are compared with `alg_id`.
+mkdir -p alg-id
+for alg in ed448 x448; do
+  openssl genpkey -algorithm $alg | openssl pkey -pubout -outform DER -out alg-id/$alg.spki.der
+done
+for curve in brainpoolP256r1 brainpoolP384r1 brainpoolP512r1; do
+  openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:$curve \
+    | openssl pkey -pubout -outform DER -out alg-id/$curve.spki.der
+done
+openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256 -out alg-id/p256.key
+for hash in sha256 sha384 sha512; do
+  openssl req -x509 -key alg-id/p256.key -$hash -subj /CN=$hash -days 36500 -outform DER \
+    -out alg-id/ecdsa-$hash.der
+done
+rm alg-id/p256.key
+
+# `sct/amazon.der` is not generated: it is a real certificate with three embedded SCTs, taken
+# from the tests of the x509-cert crate (Apache-2.0 OR MIT).
+
+# A certificate asserting all nine key usages, a critical extended key usage with a private
+# purpose, and basic constraints with cA left out.
+openssl genpkey -algorithm ed25519 -out usages.key
+openssl req -x509 -key usages.key -subj /CN=usages -days 36500 \
+  -addext "keyUsage=critical,digitalSignature,nonRepudiation,keyEncipherment,dataEncipherment,keyAgreement,keyCertSign,cRLSign,encipherOnly,decipherOnly" \
+  -addext "extendedKeyUsage=critical,codeSigning,OCSPSigning,1.3.6.1.4.1.311.10.3.4" \
+  -addext "basicConstraints=CA:FALSE" -outform DER -out usages.der
+rm usages.key
+
+# CA certificates with path length constraints that take two octets, and more than one.
+openssl genpkey -algorithm ed25519 -out pathlen.key
+for n in 200 300; do
+  openssl req -x509 -key pathlen.key -subj /CN=pathlen$n -days 36500 \
+    -addext "basicConstraints=critical,CA:TRUE,pathlen:$n" -outform DER -out pathlen$n.der
+done
+rm pathlen.key
+
+# An excerpt of Mozilla's `certdata.txt`, in its exact layout, with three certificates: ISRG
+# Root X1, which is trusted; Entrust Root Certification Authority - G2, which is trusted with
+# the server distrust-after date Mozilla gave the Entrust roots; and `root.der`, marked as
+# distrusted. Only the first two, and their trust, are as in Mozilla's file, which is why the
+# third is not a real root. A trust object without a certificate, as Mozilla writes to
+# distrust certificates it does not ship, follows for `int-cross.der`. The two real roots are
+# read from the system store of Debian and Ubuntu.
+octal() {
+  od -An -v -to1 | tr -s ' \n' '\n\n' | sed '/^$/d' \
+    | awk '{ printf "\\%s", $1 } NR % 16 == 0 { print "" } END { if (NR % 16) print "" }'
+}
+# The octets of the `n`th element of the `TBSCertificate` of the certificate in `$1`.
+tbs_field() {
+  openssl asn1parse -inform DER -in "$1" \
+    | sed -n 's/^ *\([0-9]*\):d=2 *hl= *\([0-9]*\) *l= *\([0-9]*\).*/\1 \2 \3/p' \
+    | sed -n "$2p" | { read -r at hl len; tail -c +$((at + 1)) "$1" | head -c $((hl + len)); }
+}
+# Write the attributes naming the certificate in `$1` by its issuer and serial number.
+issuer_serial() {
+  echo "CKA_ISSUER MULTILINE_OCTAL"
+  tbs_field "$1" 4 | octal
+  echo "END"
+  echo "CKA_SERIAL_NUMBER MULTILINE_OCTAL"
+  tbs_field "$1" 2 | octal
+  echo "END"
+}
+# Write the certificate object and trust object for the certificate in `$2`, labelled `$1`,
+# with server trust `$3` and a server distrust-after time `$4`, if any.
+certdata_entry() {
+  echo "#"
+  echo "# Certificate \"$1\""
+  echo "#"
+  echo "CKA_CLASS CK_OBJECT_CLASS CKO_CERTIFICATE"
+  echo "CKA_TOKEN CK_BBOOL CK_TRUE"
+  echo "CKA_PRIVATE CK_BBOOL CK_FALSE"
+  echo "CKA_MODIFIABLE CK_BBOOL CK_FALSE"
+  echo "CKA_LABEL UTF8 \"$1\""
+  echo "CKA_CERTIFICATE_TYPE CK_CERTIFICATE_TYPE CKC_X_509"
+  echo "CKA_SUBJECT MULTILINE_OCTAL"
+  tbs_field "$2" 6 | octal
+  echo "END"
+  echo "CKA_ID UTF8 \"0\""
+  issuer_serial "$2"
+  echo "CKA_VALUE MULTILINE_OCTAL"
+  octal < "$2"
+  echo "END"
+  echo "CKA_NSS_MOZILLA_CA_POLICY CK_BBOOL CK_TRUE"
+  if [ -n "$4" ]; then
+    echo "CKA_NSS_SERVER_DISTRUST_AFTER MULTILINE_OCTAL"
+    printf '%s' "$4" | octal
+    echo "END"
+    echo "CKA_NSS_EMAIL_DISTRUST_AFTER MULTILINE_OCTAL"
+    printf '%s' "$4" | octal
+    echo "END"
+  else
+    echo "CKA_NSS_SERVER_DISTRUST_AFTER CK_BBOOL CK_FALSE"
+    echo "CKA_NSS_EMAIL_DISTRUST_AFTER CK_BBOOL CK_FALSE"
+  fi
+  echo
+  echo "# Trust for \"$1\""
+  echo "CKA_CLASS CK_OBJECT_CLASS CKO_NSS_TRUST"
+  echo "CKA_TOKEN CK_BBOOL CK_TRUE"
+  echo "CKA_PRIVATE CK_BBOOL CK_FALSE"
+  echo "CKA_MODIFIABLE CK_BBOOL CK_FALSE"
+  echo "CKA_LABEL UTF8 \"$1\""
+  echo "CKA_CERT_SHA1_HASH MULTILINE_OCTAL"
+  openssl dgst -sha1 -binary "$2" | octal
+  echo "END"
+  echo "CKA_CERT_MD5_HASH MULTILINE_OCTAL"
+  openssl dgst -md5 -binary "$2" | octal
+  echo "END"
+  issuer_serial "$2"
+  echo "CKA_TRUST_SERVER_AUTH CK_TRUST $3"
+  echo "CKA_TRUST_EMAIL_PROTECTION CK_TRUST $3"
+  echo "CKA_TRUST_CODE_SIGNING CK_TRUST CKT_NSS_MUST_VERIFY_TRUST"
+  echo "CKA_TRUST_STEP_UP_APPROVED CK_BBOOL CK_FALSE"
+  echo
+}
+openssl x509 -in /etc/ssl/certs/ISRG_Root_X1.pem -outform DER -out isrg.der
+openssl x509 -in /etc/ssl/certs/Entrust_Root_Certification_Authority_-_G2.pem -outform DER \
+  -out entrust.der
+{
+  echo "# This Source Code Form is subject to the terms of the Mozilla Public"
+  echo "# License, v. 2.0. If a copy of the MPL was not distributed with this"
+  echo "# file, You can obtain one at http://mozilla.org/MPL/2.0/."
+  echo "#"
+  echo "BEGINDATA"
+  echo "CKA_CLASS CK_OBJECT_CLASS CKO_NSS_BUILTIN_ROOT_LIST"
+  echo "CKA_TOKEN CK_BBOOL CK_TRUE"
+  echo "CKA_PRIVATE CK_BBOOL CK_FALSE"
+  echo "CKA_MODIFIABLE CK_BBOOL CK_FALSE"
+  echo "CKA_LABEL UTF8 \"Mozilla Builtin Roots\""
+  echo
+  certdata_entry "ISRG Root X1" isrg.der CKT_NSS_TRUSTED_DELEGATOR
+  certdata_entry "Entrust Root Certification Authority - G2" entrust.der \
+    CKT_NSS_TRUSTED_DELEGATOR 241130235959Z
+  certdata_entry "Test Root CA" root.der CKT_NSS_NOT_TRUSTED
+  echo "# Distrust \"Test Intermediate\", issued by \"Other Root\""
+  echo "CKA_CLASS CK_OBJECT_CLASS CKO_NSS_TRUST"
+  echo "CKA_TOKEN CK_BBOOL CK_TRUE"
+  echo "CKA_LABEL UTF8 \"Test Intermediate\""
+  issuer_serial int-cross.der
+  echo "CKA_TRUST_SERVER_AUTH CK_TRUST CKT_NSS_NOT_TRUSTED"
+  echo "CKA_TRUST_EMAIL_PROTECTION CK_TRUST CKT_NSS_NOT_TRUSTED"
+  echo "CKA_TRUST_CODE_SIGNING CK_TRUST CKT_NSS_NOT_TRUSTED"
+  echo "CKA_TRUST_STEP_UP_APPROVED CK_BBOOL CK_FALSE"
+} > certdata.txt
+rm isrg.der entrust.der
+
+# PEM files that `pem::write_all` reproduces byte for byte. `bundle.pem` holds `root.der`,
+# `empty.crl.der` and a section with a label this crate does not know, and `keys.pem` the RSA
+# key as PKCS#1, the P-256 key as SEC1 and the RSA key as PKCS#8, each separated by a blank line.
+mkdir -p pem
+{
+  openssl x509 -inform DER -in root.der
+  echo
+  openssl crl -inform DER -in empty.crl.der
+  echo
+  echo "-----BEGIN OPAQUE DATA-----"
+  head -c 100 ed25519.der | openssl base64
+  echo "-----END OPAQUE DATA-----"
+} > pem/bundle.pem
+{
+  openssl rsa -inform DER -in rsa2048.pkcs1.der -traditional
+  echo
+  openssl ec -inform DER -in p256.sec1.der
+  echo
+  openssl pkey -inform DER -in rsa2048.pkcs8.der
+} > pem/keys.pem
+
+# `chain.pem` with a character in the middle of line 20, in the body of its second certificate,
+# replaced by one which is not base64, with LF and with CRLF line endings.
+sed '20s/./!/10' chain.pem > pem/corrupt.pem
+awk '{ printf "%s\r\n", $0 }' pem/corrupt.pem > pem/corrupt-crlf.pem
+
+# PEM files as some tools write them: `chain.pem` with CRLF line endings, `ed25519.pem` without
+# a newline after its `END` line, and `chain.pem` with OpenSSL's text dump before each
+# certificate.
+awk '{ printf "%s\r\n", $0 }' chain.pem > pem/crlf.pem
+printf '%s' "$(cat ed25519.pem)" > pem/no-final-newline.pem
+for cert in leaf int root; do
+  openssl x509 -inform DER -in $cert.der -text
+done > pem/text.pem
+
+# The RSA key encrypted with the passphrase "test", in the legacy format with `Proc-Type` and
+# `DEK-Info` headers.
+openssl rsa -inform DER -in rsa2048.pkcs1.der -traditional -aes128 -passout pass:test \
+  -out pem/rsa-dek-info.pem
+
+# Keys this crate cannot use: an Ed25519 key in OpenSSH's own format, and the Ed25519 key
+# encrypted with the passphrase "test", as PKCS#8.
+ssh-keygen -q -t ed25519 -N '' -C '' -f pem/openssh.pem
+rm pem/openssh.pem.pub
+openssl pkcs8 -topk8 -inform DER -in ed25519.pkcs8.der -v2 aes-256-cbc -passout pass:test \
+  -out pem/encrypted-pkcs8.pem
+
+# Files as rustls-pemfile users have them: the Ed25519 key followed by its certificate, and
+# `chain.pem` with a comment line before each certificate.
+{
+  openssl pkey -inform DER -in ed25519.pkcs8.der
+  cat ed25519.pem
+} > pem/key-then-cert.pem
+{
+  echo "# leaf"
+  openssl x509 -inform DER -in leaf.der
+  echo "# intermediate"
+  openssl x509 -inform DER -in int.der
+  echo "# root"
+  openssl x509 -inform DER -in root.der
+} > pem/comments.pem
+
+# An OCSP response from a throwaway responder, saying the certificate with serial number 1 is
+# good.
+mkdir ocsp-ca
+openssl req -x509 -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes -keyout ocsp-ca/key.pem \
+  -days 36500 -subj "/CN=OCSP Test CA" -out ocsp-ca/cert.pem
+printf 'V\t491231235959Z\t\t01\tunknown\t/CN=good\n' > ocsp-ca/index.txt
+openssl ocsp -index ocsp-ca/index.txt -rsigner ocsp-ca/cert.pem -rkey ocsp-ca/key.pem \
+  -CA ocsp-ca/cert.pem -issuer ocsp-ca/cert.pem -serial 1 -ndays 36500 -respout ocsp.der
+rm -r ocsp-ca
+
+# A PEM bundle of revocation material: `empty.crl.der`, `root.der`, which is skipped when
+# loading revocation material, and `ocsp.der`. There is no standard label for OCSP responses,
+# so OpenSSL does not write them as PEM; the section is written by hand.
+{
+  openssl crl -inform DER -in empty.crl.der
+  openssl x509 -inform DER -in root.der
+  echo "-----BEGIN OCSP RESPONSE-----"
+  openssl base64 -in ocsp.der
+  echo "-----END OCSP RESPONSE-----"
+} > pem/revocation.pem
+
+# The offsets of the top-level fields of a certificate of each algorithm family, as
+# `openssl asn1parse` finds them. After the file name, each of `tbsCertificate`,
+# `signatureAlgorithm` and `signatureValue` is given as its offset, header length and contents
+# length, separated by tabs.
+for cert in rsa2048.der p256.der ed25519.der leaf.der rsaleaf.der; do
+  printf '%s' $cert
+  openssl asn1parse -inform DER -in $cert \
+    | sed -n 's/^ *\([0-9]*\):d=1 *hl= *\([0-9]*\) l= *\([0-9]*\) .*/\t\1 \2 \3/p' | tr -d '\n'
+  echo
+done > spans.txt
+
+# One-line summaries in the format of `display_summary()`, from OpenSSL's view of the RSA,
+# P-256 and Ed25519 certificates above and of `large.crl.der`, one per line after the file
+# name and a tab.
+iso8601() {
+  sed 's/^[a-zA-Z]*=//; s/ /T/'
+}
+for cert in rsa2048.der p256.der ed25519.der; do
+  text=$(openssl x509 -inform DER -in $cert -noout -text)
+  case $(echo "$text" | sed -n 's/^ *Public Key Algorithm: //p') in
+    rsaEncryption) key="$(echo "$text" | sed -n 's/^ *Public-Key: (\([0-9]*\) bit)/\1/p')-bit RSA" ;;
+    id-ecPublicKey) key="ECDSA $(echo "$text" | sed -n 's/^ *NIST CURVE: //p')" ;;
+    ED25519) key=Ed25519 ;;
+  esac
+  subject=$(openssl x509 -inform DER -in $cert -noout -subject -nameopt RFC2253 | sed 's/^subject=//')
+  expiry=$(openssl x509 -inform DER -in $cert -noout -enddate -dateopt iso_8601 | iso8601)
+  printf '%s\t%s, expires %s, %s\n' $cert "$subject" "$expiry" "$key"
+done > summary.txt
+issuer=$(openssl crl -inform DER -in large.crl.der -noout -issuer -nameopt RFC2253 | sed 's/^issuer=//')
+updated=$(openssl crl -inform DER -in large.crl.der -noout -lastupdate -dateopt iso_8601 | iso8601)
+printf '%s\t%s, updated %s, %s revoked\n' large.crl.der "$issuer" "$updated" \
+  $(wc -l < large.crl.txt) >> summary.txt
+
+# Public keys as JSON Web Keys, exported by Node.js, which the `jwk` tests compare with:
+# the RSA, P-256 and Ed25519 keys above, and a new P-384 key. The reverse for the example keys
+# of RFC 7517 appendix A.1 (RSA and P-256) and RFC 8037 appendix A.2 (Ed25519), written as
+# SPKIs by Node.js from the JWKs spelled out in the tests.
+mkdir -p jwk
+to_jwk() {
+  node -e 'const crypto = require("crypto"), fs = require("fs");
+    const key = crypto.createPublicKey({ key: fs.readFileSync(0), format: "der", type: "spki" });
+    process.stdout.write(JSON.stringify(key.export({ format: "jwk" })) + "\n");'
+}
+from_jwk() {
+  node -e 'const crypto = require("crypto");
+    const key = crypto.createPublicKey({ key: JSON.parse(process.argv[1]), format: "jwk" });
+    process.stdout.write(key.export({ format: "der", type: "spki" }));' "$1"
+}
+for key in rsa2048 p256 ed25519; do
+  openssl x509 -inform DER -in $key.der -pubkey -noout | openssl pkey -pubin -outform DER \
+    | to_jwk > jwk/$key.json
+done
+openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-384 | openssl pkey -pubout -outform DER \
+  -out jwk/p384.spki.der
+to_jwk < jwk/p384.spki.der > jwk/p384.json
+from_jwk '{"kty":"RSA","n":"0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw","e":"AQAB"}' \
+  > jwk/rfc7517-rsa.spki.der
+from_jwk '{"kty":"EC","crv":"P-256","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}' \
+  > jwk/rfc7517-p256.spki.der
+from_jwk '{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}' \
+  > jwk/rfc8037-ed25519.spki.der
+
+# `letsencrypt/` is not generated. It holds a chain as Let's Encrypt served it in 2019: the
+# end-entity certificate and "Let's Encrypt Authority X3" come from the tests of the
+# x509-parser crate (MIT OR Apache-2.0), and "DST Root CA X3", which cross-signed it, from
+# Mozilla's root store as shipped by certifi (MPL-2.0).
@@ -0,0 +1,16 @@
+pub static ROOTS: &[rustls_pki_types::TrustAnchor<'static>] = &[
+    rustls_pki_types::TrustAnchor::from_parts(
+        b"\x31\x0b\x30\x09\x06\x03\x55\x04\x03\x0c\x02\x65\x64",
+        b"\x30\x05\x06\x03\x2b\x65\x70\x03\x21\x00\x19\xbf\x44\x09\x69\x84\xcd\xfe\x85\x41\
+          \xba\xc1\x67\xdc\x3b\x96\xc8\x50\x86\xaa\x30\xb6\xb6\xcb\x0c\x5c\x38\xad\x70\x31\
+          \x66\xe1",
+        None,
+    ),
+    rustls_pki_types::TrustAnchor::from_parts(
+        b"\x31\x0b\x30\x09\x06\x03\x55\x04\x03\x0c\x02\x6e\x63",
+        b"\x30\x05\x06\x03\x2b\x65\x70\x03\x21\x00\x19\xbf\x44\x09\x69\x84\xcd\xfe\x85\x41\
+          \xba\xc1\x67\xdc\x3b\x96\xc8\x50\x86\xaa\x30\xb6\xb6\xcb\x0c\x5c\x38\xad\x70\x31\
+          \x66\xe1",
+        Some(b"\xa0\x0f\x30\x0d\x82\x0b\x65\x78\x61\x6d\x70\x6c\x65\x2e\x63\x6f\x6d"),
+    ),
+];
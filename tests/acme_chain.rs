@@ -0,0 +1,148 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+
+use rustls_pki_types::{
+    CertificateChainDer, CertificateDer, ChainError, ChainNormalization, InvalidDer,
+    SubjectPublicKeyInfoDer,
+};
+
+/// The chain Let's Encrypt serves is already normalized
+#[test]
+fn lets_encrypt_as_served() {
+    let (leaf, x3) = (cert("letsencrypt/leaf.der"), cert("letsencrypt/x3.der"));
+    let (chain, normalization) =
+        CertificateChainDer::normalize_acme(vec![leaf.clone(), x3.clone()], &spki(&leaf)).unwrap();
+
+    assert_eq!(&chain[..], [leaf, x3]);
+    assert_eq!(normalization.order, [0, 1]);
+    assert_eq!(normalization.removed_root, None);
+    assert!(normalization.is_unchanged());
+}
+
+/// A root appended after the intermediate is left out
+#[test]
+fn lets_encrypt_with_root() {
+    let (leaf, x3) = (cert("letsencrypt/leaf.der"), cert("letsencrypt/x3.der"));
+    let root = cert("letsencrypt/dst-root-x3.der");
+    let (chain, normalization) =
+        CertificateChainDer::normalize_acme(vec![leaf.clone(), x3.clone(), root], &spki(&leaf))
+            .unwrap();
+
+    assert_eq!(&chain[..], [leaf, x3]);
+    assert_eq!(normalization.order, [0, 1]);
+    assert_eq!(normalization.removed_root, Some(2));
+    assert!(!normalization.is_unchanged());
+}
+
+/// Intermediates are put in order, and the root is left out wherever it was
+#[test]
+fn misordered() {
+    let [leaf, int, root] = ["leaf.der", "int.der", "root.der"].map(cert);
+    let (chain, normalization) =
+        CertificateChainDer::normalize_acme(vec![leaf.clone(), root, int.clone()], &spki(&leaf))
+            .unwrap();
+
+    assert_eq!(&chain[..], [leaf, int]);
+    assert_eq!(normalization.order, [0, 2]);
+    assert_eq!(normalization.removed_root, Some(1));
+
+    // The leaf alone is already normalized.
+    let leaf = cert("leaf.der");
+    let (chain, normalization) =
+        CertificateChainDer::normalize_acme(vec![leaf.clone()], &spki(&leaf)).unwrap();
+    assert_eq!(&chain[..], [leaf]);
+    assert!(normalization.is_unchanged());
+}
+
+/// The same intermediate issued by two roots could each follow the leaf
+#[test]
+fn ambiguous_issuer() {
+    let [leaf, int, cross] = ["leaf.der", "int.der", "int-cross.der"].map(cert);
+    let err = normalize(vec![leaf, int, cross], "leaf.der").unwrap_err();
+    assert_eq!(
+        err,
+        ChainError::AmbiguousIssuer {
+            index: 0,
+            candidates: vec![1, 2]
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "certificate 0 could have been issued by any of certificates [1, 2]"
+    );
+}
+
+/// A certificate that does not continue the chain is not silently dropped
+#[test]
+fn unchained() {
+    let [leaf, int, rsaleaf] = ["leaf.der", "int.der", "rsaleaf.der"].map(cert);
+    let err = normalize(vec![leaf, rsaleaf, int], "leaf.der").unwrap_err();
+    assert_eq!(err, ChainError::Unchained { index: 1 });
+    assert_eq!(
+        err.to_string(),
+        "certificate 1 is not part of the chain from the end-entity certificate"
+    );
+
+    // Nor is a second root.
+    let [leaf, x3, dst, root] = [
+        "letsencrypt/leaf.der",
+        "letsencrypt/x3.der",
+        "letsencrypt/dst-root-x3.der",
+        "root.der",
+    ]
+    .map(cert);
+    let err = normalize(vec![leaf, x3, dst, root], "letsencrypt/leaf.der").unwrap_err();
+    assert_eq!(err, ChainError::Unchained { index: 3 });
+}
+
+#[test]
+fn end_entity_mismatch() {
+    // The intermediate is first, or the key is for another certificate.
+    let [leaf, int] = ["leaf.der", "int.der"].map(cert);
+    for (certs, key) in [
+        (vec![int.clone(), leaf.clone()], "leaf.der"),
+        (vec![leaf, int], "int.der"),
+    ] {
+        let err = normalize(certs, key).unwrap_err();
+        assert_eq!(err, ChainError::EndEntityMismatch);
+        assert_eq!(
+            err.to_string(),
+            "first certificate does not have the expected public key"
+        );
+    }
+}
+
+#[test]
+fn malformed() {
+    assert_eq!(
+        normalize(vec![], "leaf.der").unwrap_err(),
+        ChainError::Empty
+    );
+
+    let junk = CertificateDer::from(vec![0x30, 0x00]);
+    let err = normalize(vec![cert("leaf.der"), junk], "leaf.der").unwrap_err();
+    assert_eq!(
+        err,
+        ChainError::InvalidCertificate {
+            index: 1,
+            error: InvalidDer::Truncated
+        }
+    );
+}
+
+fn normalize(
+    certs: Vec<CertificateDer<'static>>,
+    end_entity: &str,
+) -> Result<(CertificateChainDer<'static>, ChainNormalization), ChainError> {
+    let key = cert(end_entity);
+    CertificateChainDer::normalize_acme(certs, &spki(&key))
+}
+
+fn spki<'a>(cert: &'a CertificateDer<'_>) -> SubjectPublicKeyInfoDer<'a> {
+    cert.spans().unwrap().subject_public_key_info()
+}
+
+fn cert(name: &str) -> CertificateDer<'static> {
+    CertificateDer::from(fs::read(format!("testdata/{name}")).unwrap())
+}
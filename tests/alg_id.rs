@@ -0,0 +1,110 @@
+use rustls_pki_types::alg_id;
+
+#[test]
+fn post_quantum_oids() {
+    let cases = [
+        (alg_id::ML_DSA_44, "2.16.840.1.101.3.4.3.17"),
+        (alg_id::ML_DSA_65, "2.16.840.1.101.3.4.3.18"),
+        (alg_id::ML_DSA_87, "2.16.840.1.101.3.4.3.19"),
+        (alg_id::SLH_DSA_SHA2_128S, "2.16.840.1.101.3.4.3.20"),
+        (alg_id::SLH_DSA_SHA2_128F, "2.16.840.1.101.3.4.3.21"),
+        (alg_id::SLH_DSA_SHA2_192S, "2.16.840.1.101.3.4.3.22"),
+        (alg_id::SLH_DSA_SHA2_192F, "2.16.840.1.101.3.4.3.23"),
+        (alg_id::SLH_DSA_SHA2_256S, "2.16.840.1.101.3.4.3.24"),
+        (alg_id::SLH_DSA_SHA2_256F, "2.16.840.1.101.3.4.3.25"),
+        (alg_id::SLH_DSA_SHAKE_128S, "2.16.840.1.101.3.4.3.26"),
+        (alg_id::SLH_DSA_SHAKE_128F, "2.16.840.1.101.3.4.3.27"),
+        (alg_id::SLH_DSA_SHAKE_192S, "2.16.840.1.101.3.4.3.28"),
+        (alg_id::SLH_DSA_SHAKE_192F, "2.16.840.1.101.3.4.3.29"),
+        (alg_id::SLH_DSA_SHAKE_256S, "2.16.840.1.101.3.4.3.30"),
+        (alg_id::SLH_DSA_SHAKE_256F, "2.16.840.1.101.3.4.3.31"),
+    ];
+
+    for (alg_id, dotted) in cases {
+        // Parameters are absent, so the identifier is just the OID.
+        assert_eq!(&alg_id[..], &oid(dotted)[..], "{dotted}");
+    }
+}
+
+#[test]
+fn oid_encoding() {
+    // Examples from X.690, and an arc needing three octets.
+    assert_eq!(
+        oid("1.2.840.113549"),
+        [0x06, 0x06, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d]
+    );
+    assert_eq!(oid("2.999.3"), [0x06, 0x03, 0x88, 0x37, 0x03]);
+    assert_eq!(
+        oid("1.3.6.1.4.1.311.21.20"),
+        [0x06, 0x09, 0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x15, 0x14]
+    );
+}
+
+/// The DER encoding of the `OBJECT IDENTIFIER` written as `dotted`
+fn oid(dotted: &str) -> Vec<u8> {
+    let arcs = dotted
+        .split('.')
+        .map(|arc| arc.parse::<u64>().unwrap())
+        .collect::<Vec<_>>();
+    assert!(arcs.len() >= 2 && arcs[0] <= 2);
+
+    let mut contents = Vec::<u8>::new();
+    let first = arcs[0] * 40 + arcs[1];
+    for &arc in [first].iter().chain(&arcs[2..]) {
+        let mut groups = vec![(arc & 0x7f) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            groups.push((rest & 0x7f) as u8 | 0x80);
+            rest >>= 7;
+        }
+        contents.extend(groups.iter().rev());
+    }
+
+    let mut der = vec![0x06, contents.len() as u8];
+    der.extend(contents);
+    der
+}
+
+#[cfg(feature = "x509")]
+#[test]
+fn classical_keys_match_openssl() {
+    use rustls_pki_types::SubjectPublicKeyInfoDer;
+
+    let cases = [
+        (alg_id::ED448, "ed448"),
+        (alg_id::X448, "x448"),
+        (alg_id::ECDSA_BRAINPOOL_P256R1, "brainpoolP256r1"),
+        (alg_id::ECDSA_BRAINPOOL_P384R1, "brainpoolP384r1"),
+        (alg_id::ECDSA_BRAINPOOL_P512R1, "brainpoolP512r1"),
+    ];
+
+    for (alg_id, name) in cases {
+        let der = std::fs::read(format!("testdata/alg-id/{name}.spki.der")).unwrap();
+        let spki = SubjectPublicKeyInfoDer::from(&der[..]);
+        assert_eq!(spki.algorithm().unwrap(), &alg_id[..], "{name}");
+    }
+}
+
+#[cfg(feature = "x509")]
+#[test]
+fn ecdsa_signatures_match_openssl() {
+    use rustls_pki_types::CertificateDer;
+
+    let cases = [
+        (alg_id::ECDSA_SHA256, "sha256"),
+        (alg_id::ECDSA_SHA384, "sha384"),
+        (alg_id::ECDSA_SHA512, "sha512"),
+    ];
+
+    for (alg_id, hash) in cases {
+        let der = std::fs::read(format!("testdata/alg-id/ecdsa-{hash}.der")).unwrap();
+        let cert = CertificateDer::from(&der[..]);
+        assert_eq!(cert.signature_algorithm().unwrap(), &alg_id[..], "{hash}");
+        // Every certificate has a P-256 key, whichever hash it is signed with.
+        let spans = cert.spans().unwrap();
+        assert_ne!(
+            spans.subject_public_key_info().algorithm().unwrap(),
+            &alg_id[..]
+        );
+    }
+}
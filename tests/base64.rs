@@ -0,0 +1,207 @@
+//! Randomized comparison of the base64 decoders with a simple reference decoder.
+
+#![cfg(feature = "pem")]
+
+use rustls_pki_types::pem::{self, Item};
+use rustls_pki_types::{CertificateChainDer, CertificateDer};
+
+/// Lenient standard base64, as in PEM: whitespace is ignored and padding is optional
+#[test]
+fn pem_matches_reference() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let (mut valid, mut invalid) = (0, 0);
+    for _ in 0..ROUNDS {
+        let input = rng.input(STANDARD, Mode::Lenient);
+        let expected = reference(&input, STANDARD, Mode::Lenient);
+        match &expected {
+            Some(_) => valid += 1,
+            None => invalid += 1,
+        }
+
+        let mut pem = b"-----BEGIN DATA-----\n".to_vec();
+        pem.extend_from_slice(&input);
+        pem.extend_from_slice(b"\n-----END DATA-----\n");
+        let actual = match pem::read_all(&pem).next() {
+            Some(Ok(Item::Unknown { der, .. })) => Some(der),
+            Some(Ok(item)) => panic!("unexpected item {item:?}"),
+            Some(Err(_)) => None,
+            None => panic!("no section in {:?}", String::from_utf8_lossy(&pem)),
+        };
+        assert_eq!(actual, expected, "{:?}", String::from_utf8_lossy(&input));
+    }
+
+    assert!(
+        valid > ROUNDS / 4 && invalid > ROUNDS / 4,
+        "{valid} {invalid}"
+    );
+}
+
+/// Unpadded base64url, without whitespace
+#[test]
+fn base64url_matches_reference() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let mut valid = 0;
+    for _ in 0..ROUNDS {
+        let input = rng.input(URL_SAFE, Mode::Unpadded);
+        let expected = reference(&input, URL_SAFE, Mode::Unpadded);
+        valid += expected.is_some() as usize;
+
+        let actual = std::str::from_utf8(&input)
+            .ok()
+            .and_then(|input| CertificateDer::from_base64url(input).ok())
+            .map(|cert| cert.to_vec());
+        assert_eq!(actual, expected, "{:?}", String::from_utf8_lossy(&input));
+    }
+
+    assert!(valid > ROUNDS / 4, "{valid}");
+}
+
+/// Padded standard base64, without whitespace, as in `x5c`
+#[test]
+fn x5c_matches_reference() {
+    let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+    let mut valid = 0;
+    for _ in 0..ROUNDS {
+        let input = rng.input(STANDARD, Mode::Padded);
+        let expected = reference(&input, STANDARD, Mode::Padded);
+        valid += expected.is_some() as usize;
+
+        let actual = std::str::from_utf8(&input)
+            .ok()
+            .and_then(|input| CertificateChainDer::from_x5c([input]).ok())
+            .map(|chain| chain[0].to_vec());
+        assert_eq!(actual, expected, "{:?}", String::from_utf8_lossy(&input));
+    }
+
+    assert!(valid > ROUNDS / 4, "{valid}");
+}
+
+/// Decode `input`, one character at a time, searching `alphabet` for each one
+fn reference(input: &[u8], alphabet: &[u8; 64], mode: Mode) -> Option<Vec<u8>> {
+    let mut values = Vec::new();
+    let mut padding = 0;
+    for &c in input {
+        match c {
+            b' ' | b'\t' | b'\n' | b'\r' | b'\x0c' if mode == Mode::Lenient => {}
+            b'=' if mode != Mode::Unpadded => padding += 1,
+            _ if padding > 0 => return None,
+            _ => values.push(alphabet.iter().position(|&a| a == c)? as u32),
+        }
+    }
+
+    if mode == Mode::Padded && input.len() % 4 != 0 {
+        return None;
+    }
+    // Padding completes the last group of four, when present.
+    let rem = values.len() % 4;
+    if rem == 1 || (padding > 0 && rem + padding != 4) {
+        return None;
+    }
+
+    let mut out = Vec::new();
+    for group in values.chunks(4) {
+        let bits = group
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, v)| acc | v << (18 - 6 * i));
+        let bytes = bits.to_be_bytes();
+        let len = group.len() * 6 / 8;
+        // Bits that do not make a whole byte must be zero.
+        if bits & (0x00ff_ffff >> (len * 8)) != 0 {
+            return None;
+        }
+        out.extend_from_slice(&bytes[1..1 + len]);
+    }
+
+    Some(out)
+}
+
+/// Encode `input` with `alphabet`, with or without padding
+fn encode(input: &[u8], alphabet: &[u8; 64], pad: bool) -> Vec<u8> {
+    let mut out = Vec::new();
+    for chunk in input.chunks(3) {
+        let mut bytes = [0; 4];
+        bytes[1..1 + chunk.len()].copy_from_slice(chunk);
+        let bits = u32::from_be_bytes(bytes);
+        for i in 0..=chunk.len() {
+            out.push(alphabet[(bits >> (18 - 6 * i) & 0x3f) as usize]);
+        }
+        if pad {
+            out.resize(out.len() + 3 - chunk.len(), b'=');
+        }
+    }
+    out
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Lenient,
+    Padded,
+    Unpadded,
+}
+
+/// A xorshift generator, so that failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Make an input for `mode`: usually an encoding of random bytes, then perturbed
+    fn input(&mut self, alphabet: &[u8; 64], mode: Mode) -> Vec<u8> {
+        let bytes = (0..self.below(64))
+            .map(|_| self.next() as u8)
+            .collect::<Vec<_>>();
+        let pad = match mode {
+            Mode::Lenient => self.below(2) == 0,
+            Mode::Padded => true,
+            Mode::Unpadded => false,
+        };
+        let mut input = encode(&bytes, alphabet, pad);
+
+        if mode == Mode::Lenient {
+            for _ in 0..self.below(8) {
+                let at = self.below(input.len() + 1);
+                input.insert(at, b" \t\r\n\n\n"[self.below(6)]);
+            }
+        }
+
+        // Half of the inputs are left valid; the rest get random changes.
+        if self.below(2) == 0 {
+            return input;
+        }
+        for _ in 0..1 + self.below(3) {
+            let c = match self.below(4) {
+                0 => b'=',
+                1 => b"!_-+/ \n"[self.below(7)],
+                _ => alphabet[self.below(64)],
+            };
+            let at = self.below(input.len() + 1);
+            match self.below(3) {
+                0 if at < input.len() => input[at] = c,
+                1 if at < input.len() => {
+                    input.remove(at);
+                }
+                _ => input.insert(at, c),
+            }
+        }
+
+        // Keep the PEM framing intact.
+        if mode == Mode::Lenient {
+            input.retain(|&c| c != b'-');
+        }
+        input
+    }
+}
+
+const ROUNDS: usize = 20_000;
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
@@ -0,0 +1,104 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use rustls_pki_types::{
+    CertificateDer, CertificateRevocationListDer, CertificateSigningRequestDer, Der,
+    OcspResponseDer, PrivateKeyDer, PrivatePkcs8KeyDer, SubjectPublicKeyInfoDer,
+};
+
+#[test]
+fn lend_certificate_by_value() {
+    fn consume(cert: CertificateDer<'_>) -> usize {
+        cert.len()
+    }
+
+    let cert = CertificateDer::from(vec![0x30, 0x01, 0x00]);
+    for _ in 0..2 {
+        let view = cert.as_borrowed();
+        assert_eq!(view, cert);
+        assert_eq!(view.as_ptr(), cert.as_ptr());
+        assert_eq!(consume(view), 3);
+    }
+    assert_eq!(consume(cert), 3);
+}
+
+#[test]
+fn lend_der_by_value() {
+    fn consume(der: Der<'_>) -> Vec<u8> {
+        der.as_ref().to_vec()
+    }
+
+    let der = Der::from(vec![1, 2, 3]);
+    assert_eq!(der.as_borrowed(), der);
+    assert_eq!(consume(der.as_borrowed()), [1, 2, 3]);
+    assert_eq!(consume(der), [1, 2, 3]);
+}
+
+#[test]
+fn other_types() {
+    let bytes = vec![0x30, 0x00];
+
+    let crl = CertificateRevocationListDer::from(bytes.clone());
+    assert_eq!(crl.as_borrowed(), crl);
+    let ocsp = OcspResponseDer::from(bytes.clone());
+    assert_eq!(ocsp.as_borrowed().as_ref(), ocsp.as_ref());
+    let csr = CertificateSigningRequestDer::from(bytes.clone());
+    assert_eq!(csr.as_borrowed().as_ref(), csr.as_ref());
+    let spki = SubjectPublicKeyInfoDer::from(bytes.clone());
+    assert_eq!(spki.as_borrowed(), spki);
+
+    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(bytes));
+    let view = key.borrow_key();
+    assert_eq!(view.secret_der().as_ptr(), key.secret_der().as_ptr());
+    assert_eq!(view, key);
+}
+
+#[test]
+fn lookup_static_keys_by_slice() {
+    let mut certs: HashMap<CertificateDer<'static>, usize> = HashMap::new();
+    certs.insert(CertificateDer::from(vec![0x30, 0x01, 0x01]), 1);
+    certs.insert(CertificateDer::from(vec![0x30, 0x01, 0x02]), 2);
+
+    let received = [0x30, 0x01, 0x02];
+    assert_eq!(certs.get(&received[..]), Some(&2));
+    assert_eq!(certs.get(&[0x30, 0x01, 0x03][..]), None);
+
+    let crls = [CertificateRevocationListDer::from(vec![0x30, 0x00])]
+        .into_iter()
+        .collect::<HashSet<CertificateRevocationListDer<'static>>>();
+    assert!(crls.contains(&[0x30, 0x00][..]));
+
+    let sorted = [CertificateDer::from(vec![0x30, 0x00])]
+        .into_iter()
+        .collect::<BTreeSet<CertificateDer<'static>>>();
+    assert!(sorted.contains(&[0x30, 0x00][..]));
+
+    let mut ders: HashMap<Der<'static>, ()> = HashMap::new();
+    ders.insert(Der::from(vec![4, 5]), ());
+    assert!(ders.contains_key(&[4, 5][..]));
+}
+
+#[test]
+fn cow_of_certificate() {
+    struct Cache {
+        cert: CertificateDer<'static>,
+    }
+
+    impl Cache {
+        fn get(&self) -> Cow<'_, CertificateDer<'_>> {
+            Cow::Borrowed(&self.cert)
+        }
+    }
+
+    let cache = Cache {
+        cert: CertificateDer::from(vec![0x30, 0x00]),
+    };
+    let cert = cache.get();
+    assert!(matches!(cert, Cow::Borrowed(_)));
+    assert_eq!(cert.as_ptr(), cache.cert.as_ptr());
+
+    let owned: CertificateDer<'static> = cert.into_owned().into_owned();
+    assert_eq!(owned, cache.cert);
+}
@@ -0,0 +1,190 @@
+#![cfg(feature = "std")]
+
+use std::fs;
+use std::io::Cursor;
+
+use rustls_pki_types::{
+    CacheError, CertificateChainDer, CertificateDer, OcspResponseDer, MAX_CACHED_ENTRY_LEN,
+};
+
+#[test]
+fn round_trip() {
+    let chain = chain();
+    let mut buf = Vec::new();
+    chain.write_cached(&mut buf).unwrap();
+    buf.extend_from_slice(b"after");
+
+    let mut r = Cursor::new(&buf[..]);
+    let read = CertificateChainDer::read_cached(&mut r).unwrap();
+    assert_eq!(&read[..], &chain[..]);
+    assert_eq!(&buf[r.position() as usize..], b"after");
+}
+
+#[test]
+fn round_trip_with_ocsp() {
+    let chain = chain();
+    let ocsp = OcspResponseDer::from(vec![0x30, 0x03, 0x0a, 0x01, 0x00]);
+    for ocsp in [Some(&ocsp), None] {
+        let mut buf = Vec::new();
+        chain.write_cached_with_ocsp(ocsp, &mut buf).unwrap();
+        let (read_chain, read_ocsp) = CertificateChainDer::read_cached_with_ocsp(&buf[..]).unwrap();
+        assert_eq!(&read_chain[..], &chain[..]);
+        assert_eq!(read_ocsp.as_ref(), ocsp);
+    }
+}
+
+#[test]
+fn empty_chain_encoding() {
+    let mut buf = Vec::new();
+    CertificateChainDer::from(Vec::new())
+        .write_cached(&mut buf)
+        .unwrap();
+    // The checksum is the CRC-32 of the version and count, as computed by zlib.
+    assert_eq!(buf, b"pkic\x01\x00\x00\x00\x00\xfb\x42\xde\xad");
+}
+
+#[test]
+fn truncated() {
+    let buf = cached();
+    for len in 0..buf.len() {
+        let err = CertificateChainDer::read_cached(&buf[..len]).unwrap_err();
+        assert!(matches!(err, CacheError::Truncated), "{len}: {err:?}");
+    }
+}
+
+#[test]
+fn bad_magic() {
+    let buf = cached();
+    for i in 0..4 {
+        let mut corrupt = buf.clone();
+        corrupt[i] ^= 0x20;
+        let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+        assert!(matches!(err, CacheError::BadMagic), "{i}: {err:?}");
+    }
+
+    // A DER certificate is not a cache file.
+    let err = CertificateChainDer::read_cached(chain()[0].as_ref()).unwrap_err();
+    assert!(matches!(err, CacheError::BadMagic), "{err:?}");
+}
+
+#[test]
+fn bad_version() {
+    let mut buf = cached();
+    buf[4] = 2;
+    let err = CertificateChainDer::read_cached(&buf[..]).unwrap_err();
+    assert!(matches!(err, CacheError::UnsupportedVersion(2)), "{err:?}");
+}
+
+#[test]
+fn bad_lengths() {
+    let buf = cached();
+    let first_len = 9;
+
+    // An entry claiming more than the limit is rejected before it is read.
+    let mut corrupt = buf.clone();
+    corrupt[first_len..first_len + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+    let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+    assert!(
+        matches!(err, CacheError::EntryTooLarge { len } if len == u32::MAX as usize),
+        "{err:?}"
+    );
+
+    let mut corrupt = buf.clone();
+    let len = MAX_CACHED_ENTRY_LEN as u32 + 1;
+    corrupt[first_len..first_len + 4].copy_from_slice(&len.to_be_bytes());
+    let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+    assert!(matches!(err, CacheError::EntryTooLarge { .. }), "{err:?}");
+
+    // An entry claiming more than the file holds is truncated.
+    let mut corrupt = buf.clone();
+    corrupt[first_len..first_len + 4].copy_from_slice(&1_000_000u32.to_be_bytes());
+    let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+    assert!(matches!(err, CacheError::Truncated), "{err:?}");
+
+    // A shorter entry leaves the rest misframed, and at best fails the checksum.
+    let mut corrupt = buf.clone();
+    corrupt[first_len + 3] -= 1;
+    assert!(CertificateChainDer::read_cached(&corrupt[..]).is_err());
+
+    // So does a count that disagrees with the entries.
+    let mut corrupt = buf.clone();
+    corrupt[8] -= 1;
+    let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+    assert!(matches!(err, CacheError::ChecksumMismatch), "{err:?}");
+
+    // A count beyond the entries reads the checksum as a length, then runs off the end.
+    let mut corrupt = buf;
+    corrupt[5..9].copy_from_slice(&u32::MAX.to_be_bytes());
+    let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CacheError::Truncated | CacheError::EntryTooLarge { .. }
+        ),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn bad_checksum() {
+    let buf = cached();
+    let last = buf.len() - 1;
+    let mut corrupt = buf.clone();
+    corrupt[last] ^= 1;
+    let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+    assert!(matches!(err, CacheError::ChecksumMismatch), "{err:?}");
+
+    // A flipped bit in the DER entries is caught by the checksum.
+    let first_entry = 13;
+    for i in [first_entry, first_entry + 100, last - 10] {
+        for bit in 0..8 {
+            let mut corrupt = buf.clone();
+            corrupt[i] ^= 1 << bit;
+            let err = CertificateChainDer::read_cached(&corrupt[..]).unwrap_err();
+            assert!(
+                matches!(err, CacheError::ChecksumMismatch),
+                "{i}/{bit}: {err:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn every_bit_flip_is_rejected() {
+    let buf = cached();
+    for i in 0..buf.len() {
+        for bit in 0..8 {
+            let mut corrupt = buf.clone();
+            corrupt[i] ^= 1 << bit;
+            assert!(
+                CertificateChainDer::read_cached(&corrupt[..]).is_err(),
+                "{i}/{bit}"
+            );
+        }
+    }
+}
+
+#[test]
+fn write_too_large() {
+    let chain = CertificateChainDer::from(vec![CertificateDer::from(vec![
+        0;
+        MAX_CACHED_ENTRY_LEN + 1
+    ])]);
+    let mut buf = Vec::new();
+    let err = chain.write_cached(&mut buf).unwrap_err();
+    assert!(matches!(err, CacheError::EntryTooLarge { len } if len == MAX_CACHED_ENTRY_LEN + 1));
+    assert!(buf.is_empty());
+}
+
+fn cached() -> Vec<u8> {
+    let mut buf = Vec::new();
+    chain().write_cached(&mut buf).unwrap();
+    buf
+}
+
+fn chain() -> CertificateChainDer<'static> {
+    ["testdata/leaf.der", "testdata/int.der", "testdata/root.der"]
+        .iter()
+        .map(|path| CertificateDer::from(fs::read(path).unwrap()))
+        .collect()
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "codegen")]
+
+use std::fs;
+
+use rustls_pki_types::codegen::{emit_rust, EmitOptions};
+use rustls_pki_types::TrustAnchor;
+
+/// The output of `emit_rust()` for `anchors()`, checked in so that it is compiled here
+mod generated {
+    include!("../testdata/roots.rs");
+}
+
+#[test]
+fn output_is_unchanged() {
+    let mut out = String::new();
+    emit_rust(&anchors(), &mut out, &EmitOptions::default()).unwrap();
+
+    let expected = fs::read_to_string("testdata/roots.rs").unwrap();
+    assert_eq!(
+        out, expected,
+        "emit_rust() output changed; update testdata/roots.rs if this is intended"
+    );
+}
+
+#[test]
+fn output_compiles_to_the_anchors() {
+    let mut anchors = anchors();
+    anchors.sort_by(|a, b| {
+        (&a.subject, &a.subject_public_key_info).cmp(&(&b.subject, &b.subject_public_key_info))
+    });
+    assert_eq!(generated::ROOTS, &anchors[..]);
+}
+
+#[test]
+fn options() {
+    let mut out = String::new();
+    let options = EmitOptions {
+        name: "MINE",
+        visibility: "",
+        crate_path: "crate::pki",
+    };
+    emit_rust(&anchors()[..1], &mut out, &options).unwrap();
+    assert!(out.starts_with("static MINE: &[crate::pki::TrustAnchor<'static>] = &[\n"));
+    assert!(out.contains("    crate::pki::TrustAnchor::from_parts(\n"));
+}
+
+fn anchors() -> Vec<TrustAnchor<'static>> {
+    // CN=ed, with an Ed25519 key, and no name constraints.
+    let ed = TrustAnchor::from_parts(
+        &[
+            0x31, 0x0b, 0x30, 0x09, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x02, b'e', b'd',
+        ],
+        ED25519_SPKI,
+        None,
+    );
+
+    // CN=nc, with the same key, limited to example.com.
+    let constrained = TrustAnchor::from_parts(
+        &[
+            0x31, 0x0b, 0x30, 0x09, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x02, b'n', b'c',
+        ],
+        ED25519_SPKI,
+        Some(&[
+            0xa0, 0x0f, 0x30, 0x0d, 0x82, 0x0b, b'e', b'x', b'a', b'm', b'p', b'l', b'e', b'.',
+            b'c', b'o', b'm',
+        ]),
+    );
+
+    vec![constrained, ed]
+}
+
+const ED25519_SPKI: &[u8] = &[
+    0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00, 0x19, 0xbf, 0x44, 0x09, 0x69, 0x84,
+    0xcd, 0xfe, 0x85, 0x41, 0xba, 0xc1, 0x67, 0xdc, 0x3b, 0x96, 0xc8, 0x50, 0x86, 0xaa, 0x30, 0xb6,
+    0xb6, 0xcb, 0x0c, 0x5c, 0x38, 0xad, 0x70, 0x31, 0x66, 0xe1,
+];
@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use std::fs;
+
+use rustls_pki_types::{
+    KeyConversionError, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer,
+};
+
+#[test]
+fn pkcs1_to_pkcs8() {
+    // `rsa2048.pkcs8.der` was written by `openssl pkcs8 -topk8` from the PKCS#1 key.
+    let pkcs1 = PrivatePkcs1KeyDer::from(read("rsa2048.pkcs1.der"));
+    let pkcs8 = PrivatePkcs8KeyDer::from_pkcs1(&pkcs1);
+    assert_eq!(pkcs8.secret_pkcs8_der(), &read("rsa2048.pkcs8.der")[..]);
+}
+
+#[test]
+fn sec1_to_pkcs8() {
+    // The PKCS#8 keys were written by `openssl pkcs8 -topk8` from the SEC1 keys.
+    for curve in ["p256", "p384"] {
+        let sec1 = PrivateSec1KeyDer::from(read(&format!("{curve}.sec1.der")));
+        let pkcs8 = PrivatePkcs8KeyDer::from_sec1(&sec1).unwrap();
+        assert_eq!(
+            pkcs8.secret_pkcs8_der(),
+            &read(&format!("{curve}.pkcs8.der"))[..],
+            "{curve}"
+        );
+    }
+}
+
+#[test]
+fn sec1_without_curve() {
+    // Leave out the `[0]` parameters of the P-256 key, which come right after its private key.
+    let der = read("p256.sec1.der");
+    let (start, len) = (2 + 3 + 2 + 32, 2 + 10);
+    assert_eq!(der[start], 0xa0);
+    let mut stripped = der[..start].to_vec();
+    stripped.extend_from_slice(&der[start + len..]);
+    stripped[1] -= len as u8;
+
+    let sec1 = PrivateSec1KeyDer::from(stripped);
+    assert_eq!(
+        PrivatePkcs8KeyDer::from_sec1(&sec1),
+        Err(KeyConversionError::MissingCurve)
+    );
+}
+
+#[test]
+fn pkcs8_to_pkcs1() {
+    // `openssl rsa -traditional` writes the same PKCS#1 key from the PKCS#8 one.
+    let pkcs8 = PrivatePkcs8KeyDer::from(read("rsa2048.pkcs8.der"));
+    let pkcs1 = pkcs8.try_into_pkcs1().unwrap();
+    assert_eq!(pkcs1.secret_pkcs1_der(), &read("rsa2048.pkcs1.der")[..]);
+}
+
+#[test]
+fn pkcs8_to_sec1() {
+    // `openssl ec` writes the same SEC1 keys from the PKCS#8 ones, with the curve put back.
+    for curve in ["p256", "p384"] {
+        let pkcs8 = PrivatePkcs8KeyDer::from(read(&format!("{curve}.pkcs8.der")));
+        let sec1 = pkcs8.try_into_sec1().unwrap();
+        assert_eq!(
+            sec1.secret_sec1_der(),
+            &read(&format!("{curve}.sec1.der"))[..],
+            "{curve}"
+        );
+    }
+}
+
+#[test]
+fn unsupported_algorithms() {
+    let ed25519 = PrivatePkcs8KeyDer::from(read("ed25519.pkcs8.der"));
+    let expected = KeyConversionError::UnsupportedAlgorithm(vec![0x2b, 0x65, 0x70]);
+    assert_eq!(ed25519.try_into_pkcs1(), Err(expected.clone()));
+    assert_eq!(ed25519.try_into_sec1(), Err(expected.clone()));
+    assert_eq!(
+        expected.to_string(),
+        "cannot convert private key with algorithm 1.3.101.112"
+    );
+
+    // RSA keys are not EC keys, and the other way around.
+    let rsa = PrivatePkcs8KeyDer::from(read("rsa2048.pkcs8.der"));
+    assert_eq!(
+        rsa.try_into_sec1(),
+        Err(KeyConversionError::UnsupportedAlgorithm(vec![
+            0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01
+        ]))
+    );
+    let p256 = PrivatePkcs8KeyDer::from(read("p256.pkcs8.der"));
+    assert_eq!(
+        p256.try_into_pkcs1(),
+        Err(KeyConversionError::UnsupportedAlgorithm(vec![
+            0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01
+        ]))
+    );
+}
+
+fn read(name: &str) -> Vec<u8> {
+    fs::read(format!("testdata/{name}")).unwrap()
+}
@@ -0,0 +1,181 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+
+use rustls_pki_types::{CertificateRevocationListDer, CrlSet, SerialNumber};
+
+#[test]
+fn large_crl_matches_openssl() {
+    let crl = large();
+    let expected = fs::read_to_string("testdata/large.crl.txt").unwrap();
+    let expected = expected.lines().collect::<Vec<_>>();
+    assert_eq!(expected.len(), 3000);
+
+    let revoked = crl
+        .revoked_serials()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(revoked.len(), expected.len());
+
+    // 2024-01-01T00:00:00Z, as written by generate.sh.
+    let revoked_at = 1_704_067_200;
+    for ((serial, date), expected) in revoked.iter().zip(&expected) {
+        assert_eq!(openssl_hex(serial), *expected);
+        assert_eq!(date.as_secs(), revoked_at);
+    }
+
+    // Serial numbers with the high bit set are encoded with a leading zero.
+    assert!(revoked.iter().any(|(serial, _)| serial.as_bytes()[0] == 0));
+
+    let last = revoked.last().unwrap().0;
+    assert_eq!(crl.contains_serial(&last), Ok(true));
+    assert_eq!(
+        crl.contains_serial(&SerialNumber::from_slice(&[0x01])),
+        Ok(false)
+    );
+}
+
+#[test]
+fn crl_without_revoked_certificates() {
+    let crl = CertificateRevocationListDer::from(fs::read("testdata/empty.crl.der").unwrap());
+    assert_eq!(crl.revoked_serials().unwrap().count(), 0);
+    assert_eq!(
+        crl.contains_serial(&SerialNumber::from_slice(&[0x01])),
+        Ok(false)
+    );
+}
+
+/// Walk randomly corrupted CRLs, which must never panic or loop forever
+#[test]
+fn fuzz_revoked_serials() {
+    let original = fs::read("testdata/large.crl.der").unwrap();
+    let mut rng = Rng(0x853c_49e6_748f_ea9b);
+    for _ in 0..2_000 {
+        let mut der = original.clone();
+        match rng.below(3) {
+            0 => der.truncate(rng.below(der.len())),
+            _ => {
+                for _ in 0..1 + rng.below(4) {
+                    let at = rng.below(der.len());
+                    der[at] = rng.next() as u8;
+                }
+            }
+        }
+
+        let crl = CertificateRevocationListDer::from(der);
+        if let Ok(serials) = crl.revoked_serials() {
+            let mut count = 0;
+            for entry in serials {
+                count += 1;
+                assert!(count <= 3_001);
+                if entry.is_err() {
+                    break;
+                }
+            }
+        }
+        let _ = crl.contains_serial(&SerialNumber::from_slice(&[0x01]));
+    }
+}
+
+/// Truncating a CRL anywhere makes it fail to decode, rather than yield fewer entries
+#[test]
+fn truncated_crl() {
+    let original = fs::read("testdata/large.crl.der").unwrap();
+    for len in (0..original.len()).step_by(97) {
+        let crl = CertificateRevocationListDer::from(&original[..len]);
+        assert!(crl.revoked_serials().is_err(), "{len}");
+    }
+}
+
+/// Build sets from a mix of valid and corrupted CRLs, which must never panic
+#[test]
+fn fuzz_crl_set() {
+    let crls = [
+        fs::read("testdata/large.crl.der").unwrap(),
+        fs::read("testdata/empty.crl.der").unwrap(),
+    ];
+    let mut rng = Rng(0xda94_2042_e4dd_58b5);
+    for _ in 0..500 {
+        let mut input = Vec::new();
+        let mut valid = 0;
+        for _ in 0..rng.below(6) {
+            let mut der = crls[rng.below(2)].clone();
+            if rng.below(2) == 0 {
+                let at = rng.below(der.len());
+                der[at] ^= 1 << rng.below(8);
+            } else {
+                valid += 1;
+            }
+            input.push(CertificateRevocationListDer::from(der));
+        }
+
+        let (set, errors) = CrlSet::from_crls(input.clone());
+        assert!(set.len() + errors.len() <= input.len());
+        assert!(set.len() >= valid.min(1));
+        for (index, _) in &errors {
+            assert!(input[*index].spans().is_err());
+        }
+        for crl in set.iter() {
+            let issuer = crl.spans().unwrap().issuer();
+            assert!(set.find_by_issuer(issuer).any(|found| found == crl));
+            assert!(set.freshest_for_issuer(issuer).is_some());
+        }
+    }
+}
+
+#[test]
+fn crl_set_indexes_by_issuer() {
+    let large = large();
+    let empty = CertificateRevocationListDer::from(fs::read("testdata/empty.crl.der").unwrap());
+    let issuer = large.spans().unwrap().issuer().to_vec();
+    assert_eq!(empty.spans().unwrap().issuer(), &issuer[..]);
+
+    let (mut set, errors) = CrlSet::from_crls([empty.clone(), large.clone(), empty.clone()]);
+    assert!(errors.is_empty());
+    assert_eq!(set.len(), 2);
+    // The CRLs were issued in the same second, so the later insertion is the freshest.
+    assert_eq!(set.freshest_for_issuer(&issuer), Some(&large));
+    assert_eq!(set.find_by_issuer(&issuer).count(), 2);
+    assert_eq!(set.find_by_issuer(&[0x31, 0x00]).count(), 0);
+
+    let removed = set.replace(empty.clone()).unwrap();
+    assert_eq!(removed.len(), 2);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [&empty]);
+
+    let (set, errors) =
+        CrlSet::from_crls([CertificateRevocationListDer::from(vec![0x30, 0x00]), large]);
+    assert_eq!(set.len(), 1);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 0);
+}
+
+fn large() -> CertificateRevocationListDer<'static> {
+    CertificateRevocationListDer::from(fs::read("testdata/large.crl.der").unwrap())
+}
+
+/// Format `serial` as `openssl crl -text` does: upper-case hex, without a sign octet
+fn openssl_hex(serial: &SerialNumber<'_>) -> String {
+    let bytes = serial.as_bytes();
+    let bytes = match bytes {
+        [0, rest @ ..] if !rest.is_empty() => rest,
+        _ => bytes,
+    };
+    bytes.iter().map(|b| format!("{b:02X}")).collect()
+}
+
+/// A xorshift generator, so that failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(any(feature = "x509", feature = "alloc"))]
+
+use rustls_pki_types::der::peek_len;
+use rustls_pki_types::InvalidDer;
+
+#[test]
+fn short_form() {
+    assert_eq!(peek_len(&[0x30, 0x00]), Ok(Some(2)));
+    assert_eq!(peek_len(&[0x30, 0x7f]), Ok(Some(2 + 127)));
+    // The contents are not needed, and anything after them is ignored.
+    assert_eq!(peek_len(&[0x04, 0x01, 0xff, 0x30]), Ok(Some(3)));
+}
+
+#[test]
+fn long_form() {
+    assert_eq!(peek_len(&[0x30, 0x81, 0x80]), Ok(Some(3 + 128)));
+    assert_eq!(peek_len(&[0x30, 0x81, 0xff]), Ok(Some(3 + 255)));
+    assert_eq!(peek_len(&[0x30, 0x82, 0x01, 0x00]), Ok(Some(4 + 256)));
+    assert_eq!(peek_len(&[0x30, 0x82, 0xff, 0xff]), Ok(Some(4 + 65535)));
+    assert_eq!(
+        peek_len(&[0x30, 0x83, 0x01, 0x00, 0x00]),
+        Ok(Some(5 + 65536))
+    );
+    assert_eq!(
+        peek_len(&[0x30, 0x84, 0x01, 0x00, 0x00, 0x00]),
+        Ok(Some(6 + 0x0100_0000))
+    );
+}
+
+#[test]
+fn incomplete_header() {
+    assert_eq!(peek_len(&[]), Ok(None));
+    assert_eq!(peek_len(&[0x30]), Ok(None));
+    assert_eq!(peek_len(&[0x30, 0x81]), Ok(None));
+    assert_eq!(peek_len(&[0x30, 0x82, 0x01]), Ok(None));
+    assert_eq!(peek_len(&[0x30, 0x84, 0x01, 0x00, 0x00]), Ok(None));
+}
+
+#[test]
+fn invalid_lengths() {
+    for header in [
+        // Indefinite length.
+        &[0x30, 0x80][..],
+        // Long form where the short form fits.
+        &[0x30, 0x81, 0x7f],
+        // Leading zero length octet.
+        &[0x30, 0x82, 0x00, 0x80],
+        // More length octets than supported.
+        &[0x30, 0x85, 0x01, 0x00, 0x00, 0x00, 0x00],
+    ] {
+        assert_eq!(
+            peek_len(header),
+            Err(InvalidDer::InvalidLength),
+            "{header:02x?}"
+        );
+    }
+
+    assert_eq!(
+        peek_len(&[0x1f, 0x81, 0x01]),
+        Err(InvalidDer::UnsupportedTag(0x1f))
+    );
+}
+
+#[cfg(feature = "std")]
+mod read_one_der {
+    use std::io::{self, Cursor, Read};
+
+    use rustls_pki_types::der::{read_one_der, ReadDerError};
+    use rustls_pki_types::InvalidDer;
+
+    #[test]
+    fn lengths() {
+        for len in [0, 1, 127, 128, 255, 256, 65535, 65536] {
+            let der = element(len);
+            assert_eq!(read_one_der(&der[..], der.len()).unwrap(), der, "{len}");
+        }
+    }
+
+    #[test]
+    fn consecutive_elements() {
+        let (first, second) = (element(127), element(128));
+        let mut input = Cursor::new([&first[..], &second[..], &[0xff]].concat());
+
+        assert_eq!(read_one_der(&mut input, 1024).unwrap(), first);
+        assert_eq!(read_one_der(&mut input, 1024).unwrap(), second);
+        assert_eq!(input.position() as usize, first.len() + second.len());
+    }
+
+    #[test]
+    fn one_byte_at_a_time() {
+        for len in [0, 127, 128, 300] {
+            let der = element(len);
+            let input = [&der[..], &[0x30, 0x00]].concat();
+            let mut input = OneByte(Cursor::new(input));
+            assert_eq!(read_one_der(&mut input, 1024).unwrap(), der, "{len}");
+            assert_eq!(input.0.position() as usize, der.len());
+        }
+    }
+
+    #[test]
+    fn too_large() {
+        let der = element(128);
+        assert_eq!(read_one_der(&der[..], 131).unwrap(), der);
+
+        // The limit covers the tag and length octets, and is checked before the contents are read.
+        let mut input = Cursor::new(&der[..]);
+        match read_one_der(&mut input, 130) {
+            Err(ReadDerError::TooLarge { len, max_len }) => assert_eq!((len, max_len), (131, 130)),
+            res => panic!("unexpected {res:?}"),
+        }
+        assert_eq!(input.position(), 3);
+
+        let err = read_one_der(&der[..], 130).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "DER element of 131 bytes exceeds the limit of 130 bytes"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        // Indefinite length.
+        match read_one_der(&[0x30, 0x80, 0x00, 0x00][..], 1024) {
+            Err(ReadDerError::InvalidDer(InvalidDer::InvalidLength)) => {}
+            res => panic!("unexpected {res:?}"),
+        }
+
+        // Input ending in the length octets, and in the contents.
+        for input in [&[0x30, 0x82, 0x01][..], &[0x30, 0x03, 0x05, 0x00]] {
+            match read_one_der(input, 1024) {
+                Err(ReadDerError::InvalidDer(InvalidDer::Truncated)) => {}
+                res => panic!("unexpected {res:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn io_error() {
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::PermissionDenied, "denied"))
+            }
+        }
+
+        match read_one_der(Failing, 1024) {
+            Err(ReadDerError::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::PermissionDenied),
+            res => panic!("unexpected {res:?}"),
+        }
+    }
+
+    /// A reader which yields at most one byte per call
+    struct OneByte<R>(R);
+
+    impl<R: Read> Read for OneByte<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(1);
+            self.0.read(&mut buf[..len])
+        }
+    }
+
+    /// An `OCTET STRING` with `len` contents octets
+    fn element(len: usize) -> Vec<u8> {
+        let mut der = vec![0x04];
+        match len {
+            0..=0x7f => der.push(len as u8),
+            0x80..=0xff => der.extend([0x81, len as u8]),
+            0x100..=0xffff => der.extend([0x82, (len >> 8) as u8, len as u8]),
+            _ => der.extend([0x83, (len >> 16) as u8, (len >> 8) as u8, len as u8]),
+        }
+        der.extend((0..len).map(|i| i as u8));
+        der
+    }
+}
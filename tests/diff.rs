@@ -0,0 +1,161 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use rustls_pki_types::{diff_anchors, TrustAnchor};
+
+#[test]
+fn identical() {
+    let a = cn("a");
+    let b = cn("b");
+    let anchors = [anchor(&a, b"A", None), anchor(&b, b"B", Some(b"NC"))];
+
+    let diff = diff_anchors(&anchors, &anchors);
+    assert!(diff.is_empty());
+    assert_eq!(
+        diff.to_string(),
+        "0 added, 0 removed, 0 with changed name constraints\n"
+    );
+}
+
+#[test]
+fn added_and_removed() {
+    let (a, b, c) = (cn("a"), cn("b"), cn("c"));
+    let old = [anchor(&c, b"C", None), anchor(&a, b"A", None)];
+    let new = [anchor(&b, b"B", None), anchor(&a, b"A", None)];
+
+    let diff = diff_anchors(&old, &new);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.added, [&new[0]]);
+    assert_eq!(diff.removed, [&old[0]]);
+    assert!(diff.constraints_changed.is_empty());
+
+    let reverse = diff_anchors(&new, &old);
+    assert_eq!(reverse.added, [&old[0]]);
+    assert_eq!(reverse.removed, [&new[0]]);
+}
+
+#[test]
+fn identity_is_subject_and_key() {
+    // The same subject with a new key is a different anchor, as is the same key under a
+    // different subject.
+    let (a, b) = (cn("a"), cn("b"));
+    let old = [anchor(&a, b"K1", None)];
+    let new = [anchor(&a, b"K2", None), anchor(&b, b"K1", None)];
+
+    let diff = diff_anchors(&old, &new);
+    assert_eq!(diff.added, [&new[0], &new[1]]);
+    assert_eq!(diff.removed, [&old[0]]);
+    assert!(diff.constraints_changed.is_empty());
+}
+
+#[test]
+fn constraints_changed() {
+    let (a, b, c, d) = (cn("a"), cn("b"), cn("c"), cn("d"));
+    let old = [
+        anchor(&a, b"A", None),
+        anchor(&b, b"B", Some(b"NC1")),
+        anchor(&c, b"C", Some(b"NC1")),
+        anchor(&d, b"D", Some(b"NC1")),
+    ];
+    let new = [
+        anchor(&d, b"D", Some(b"NC1")),
+        anchor(&c, b"C", None),
+        anchor(&b, b"B", Some(b"NC2")),
+        anchor(&a, b"A", Some(b"NC1")),
+    ];
+
+    let diff = diff_anchors(&old, &new);
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(
+        diff.constraints_changed,
+        [(&old[0], &new[3]), (&old[1], &new[2]), (&old[2], &new[1])]
+    );
+}
+
+#[test]
+fn duplicates() {
+    let (a, b) = (cn("a"), cn("b"));
+
+    // A repeated anchor is reported once.
+    let old = [anchor(&a, b"A", None)];
+    let new = [
+        anchor(&a, b"A", None),
+        anchor(&b, b"B", None),
+        anchor(&b, b"B", None),
+    ];
+    let diff = diff_anchors(&old, &new);
+    assert_eq!(diff.added, [&new[1]]);
+    assert!(diff.removed.is_empty());
+    assert!(diff_anchors(&new, &new).is_empty());
+
+    // Only the first occurrence counts, so its name constraints are the ones compared.
+    let old = [
+        anchor(&a, b"A", Some(b"NC1")),
+        anchor(&a, b"A", Some(b"NC2")),
+    ];
+    let new = [anchor(&a, b"A", Some(b"NC1"))];
+    assert!(diff_anchors(&old, &new).is_empty());
+
+    let new = [
+        anchor(&a, b"A", Some(b"NC2")),
+        anchor(&a, b"A", Some(b"NC1")),
+    ];
+    let diff = diff_anchors(&old, &new);
+    assert_eq!(diff.constraints_changed, [(&old[0], &new[0])]);
+}
+
+#[test]
+fn display() {
+    let (alpha, beta, gamma) = (cn("Alpha Root"), cn("Beta Root"), cn("Gamma Root"));
+    // An organization but no common name, as some roots have.
+    let org = name(&[(&[0x55, 0x04, 0x0a], 0x13, b"Delta Org")]);
+    let empty = Vec::new();
+    let malformed = vec![0x31, 0x05, 0x30];
+
+    let old = [
+        anchor(&alpha, b"A", None),
+        anchor(&beta, b"B", Some(b"NC1")),
+        anchor(&org, b"D", None),
+    ];
+    let new = [
+        anchor(&gamma, b"C", None),
+        anchor(&beta, b"B", Some(b"NC2")),
+        anchor(&empty, b"E", None),
+        anchor(&malformed, b"F", None),
+    ];
+
+    assert_eq!(
+        diff_anchors(&old, &new).to_string(),
+        "3 added, 2 removed, 1 with changed name constraints\n\
+         + (empty)\n\
+         + 310530\n\
+         + CN=Gamma Root\n\
+         - O=Delta Org\n\
+         - CN=Alpha Root\n\
+         ~ CN=Beta Root\n"
+    );
+}
+
+fn anchor<'a>(subject: &'a [u8], spki: &'a [u8], nc: Option<&'a [u8]>) -> TrustAnchor<'a> {
+    TrustAnchor::from_parts(subject, spki, nc)
+}
+
+/// The contents of a `Name` with a single UTF8String common name
+fn cn(value: &str) -> Vec<u8> {
+    name(&[(&[0x55, 0x04, 0x03], 0x0c, value.as_bytes())])
+}
+
+/// The contents of a `Name` with one attribute per RDN, each as (OID contents, tag, value)
+fn name(attributes: &[(&[u8], u8, &[u8])]) -> Vec<u8> {
+    let mut name = Vec::new();
+    for (oid, tag, value) in attributes {
+        let attribute = [tlv(0x06, oid), tlv(*tag, value)].concat();
+        name.extend(tlv(0x31, &tlv(0x30, &attribute)));
+    }
+    name
+}
+
+fn tlv(tag: u8, contents: &[u8]) -> Vec<u8> {
+    assert!(contents.len() < 0x80);
+    [&[tag, contents.len() as u8][..], contents].concat()
+}
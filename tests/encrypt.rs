@@ -0,0 +1,213 @@
+#![cfg(all(feature = "pem", feature = "std", feature = "zeroize"))]
+
+use std::cell::Cell;
+use std::fs;
+
+use rustls_pki_types::pem::{self, Item, PemObject};
+use rustls_pki_types::{
+    EncryptError, EncryptedPrivateKeyInfoDer, KeyEncryptor, PrivateKeyDer, PrivatePkcs1KeyDer,
+    PrivatePkcs8KeyDer, PrivateSec1KeyDer,
+};
+
+/// "Encrypts" a key by XORing it with the passphrase, wrapped as an `EncryptedPrivateKeyInfo`
+struct XorEncryptor;
+
+impl XorEncryptor {
+    /// Reverse [`XorEncryptor::encrypt()`] for the encrypted key in `der`
+    fn decrypt(der: &[u8], passphrase: &[u8]) -> Vec<u8> {
+        // SEQUENCE { SEQUENCE { OID }, OCTET STRING }
+        let (outer, rest) = split_tlv(der);
+        assert_eq!(outer, 0x30);
+        assert!(rest.is_empty());
+        let (tag, after_alg) = split_tlv(&der[header_len(der)..]);
+        assert_eq!(tag, 0x30);
+        let (tag, _) = split_tlv(after_alg);
+        assert_eq!(tag, 0x04);
+        let data = &after_alg[header_len(after_alg)..];
+        xor(data, passphrase)
+    }
+}
+
+impl KeyEncryptor for XorEncryptor {
+    fn encrypt(
+        &self,
+        key: &PrivatePkcs8KeyDer<'_>,
+        passphrase: &[u8],
+    ) -> Result<EncryptedPrivateKeyInfoDer<'static>, EncryptError> {
+        let algorithm = tlv(0x30, &tlv(0x06, &[0x2a, 0x03]));
+        let data = tlv(0x04, &xor(key.secret_pkcs8_der(), passphrase));
+        Ok(EncryptedPrivateKeyInfoDer::from(tlv(
+            0x30,
+            &[algorithm, data].concat(),
+        )))
+    }
+}
+
+/// Fails every time
+struct FailingEncryptor;
+
+impl KeyEncryptor for FailingEncryptor {
+    fn encrypt(
+        &self,
+        _: &PrivatePkcs8KeyDer<'_>,
+        _: &[u8],
+    ) -> Result<EncryptedPrivateKeyInfoDer<'static>, EncryptError> {
+        Err(EncryptError::Encryptor("no cipher".into()))
+    }
+}
+
+#[test]
+fn pkcs8_round_trip() {
+    let pkcs8 = fs::read("testdata/ed25519.pkcs8.der").unwrap();
+    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(pkcs8.clone()));
+    assert_eq!(round_trip(&key, b"hunter2"), pkcs8);
+}
+
+#[test]
+fn pkcs1_is_converted_to_pkcs8() {
+    let pkcs1 = fs::read("testdata/rsa2048.pkcs1.der").unwrap();
+    let key = PrivateKeyDer::from(PrivatePkcs1KeyDer::from(pkcs1));
+    let pkcs8 = fs::read("testdata/rsa2048.pkcs8.der").unwrap();
+    assert_eq!(round_trip(&key, b"correct horse"), pkcs8);
+}
+
+#[test]
+fn sec1_is_converted_to_pkcs8() {
+    let sec1 = fs::read("testdata/p256.sec1.der").unwrap();
+    let key = PrivateKeyDer::from(PrivateSec1KeyDer::from(sec1));
+    let pkcs8 = fs::read("testdata/p256.pkcs8.der").unwrap();
+    assert_eq!(round_trip(&key, b"battery staple"), pkcs8);
+}
+
+#[test]
+fn passphrase_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("key.pem");
+    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(
+        fs::read("testdata/ed25519.pkcs8.der").unwrap(),
+    ));
+
+    let err = key
+        .write_encrypted_pem_file(
+            &path,
+            || Err(EncryptError::Passphrase("cancelled".into())),
+            &XorEncryptor,
+        )
+        .unwrap_err();
+    assert!(matches!(err, EncryptError::Passphrase(_)));
+    assert!(!path.exists());
+}
+
+#[test]
+fn encryptor_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("key.pem");
+    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(
+        fs::read("testdata/ed25519.pkcs8.der").unwrap(),
+    ));
+
+    let err = key
+        .write_encrypted_pem_file(&path, || Ok(b"pass".to_vec()), &FailingEncryptor)
+        .unwrap_err();
+    assert!(matches!(err, EncryptError::Encryptor(_)));
+    assert_eq!(err.to_string(), "failed to encrypt private key: no cipher");
+    assert!(!path.exists());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn sec1_without_curve() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("key.pem");
+
+    // ECPrivateKey { version 1, privateKey }, with neither parameters nor public key.
+    let sec1 = tlv(
+        0x30,
+        &[&[0x02, 0x01, 0x01][..], &tlv(0x04, &[0x11; 32])].concat(),
+    );
+    let key = PrivateKeyDer::from(PrivateSec1KeyDer::from(sec1));
+
+    let asked = Cell::new(false);
+    let err = key
+        .write_encrypted_pem_file(
+            &path,
+            || {
+                asked.set(true);
+                Ok(b"pass".to_vec())
+            },
+            &XorEncryptor,
+        )
+        .unwrap_err();
+    assert!(matches!(err, EncryptError::MissingCurve));
+    assert!(!asked.get());
+    assert!(!path.exists());
+}
+
+/// Write `key` encrypted with `passphrase`, check the file, and return the decrypted key
+fn round_trip(key: &PrivateKeyDer<'_>, passphrase: &[u8]) -> Vec<u8> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("key.pem");
+    key.write_encrypted_pem_file(&path, || Ok(passphrase.to_vec()), &XorEncryptor)
+        .unwrap();
+
+    // The file holds the encrypted key and nothing else.
+    let contents = fs::read(&path).unwrap();
+    let items = pem::read_all(&contents)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(items.len(), 1);
+    let encrypted = match &items[0] {
+        Item::EncryptedPkcs8Key(encrypted) => encrypted.clone(),
+        other => panic!("unexpected item {other:?}"),
+    };
+    assert_eq!(
+        EncryptedPrivateKeyInfoDer::from_pem_file(&path).unwrap(),
+        encrypted
+    );
+    assert_eq!(contents, encrypted.to_pem().as_bytes());
+
+    let decrypted = XorEncryptor::decrypt(encrypted.as_ref(), passphrase);
+    assert_ne!(decrypted, encrypted.as_ref());
+    decrypted
+}
+
+fn xor(data: &[u8], passphrase: &[u8]) -> Vec<u8> {
+    data.iter()
+        .zip(passphrase.iter().cycle())
+        .map(|(d, p)| d ^ p)
+        .collect()
+}
+
+/// Encode `contents` with `tag`, as DER
+fn tlv(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    match contents.len() {
+        len if len < 0x80 => out.push(len as u8),
+        len if len < 0x100 => out.extend_from_slice(&[0x81, len as u8]),
+        len => {
+            let len = u16::try_from(len).unwrap().to_be_bytes();
+            out.extend_from_slice(&[0x82, len[0], len[1]]);
+        }
+    }
+    out.extend_from_slice(contents);
+    out
+}
+
+/// Split off the element at the start of `der`, returning its tag and what follows it
+fn split_tlv(der: &[u8]) -> (u8, &[u8]) {
+    let header = header_len(der);
+    let len = match der[1] {
+        len if len < 0x80 => len as usize,
+        0x81 => der[2] as usize,
+        0x82 => u16::from_be_bytes([der[2], der[3]]) as usize,
+        _ => panic!("unexpected length"),
+    };
+    (der[0], &der[header + len..])
+}
+
+fn header_len(der: &[u8]) -> usize {
+    match der[1] {
+        len if len < 0x80 => 2,
+        _ => 2 + (der[1] & 0x7f) as usize,
+    }
+}
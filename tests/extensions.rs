@@ -0,0 +1,129 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+
+use rustls_pki_types::{CertificateDer, ExtendedKeyUsage, KeyUsage};
+
+#[test]
+fn key_usage() {
+    let leaf = cert("leaf.der").key_usage().unwrap().unwrap();
+    assert!(leaf.is_critical());
+    assert_eq!(leaf.bits(), KeyUsage::DIGITAL_SIGNATURE);
+    assert!(leaf.contains(KeyUsage::DIGITAL_SIGNATURE));
+    assert!(!leaf.contains(KeyUsage::KEY_CERT_SIGN));
+
+    let root = cert("root.der").key_usage().unwrap().unwrap();
+    assert!(root.is_critical());
+    assert_eq!(root.bits(), KeyUsage::KEY_CERT_SIGN | KeyUsage::CRL_SIGN);
+    assert!(root.contains(KeyUsage::KEY_CERT_SIGN | KeyUsage::CRL_SIGN));
+    assert!(!root.contains(KeyUsage::KEY_CERT_SIGN | KeyUsage::DIGITAL_SIGNATURE));
+
+    // Nine bits take a second octet.
+    let all = cert("usages.der").key_usage().unwrap().unwrap();
+    assert_eq!(all.bits(), 0x1ff);
+    assert!(all.contains(KeyUsage::ENCIPHER_ONLY | KeyUsage::DECIPHER_ONLY));
+
+    for name in ["rsaleaf.der", "leaf-any-eku.der"] {
+        assert_eq!(cert(name).key_usage(), Ok(None), "{name}");
+    }
+}
+
+#[test]
+fn extended_key_usage() {
+    let leaf = cert("leaf.der");
+    let eku = leaf.extended_key_usage().unwrap().unwrap();
+    assert!(!eku.is_critical());
+    assert_eq!(
+        eku.iter().collect::<Result<Vec<_>, _>>().unwrap(),
+        [ExtendedKeyUsage::SERVER_AUTH, ExtendedKeyUsage::CLIENT_AUTH]
+    );
+    assert_eq!(eku.contains(ExtendedKeyUsage::SERVER_AUTH), Ok(true));
+    assert_eq!(eku.contains(ExtendedKeyUsage::CODE_SIGNING), Ok(false));
+    assert_eq!(eku.contains(ExtendedKeyUsage::ANY), Ok(false));
+
+    // anyExtendedKeyUsage is listed like any other purpose, and does not match others.
+    let any = cert("leaf-any-eku.der");
+    let eku = any.extended_key_usage().unwrap().unwrap();
+    assert_eq!(
+        eku.iter().collect::<Result<Vec<_>, _>>().unwrap(),
+        [ExtendedKeyUsage::ANY]
+    );
+    assert_eq!(eku.contains(ExtendedKeyUsage::ANY), Ok(true));
+    assert_eq!(eku.contains(ExtendedKeyUsage::SERVER_AUTH), Ok(false));
+
+    let usages = cert("usages.der");
+    let eku = usages.extended_key_usage().unwrap().unwrap();
+    assert!(eku.is_critical());
+    let purposes = (&eku).into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(
+        purposes,
+        [
+            ExtendedKeyUsage::CODE_SIGNING,
+            ExtendedKeyUsage::OCSP_SIGNING,
+            // 1.3.6.1.4.1.311.10.3.4, Microsoft's encrypting file system
+            &[0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x0a, 0x03, 0x04][..],
+        ]
+    );
+
+    for name in ["root.der", "int.der", "rsaleaf.der"] {
+        assert_eq!(cert(name).extended_key_usage(), Ok(None), "{name}");
+    }
+}
+
+#[test]
+fn basic_constraints() {
+    // A root without a path length constraint.
+    let root = cert("root.der").basic_constraints().unwrap().unwrap();
+    assert!(root.is_ca());
+    assert!(root.is_critical());
+    assert_eq!(root.path_len(), None);
+
+    let int = cert("int.der").basic_constraints().unwrap().unwrap();
+    assert!(int.is_ca());
+    assert_eq!(int.path_len(), Some(0));
+
+    // `cA` left out, as DER requires for `FALSE`.
+    let usages = cert("usages.der").basic_constraints().unwrap().unwrap();
+    assert!(!usages.is_ca());
+    assert!(!usages.is_critical());
+    assert_eq!(usages.path_len(), None);
+
+    for name in ["leaf.der", "rsaleaf.der"] {
+        assert_eq!(cert(name).basic_constraints(), Ok(None), "{name}");
+    }
+}
+
+#[test]
+fn long_path_len() {
+    let constraints = cert("pathlen200.der").basic_constraints().unwrap().unwrap();
+    assert_eq!(constraints.path_len(), Some(200));
+
+    // Saturated, rather than rejected.
+    let constraints = cert("pathlen300.der").basic_constraints().unwrap().unwrap();
+    assert!(constraints.is_ca());
+    assert_eq!(constraints.path_len(), Some(255));
+}
+
+#[test]
+fn invalid_path_len() {
+    let der = fs::read("testdata/pathlen300.der").unwrap();
+    // The encoded `INTEGER` 300, 0x012c, inside the extension.
+    let at = der
+        .windows(4)
+        .position(|w| w == [0x02, 0x02, 0x01, 0x2c])
+        .unwrap();
+
+    // Negative, and not minimally encoded. The certificate's signature is not checked.
+    for int in [[0x81, 0x2c], [0xff, 0xff], [0x00, 0x05]] {
+        let mut der = der.clone();
+        der[at + 2..at + 4].copy_from_slice(&int);
+        assert!(
+            CertificateDer::from(der).basic_constraints().is_err(),
+            "{int:02x?}"
+        );
+    }
+}
+
+fn cert(name: &str) -> CertificateDer<'static> {
+    CertificateDer::from(fs::read(format!("testdata/{name}")).unwrap())
+}
@@ -0,0 +1,52 @@
+use rustls_pki_types::{Fingerprint, InvalidFingerprint};
+
+#[test]
+fn equality() {
+    let digest = [0x5a; 32];
+    let fingerprint = Fingerprint::new(&digest).unwrap();
+    assert_eq!(fingerprint, Fingerprint::new(&digest).unwrap());
+
+    // A difference at either end, or anywhere between, is found.
+    for i in 0..digest.len() {
+        let mut other = digest;
+        other[i] ^= 0x01;
+        assert_ne!(fingerprint, Fingerprint::new(&other).unwrap(), "{i}");
+    }
+
+    // Fingerprints of different lengths differ, even when one is a prefix of the other.
+    assert_ne!(fingerprint, Fingerprint::new(&digest[..20]).unwrap());
+    assert_ne!(fingerprint, Fingerprint::new(&[0x5a; 64]).unwrap());
+}
+
+#[test]
+fn lengths() {
+    assert_eq!(Fingerprint::new(&[]), Err(InvalidFingerprint));
+    assert_eq!(Fingerprint::new(&[0; 65]), Err(InvalidFingerprint));
+    assert_eq!(Fingerprint::new(&[0; 64]).unwrap().as_ref(), [0; 64]);
+    assert_eq!(Fingerprint::new(&[7]).unwrap().as_ref(), [7]);
+}
+
+#[test]
+fn openssl_format() {
+    // From `openssl x509 -noout -fingerprint -sha256 -in testdata/ed25519.pem`
+    let openssl = "sha256 Fingerprint=1F:5C:53:9B:3C:DA:3E:5C:74:8D:70:AB:3C:A8:13:CC:\
+                   DD:2E:1E:1A:3C:FC:45:55:0C:12:D2:A0:5B:67:20:00";
+    let (_, hex) = openssl.split_once('=').unwrap();
+    let fingerprint = hex.parse::<Fingerprint>().unwrap();
+    assert_eq!(format!("{fingerprint:X}"), hex);
+
+    #[cfg(feature = "sha2")]
+    {
+        let der = std::fs::read("testdata/ed25519.der").unwrap();
+        let cert = rustls_pki_types::CertificateDer::from(&der[..]);
+        assert_eq!(cert.sha256_fingerprint(), fingerprint);
+    }
+
+    for invalid in ["", ":", "1F:", ":1F", "1F:5", "1F5C:53", "1g", "1F::5C"] {
+        assert_eq!(
+            invalid.parse::<Fingerprint>(),
+            Err(InvalidFingerprint),
+            "{invalid}"
+        );
+    }
+}
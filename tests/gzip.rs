@@ -0,0 +1,86 @@
+#![cfg(feature = "gzip")]
+
+use std::fs;
+
+use rustls_pki_types::{CertificateRevocationListDer, GzipError};
+
+#[test]
+fn decompress() {
+    let expected = fs::read("testdata/empty.crl.der").unwrap();
+    let crl =
+        CertificateRevocationListDer::from_gzip_file("testdata/empty.crl.der.gz", 1024).unwrap();
+    assert_eq!(crl.as_ref(), &expected[..]);
+
+    // The limit is inclusive.
+    let compressed = fs::read("testdata/empty.crl.der.gz").unwrap();
+    let crl = CertificateRevocationListDer::from_gzip_slice(&compressed, expected.len()).unwrap();
+    assert_eq!(crl.as_ref(), &expected[..]);
+    assert!(matches!(
+        CertificateRevocationListDer::from_gzip_slice(&compressed, expected.len() - 1),
+        Err(GzipError::TooLarge { .. })
+    ));
+}
+
+#[test]
+fn bomb_is_capped() {
+    // 64 MiB of zeros, compressed to 64 KiB.
+    let bomb = fs::read("testdata/bomb.gz").unwrap();
+    assert!(bomb.len() < 100 * 1024);
+
+    let err = CertificateRevocationListDer::from_gzip_slice(&bomb, 1024 * 1024).unwrap_err();
+    assert!(matches!(err, GzipError::TooLarge { max_len } if max_len == 1024 * 1024));
+    assert_eq!(
+        err.to_string(),
+        "decompressed CRL exceeds the limit of 1048576 bytes"
+    );
+
+    assert!(matches!(
+        CertificateRevocationListDer::from_gzip_file("testdata/bomb.gz", 0),
+        Err(GzipError::TooLarge { max_len: 0 })
+    ));
+}
+
+#[test]
+fn trailing_data() {
+    let compressed = fs::read("testdata/empty.crl.der.gz").unwrap();
+
+    let mut garbage = compressed.clone();
+    garbage.extend_from_slice(b"\0\0\0\0");
+    assert!(matches!(
+        CertificateRevocationListDer::from_gzip_slice(&garbage, 1024),
+        Err(GzipError::TrailingData)
+    ));
+
+    // `gunzip` would decompress this to two concatenated CRLs.
+    let two_members = [&compressed[..], &compressed[..]].concat();
+    assert!(matches!(
+        CertificateRevocationListDer::from_gzip_slice(&two_members, 1024),
+        Err(GzipError::TrailingData)
+    ));
+}
+
+#[test]
+fn corrupt() {
+    let compressed = fs::read("testdata/empty.crl.der.gz").unwrap();
+    for len in [0, 5, 10, compressed.len() / 2, compressed.len() - 1] {
+        assert!(
+            matches!(
+                CertificateRevocationListDer::from_gzip_slice(&compressed[..len], 1024),
+                Err(GzipError::Corrupt(_))
+            ),
+            "{len}"
+        );
+    }
+
+    // A flipped bit in the contents fails the CRC check.
+    let mut flipped = compressed.clone();
+    let middle = flipped.len() / 2;
+    flipped[middle] ^= 0x01;
+    assert!(CertificateRevocationListDer::from_gzip_slice(&flipped, 1024).is_err());
+
+    let der = fs::read("testdata/empty.crl.der").unwrap();
+    assert!(matches!(
+        CertificateRevocationListDer::from_gzip_slice(&der, 1024),
+        Err(GzipError::Corrupt(_))
+    ));
+}
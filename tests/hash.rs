@@ -0,0 +1,123 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use rustls_pki_types::{CertificateDer, CertificateRevocationListDer, Der};
+
+/// For random contents, owned and borrowed values are equal and hash equally, and hash as the
+/// bytes do
+#[test]
+fn owned_and_borrowed_agree() {
+    for bytes in inputs() {
+        check(Der::from(bytes.clone()), Der::from(&bytes[..]), &bytes);
+        check(
+            CertificateDer::from(bytes.clone()),
+            CertificateDer::from(&bytes[..]),
+            &bytes,
+        );
+        check(
+            CertificateRevocationListDer::from(bytes.clone()),
+            CertificateRevocationListDer::from(&bytes[..]),
+            &bytes,
+        );
+    }
+}
+
+/// Values are equal exactly when their bytes are, however they are stored
+#[test]
+fn eq_follows_contents() {
+    let inputs = inputs().collect::<Vec<_>>();
+    for (i, a) in inputs.iter().enumerate() {
+        for b in &inputs[i..] {
+            let same = a == b;
+            assert_eq!(Der::from(a.clone()) == Der::from(&b[..]), same);
+            assert_eq!(Der::from(&a[..]) == Der::from(b.clone()), same);
+            assert_eq!(
+                CertificateDer::from(a.clone()) == CertificateDer::from(&b[..]),
+                same
+            );
+            if same {
+                assert_eq!(hash(&Der::from(&a[..])), hash(&Der::from(b.clone())));
+            }
+        }
+    }
+}
+
+/// A set of owned and borrowed values holds each distinct content once
+#[test]
+fn set_deduplicates_across_storage() {
+    let inputs = inputs().collect::<Vec<_>>();
+    let distinct = inputs.iter().collect::<HashSet<_>>().len();
+    assert!(distinct < inputs.len());
+
+    let mut certs = HashSet::new();
+    for bytes in &inputs {
+        certs.insert(CertificateDer::from(&bytes[..]));
+        certs.insert(CertificateDer::from(bytes.clone()));
+    }
+    assert_eq!(certs.len(), distinct);
+    for bytes in &inputs {
+        assert!(certs.contains(&CertificateDer::from(bytes.clone())));
+        assert!(certs.contains(&bytes[..]));
+    }
+}
+
+/// The length is hashed along with the bytes, as for `[u8]`, so that the same bytes split
+/// differently between two values do not hash the same
+#[test]
+fn split_contents_differ() {
+    let bytes = b"\x30\x03\x02\x01\x05";
+    for at in 0..bytes.len() {
+        let (a, b) = bytes.split_at(at);
+        let (c, d) = bytes.split_at(at + 1);
+        let first = (Der::from(a), Der::from(b.to_vec()));
+        let second = (Der::from(c.to_vec()), Der::from(d));
+        assert_ne!(first, second);
+        assert_ne!(hash(&first), hash(&second));
+    }
+}
+
+fn check<T: Eq + Hash + AsRef<[u8]> + std::fmt::Debug>(owned: T, borrowed: T, bytes: &[u8]) {
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed, owned);
+    assert_eq!(owned.as_ref(), bytes);
+    assert_eq!(hash(&owned), hash(&borrowed));
+    assert_eq!(hash(&owned), hash(&bytes));
+}
+
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Random byte strings from a fixed seed, of lengths up to 64, followed by every prefix of
+/// one of them and some repeats
+fn inputs() -> impl Iterator<Item = Vec<u8>> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let random = (0..200)
+        .map(|_| {
+            let len = (next() % 65) as usize;
+            (0..len).map(|_| next() as u8).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let long = random
+        .iter()
+        .max_by_key(|bytes| bytes.len())
+        .unwrap()
+        .clone();
+    let prefixes = (0..=long.len()).map(move |len| long[..len].to_vec());
+    let repeats = random[..10].to_vec();
+
+    random.into_iter().chain(prefixes).chain(repeats)
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "std")]
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use rustls_pki_types::{InvalidIpNet, IpNet};
+
+#[test]
+fn zero_prefix_contains_everything() {
+    let v4 = IpNet::try_from("0.0.0.0/0").unwrap();
+    assert_eq!(v4.prefix_len(), 0);
+    for addr in ["0.0.0.0", "10.1.2.3", "255.255.255.255"] {
+        assert!(v4.contains(&addr.parse().unwrap()), "{addr}");
+    }
+    assert!(!v4.contains(&"::".parse().unwrap()));
+
+    let v6 = IpNet::try_from("::/0").unwrap();
+    for addr in [
+        "::",
+        "2001:db8::1",
+        "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+    ] {
+        assert!(v6.contains(&addr.parse().unwrap()), "{addr}");
+    }
+    assert!(!v6.contains(&"0.0.0.0".parse().unwrap()));
+
+    assert_eq!(
+        IpNet::try_from("10.0.0.0/0"),
+        Err(InvalidIpNet::HostBitsSet)
+    );
+    let truncated = IpNet::new_truncated("10.0.0.0".parse().unwrap(), 0).unwrap();
+    assert_eq!(truncated, v4);
+}
+
+#[test]
+fn full_prefix_contains_one_address() {
+    let v4 = IpNet::try_from("192.0.2.1/32").unwrap();
+    assert!(v4.contains(&"192.0.2.1".parse().unwrap()));
+    assert!(!v4.contains(&"192.0.2.0".parse().unwrap()));
+    assert!(!v4.contains(&"192.0.2.2".parse().unwrap()));
+    assert_eq!(v4.to_string(), "192.0.2.1/32");
+
+    let v6 = IpNet::try_from("2001:db8::1/128").unwrap();
+    assert!(v6.contains(&"2001:db8::1".parse().unwrap()));
+    assert!(!v6.contains(&"2001:db8::".parse().unwrap()));
+    assert!(!v6.contains(&"2001:db8::2".parse().unwrap()));
+    assert_eq!(v6.to_string(), "2001:db8::1/128");
+
+    // IPv4-mapped addresses are IPv6 addresses.
+    assert!(!v4.contains(&"::ffff:192.0.2.1".parse().unwrap()));
+
+    assert_eq!(
+        IpNet::try_from("192.0.2.1/33"),
+        Err(InvalidIpNet::PrefixTooLong)
+    );
+    assert_eq!(
+        IpNet::try_from("2001:db8::1/129"),
+        Err(InvalidIpNet::PrefixTooLong)
+    );
+    assert_eq!(
+        IpNet::try_from("192.0.2.1/999"),
+        Err(InvalidIpNet::PrefixTooLong)
+    );
+}
+
+#[test]
+fn misaligned_prefixes() {
+    let net = IpNet::try_from("10.0.0.0/9").unwrap();
+    assert!(net.contains(&"10.127.255.255".parse().unwrap()));
+    assert!(!net.contains(&"10.128.0.0".parse().unwrap()));
+    assert_eq!(
+        IpNet::try_from("10.128.0.0/8"),
+        Err(InvalidIpNet::HostBitsSet)
+    );
+
+    let net = IpNet::try_from("192.0.2.64/27").unwrap();
+    assert!(net.contains(&"192.0.2.95".parse().unwrap()));
+    assert!(!net.contains(&"192.0.2.96".parse().unwrap()));
+    assert!(!net.contains(&"192.0.2.63".parse().unwrap()));
+    assert_eq!(
+        IpNet::new_truncated("192.0.2.77".parse().unwrap(), 27),
+        Ok(net)
+    );
+
+    let net = IpNet::try_from("2001:db8:8000::/33").unwrap();
+    assert!(net.contains(&"2001:db8:ffff::1".parse().unwrap()));
+    assert!(!net.contains(&"2001:db8:7fff::1".parse().unwrap()));
+    assert_eq!(
+        IpNet::try_from("2001:db8::1/127"),
+        Err(InvalidIpNet::HostBitsSet)
+    );
+    assert_eq!(
+        IpNet::new_truncated("2001:db8::1".parse().unwrap(), 127)
+            .unwrap()
+            .to_string(),
+        "2001:db8::/127"
+    );
+}
+
+#[test]
+fn syntax() {
+    for bad in [
+        "10.0.0.0",
+        "10.0.0.0/",
+        "/8",
+        "10.0.0/8",
+        "10.0.0.0/+8",
+        "10.0.0.0/ 8",
+    ] {
+        assert_eq!(
+            IpNet::try_from(bad),
+            Err(InvalidIpNet::InvalidSyntax),
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn name_constraint_masks() {
+    for prefix_len in 0..=32 {
+        let mask = u32::MAX.checked_shl(32 - prefix_len).unwrap_or(0);
+        let addr = 0xc000_0200 & mask;
+        let bytes = [addr.to_be_bytes(), mask.to_be_bytes()].concat();
+        let net = IpNet::from_name_constraint(&bytes).unwrap();
+        assert_eq!(u32::from(net.prefix_len()), prefix_len);
+        assert_eq!(net.addr(), IpAddr::V4(Ipv4Addr::from(addr)));
+    }
+
+    for prefix_len in 0..=128 {
+        let mask = u128::MAX.checked_shl(128 - prefix_len).unwrap_or(0);
+        let addr = 0x2001_0db8_0000_0000_0000_0000_0000_0001 & mask;
+        let bytes = [addr.to_be_bytes(), mask.to_be_bytes()].concat();
+        let net = IpNet::from_name_constraint(&bytes).unwrap();
+        assert_eq!(u32::from(net.prefix_len()), prefix_len);
+        assert_eq!(net.addr(), IpAddr::V6(Ipv6Addr::from(addr)));
+    }
+}
+
+#[test]
+fn invalid_name_constraints() {
+    // 192.0.2.0 with a mask of 255.255.255.0, as it appears in a certificate.
+    let valid = [192, 0, 2, 0, 255, 255, 255, 0];
+    assert_eq!(
+        IpNet::from_name_constraint(&valid),
+        IpNet::try_from("192.0.2.0/24")
+    );
+
+    for len in [0, 4, 7, 9, 16, 31, 33, 64] {
+        assert_eq!(
+            IpNet::from_name_constraint(&vec![0; len]),
+            Err(InvalidIpNet::InvalidLength),
+            "{len}"
+        );
+    }
+
+    for mask in [
+        [255, 0, 255, 0],
+        [0, 0, 0, 1],
+        [255, 255, 255, 254 ^ 0x10],
+        [0x7f, 0xff, 0xff, 0xff],
+    ] {
+        let bytes = [[0; 4], mask].concat();
+        assert_eq!(
+            IpNet::from_name_constraint(&bytes),
+            Err(InvalidIpNet::NonContiguousMask),
+            "{mask:?}"
+        );
+    }
+
+    let mut v6 = [0; 32];
+    v6[16..24].fill(0xff);
+    v6[31] = 1;
+    assert_eq!(
+        IpNet::from_name_constraint(&v6),
+        Err(InvalidIpNet::NonContiguousMask)
+    );
+
+    assert_eq!(
+        IpNet::from_name_constraint(&[192, 0, 2, 1, 255, 255, 255, 0]),
+        Err(InvalidIpNet::HostBitsSet)
+    );
+}
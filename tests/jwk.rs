@@ -0,0 +1,142 @@
+#![cfg(feature = "jwk")]
+
+use std::fs;
+
+use rustls_pki_types::{CertificateDer, Jwk, JwkError, SubjectPublicKeyInfoDer};
+
+// RFC 7517 appendix A.1
+const RFC7517_N: &str = "0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw";
+
+#[test]
+fn rfc7517_rsa() {
+    let jwk = to_jwk("jwk/rfc7517-rsa.spki.der").unwrap();
+    assert_eq!(
+        jwk,
+        Jwk::Rsa {
+            n: RFC7517_N.to_owned(),
+            e: "AQAB".to_owned(),
+        }
+    );
+
+    // The canonical JSON of RFC 7638 section 3.1, which is hashed for the key's thumbprint.
+    assert_eq!(
+        serde_json::to_string(&jwk).unwrap(),
+        format!(r#"{{"e":"AQAB","kty":"RSA","n":"{RFC7517_N}"}}"#)
+    );
+}
+
+#[test]
+fn rfc7517_p256() {
+    let jwk = to_jwk("jwk/rfc7517-p256.spki.der").unwrap();
+    assert_eq!(
+        serde_json::to_string(&jwk).unwrap(),
+        concat!(
+            r#"{"crv":"P-256","kty":"EC","#,
+            r#""x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","#,
+            r#""y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#
+        )
+    );
+}
+
+#[test]
+fn rfc8037_ed25519() {
+    let jwk = to_jwk("jwk/rfc8037-ed25519.spki.der").unwrap();
+    assert_eq!(
+        serde_json::to_string(&jwk).unwrap(),
+        r#"{"crv":"Ed25519","kty":"OKP","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#
+    );
+}
+
+#[test]
+fn leading_zero() {
+    // The modulus is 256 bytes with its top bit set, so its DER `INTEGER` has a leading zero
+    // octet. Kept, it would make the base64url one character longer, starting with "AN".
+    let der = fs::read(testdata("jwk/rfc7517-rsa.spki.der")).unwrap();
+    let spki = SubjectPublicKeyInfoDer::from(&der[..]);
+    let key = spki.subject_public_key().unwrap();
+    assert_eq!(&key[8..10], &[0x00, 0xd2]);
+
+    match to_jwk("jwk/rfc7517-rsa.spki.der").unwrap() {
+        Jwk::Rsa { n, .. } => assert_eq!(n.len(), 342),
+        jwk => panic!("unexpected {jwk:?}"),
+    }
+}
+
+#[test]
+fn fixture_keys() {
+    // Compared with the JWKs exported by Node.js, as JSON values so member order doesn't matter.
+    for (cert, expected) in [
+        ("rsa2048.der", "jwk/rsa2048.json"),
+        ("p256.der", "jwk/p256.json"),
+        ("ed25519.der", "jwk/ed25519.json"),
+    ] {
+        let der = CertificateDer::from(fs::read(testdata(cert)).unwrap());
+        let spans = der.spans().unwrap();
+        let jwk = spans.subject_public_key_info().to_jwk().unwrap();
+        assert_eq!(json(&jwk), expected_json(expected), "{cert}");
+    }
+
+    let jwk = to_jwk("jwk/p384.spki.der").unwrap();
+    assert!(matches!(jwk, Jwk::Ec { crv: "P-384", .. }));
+    assert_eq!(json(&jwk), expected_json("jwk/p384.json"));
+}
+
+#[test]
+fn unsupported_algorithm() {
+    for (file, dotted) in [
+        ("alg-id/ed448.spki.der", "1.3.101.113"),
+        ("alg-id/x448.spki.der", "1.3.101.111"),
+    ] {
+        let err = to_jwk(file).unwrap_err();
+        assert!(matches!(err, JwkError::UnsupportedAlgorithm(_)), "{file}");
+        assert_eq!(
+            err.to_string(),
+            format!("unsupported public key algorithm {dotted}")
+        );
+    }
+}
+
+#[test]
+fn unsupported_curve() {
+    let err = to_jwk("alg-id/brainpoolP256r1.spki.der").unwrap_err();
+    assert_eq!(
+        err,
+        JwkError::UnsupportedCurve(vec![0x2b, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x07])
+    );
+    assert_eq!(
+        err.to_string(),
+        "unsupported elliptic curve 1.3.36.3.3.2.8.1.1.7"
+    );
+}
+
+#[test]
+fn invalid_key() {
+    // Truncate the P-256 point, and fix up the lengths of the BIT STRING and the outer SEQUENCE.
+    let mut der = fs::read(testdata("jwk/rfc7517-p256.spki.der")).unwrap();
+    der.pop();
+    der[1] -= 1;
+    der[24] -= 1;
+    let spki = SubjectPublicKeyInfoDer::from(&der[..]);
+    assert_eq!(spki.to_jwk(), Err(JwkError::InvalidKey));
+    assert_eq!(
+        spki.to_jwk().unwrap_err().to_string(),
+        "malformed public key"
+    );
+}
+
+fn to_jwk(name: &str) -> Result<Jwk, JwkError> {
+    let der = fs::read(testdata(name)).unwrap();
+    SubjectPublicKeyInfoDer::from(&der[..]).to_jwk()
+}
+
+fn json(jwk: &Jwk) -> serde_json::Value {
+    serde_json::to_value(jwk).unwrap()
+}
+
+fn expected_json(name: &str) -> serde_json::Value {
+    serde_json::from_slice(&fs::read(testdata(name)).unwrap()).unwrap()
+}
+
+fn testdata(name: &str) -> String {
+    format!("testdata/{name}")
+}
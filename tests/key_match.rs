@@ -0,0 +1,130 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+
+use rustls_pki_types::{
+    CertificateDer, KeyMatch, PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer,
+    PrivateSec1KeyDer,
+};
+
+#[test]
+fn ed25519_with_public_key() {
+    let ed25519 = cert("ed25519.der");
+    let key = ed25519_v2(&public_key(&ed25519));
+    assert_eq!(key.matches_certificate(&ed25519), Ok(KeyMatch::Matches));
+
+    // The leaf certificate has an Ed25519 key too, but another one.
+    let key = ed25519_v2(&public_key(&cert("leaf.der")));
+    assert_eq!(key.matches_certificate(&ed25519), Ok(KeyMatch::Mismatch));
+}
+
+#[test]
+fn ed25519_without_public_key() {
+    let key = pkcs8("ed25519.pkcs8.der");
+    assert_eq!(
+        key.matches_certificate(&cert("ed25519.der")),
+        Ok(KeyMatch::Indeterminate)
+    );
+    assert_eq!(
+        key.matches_certificate(&cert("leaf.der")),
+        Ok(KeyMatch::Indeterminate)
+    );
+    assert_eq!(
+        key.matches_certificate(&cert("rsa2048.der")),
+        Ok(KeyMatch::Mismatch)
+    );
+}
+
+#[test]
+fn rsa() {
+    let pkcs1 = PrivateKeyDer::from(PrivatePkcs1KeyDer::from(
+        fs::read("testdata/rsa2048.pkcs1.der").unwrap(),
+    ));
+    let pkcs8 = pkcs8("rsa2048.pkcs8.der");
+    for key in [&pkcs1, &pkcs8] {
+        assert_eq!(
+            key.matches_certificate(&cert("rsa2048.der")),
+            Ok(KeyMatch::Indeterminate)
+        );
+        assert_eq!(
+            key.matches_certificate(&cert("rsaleaf.der")),
+            Ok(KeyMatch::Indeterminate)
+        );
+        assert_eq!(
+            key.matches_certificate(&cert("ed25519.der")),
+            Ok(KeyMatch::Mismatch)
+        );
+    }
+}
+
+#[test]
+fn p256() {
+    let sec1 = fs::read("testdata/p256.sec1.der").unwrap();
+    let pkcs8 = pkcs8("p256.pkcs8.der");
+    for key in [
+        &PrivateKeyDer::from(PrivateSec1KeyDer::from(sec1.clone())),
+        &pkcs8,
+    ] {
+        assert_eq!(
+            key.matches_certificate(&cert("p256.der")),
+            Ok(KeyMatch::Matches)
+        );
+        // Another P-256 key.
+        assert_eq!(
+            key.matches_certificate(&cert("int.der")),
+            Ok(KeyMatch::Mismatch)
+        );
+        assert_eq!(
+            key.matches_certificate(&cert("rsa2048.der")),
+            Ok(KeyMatch::Mismatch)
+        );
+    }
+
+    // The same key with its trailing `[1] publicKey` removed, as SEC1 allows.
+    let mut without_public_key = sec1[..51].to_vec();
+    without_public_key[1] = 49;
+    let key = PrivateKeyDer::from(PrivateSec1KeyDer::from(without_public_key));
+    assert_eq!(
+        key.matches_certificate(&cert("p256.der")),
+        Ok(KeyMatch::Indeterminate)
+    );
+    assert_eq!(
+        key.matches_certificate(&cert("ed25519.der")),
+        Ok(KeyMatch::Mismatch)
+    );
+}
+
+#[test]
+fn invalid_certificate() {
+    let key = pkcs8("ed25519.pkcs8.der");
+    let cert = CertificateDer::from(vec![0x30, 0x00]);
+    assert!(key.matches_certificate(&cert).is_err());
+}
+
+/// A PKCS#8 v2 Ed25519 key, holding the private key of `ed25519.pkcs8.der` and `public_key`
+///
+/// OpenSSL only writes v1 keys, without their public key.
+fn ed25519_v2(public_key: &[u8]) -> PrivateKeyDer<'static> {
+    let mut der = fs::read("testdata/ed25519.pkcs8.der").unwrap();
+    assert_eq!(der[..5], [0x30, 0x2e, 0x02, 0x01, 0x00]);
+    der[1] += 35;
+    der[4] = 1;
+    der.extend_from_slice(&[0x81, 0x21, 0x00]);
+    der.extend_from_slice(public_key);
+    PrivateKeyDer::from(PrivatePkcs8KeyDer::from(der))
+}
+
+fn public_key(cert: &CertificateDer<'_>) -> Vec<u8> {
+    let spans = cert.spans().unwrap();
+    let spki = spans.subject_public_key_info();
+    spki.subject_public_key().unwrap().to_vec()
+}
+
+fn pkcs8(name: &str) -> PrivateKeyDer<'static> {
+    let der = fs::read(format!("testdata/{name}")).unwrap();
+    PrivateKeyDer::from(PrivatePkcs8KeyDer::from(der))
+}
+
+fn cert(name: &str) -> CertificateDer<'static> {
+    CertificateDer::from(fs::read(format!("testdata/{name}")).unwrap())
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "std")]
+
+use std::fs;
+use std::path::Path;
+
+use rustls_pki_types::{CertificateDer, LoadError};
+
+#[test]
+fn dir_with_pem_der_and_garbage() {
+    let dir = tempfile::tempdir().unwrap();
+    let pem = fs::read("testdata/ed25519.pem").unwrap();
+    let trusted = fs::read("testdata/ed25519.trusted.pem").unwrap();
+    fs::write(dir.path().join("a.pem"), [&pem[..], &trusted[..]].concat()).unwrap();
+    fs::copy("testdata/ed25519.der", dir.path().join("b.der")).unwrap();
+    fs::write(dir.path().join("c.txt"), "not a certificate").unwrap();
+    fs::create_dir(dir.path().join("d")).unwrap();
+    fs::write(
+        dir.path().join("e.pem"),
+        "-----BEGIN CERTIFICATE-----\n!!!!\n",
+    )
+    .unwrap();
+
+    let results = CertificateDer::from_dir_iter(dir.path())
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 4);
+
+    // The `TRUSTED CERTIFICATE` section is read, without its trust settings.
+    let expected = cert();
+    assert_eq!(
+        results[0].as_ref().unwrap(),
+        &[expected.clone(), expected.clone()]
+    );
+    assert_eq!(results[1].as_ref().unwrap(), &[expected]);
+    assert!(matches!(
+        &results[2],
+        Err(LoadError::UnrecognizedFormat { path }) if path.ends_with("c.txt")
+    ));
+    assert!(matches!(
+        &results[3],
+        Err(LoadError::InvalidPem { path }) if path.ends_with("e.pem")
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn dir_with_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let dir = tempfile::tempdir().unwrap();
+    fs::copy("testdata/ed25519.pem", dir.path().join("ed25519.pem")).unwrap();
+    // Hashed names as written by `openssl rehash`, pointing at the same file.
+    symlink("ed25519.pem", dir.path().join("0a1b2c3d.0")).unwrap();
+    symlink(
+        dir.path().join("ed25519.pem"),
+        dir.path().join("0a1b2c3d.1"),
+    )
+    .unwrap();
+    // A link to a file outside the directory is followed.
+    let other = tempfile::tempdir().unwrap();
+    fs::copy("testdata/ed25519.der", other.path().join("cert.der")).unwrap();
+    symlink(other.path().join("cert.der"), dir.path().join("1a2b3c4d.0")).unwrap();
+    // Links to directories are skipped, and dangling links are errors.
+    symlink(other.path(), dir.path().join("linked-dir")).unwrap();
+    symlink("missing.pem", dir.path().join("dangling.0")).unwrap();
+
+    let results = CertificateDer::from_dir_iter(dir.path())
+        .unwrap()
+        .collect::<Vec<_>>();
+    let paths = ["0a1b2c3d.0", "1a2b3c4d.0", "dangling.0"];
+    assert_eq!(results.len(), paths.len());
+    assert_eq!(results[0].as_ref().unwrap(), &[cert()]);
+    assert_eq!(results[1].as_ref().unwrap(), &[cert()]);
+    assert!(matches!(
+        &results[2],
+        Err(LoadError::Io { path, .. }) if path.ends_with(paths[2])
+    ));
+}
+
+#[test]
+fn missing_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing");
+    assert!(matches!(
+        CertificateDer::from_dir_iter(&missing),
+        Err(LoadError::Io { path, .. }) if path == Path::new(&missing)
+    ));
+}
+
+fn cert() -> CertificateDer<'static> {
+    CertificateDer::from(fs::read("testdata/ed25519.der").unwrap())
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "alloc")]
+
+use std::mem;
+
+use rustls_pki_types::{
+    CertificateChainDer, CertificateDer, Der, MemoryUsage, PrivateKeyDer, PrivatePkcs8KeyDer,
+    TrustAnchor,
+};
+
+#[test]
+fn owned_and_borrowed() {
+    // The capacity counts, not just the length.
+    let mut bytes = Vec::with_capacity(16);
+    bytes.extend([0x30, 0x01, 0x00]);
+    let owned = CertificateDer::from(bytes);
+    assert_eq!(owned.approx_heap_size(), 16);
+    assert_eq!(owned.as_borrowed().approx_heap_size(), 0);
+
+    let bytes = [0x30, 0x01, 0x00];
+    let borrowed = CertificateDer::from(&bytes[..]);
+    assert_eq!(borrowed.approx_heap_size(), 0);
+    assert_eq!(Der::from_slice(&bytes).approx_heap_size(), 0);
+    assert_eq!(borrowed.into_owned().approx_heap_size(), 3);
+
+    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(vec![0x30; 40]));
+    assert_eq!(key.approx_heap_size(), 40);
+    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(&[0x30; 40][..]));
+    assert_eq!(key.approx_heap_size(), 0);
+}
+
+#[test]
+fn chain_is_sum_of_certificates() {
+    let intermediate = [0x30; 20];
+    let mut chain = CertificateChainDer::from(Vec::with_capacity(4));
+    chain.push(CertificateDer::from(vec![0x30; 10]));
+    chain.push(CertificateDer::from(&intermediate[..]));
+    chain.push(CertificateDer::from(vec![0x30; 30]));
+
+    let certs: usize = chain.iter().map(|cert| cert.approx_heap_size()).sum();
+    assert_eq!(certs, 10 + 30);
+    assert_eq!(
+        chain.approx_heap_size(),
+        4 * mem::size_of::<CertificateDer<'_>>() + certs
+    );
+
+    let empty = CertificateChainDer::from(Vec::new());
+    assert_eq!(empty.approx_heap_size(), 0);
+}
+
+#[test]
+fn trust_anchor_is_sum_of_fields() {
+    let spki = [0x30; 20];
+    let anchor = TrustAnchor {
+        subject: Der::from(vec![0x30; 10]),
+        subject_public_key_info: Der::from_slice(&spki),
+        name_constraints: Some(Der::from(vec![0x30; 30])),
+    };
+
+    assert_eq!(
+        anchor.approx_heap_size(),
+        anchor.subject.approx_heap_size()
+            + anchor.subject_public_key_info.approx_heap_size()
+            + anchor.name_constraints.approx_heap_size()
+    );
+    assert_eq!(anchor.approx_heap_size(), 10 + 30);
+    assert_eq!(anchor.to_owned().approx_heap_size(), 10 + 20 + 30);
+    assert_eq!(
+        TrustAnchor::from_parts(&spki, &spki, Some(&spki)).approx_heap_size(),
+        0
+    );
+}
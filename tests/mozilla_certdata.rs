@@ -0,0 +1,122 @@
+#![cfg(feature = "mozilla-certdata")]
+
+use std::fs;
+use std::time::Duration;
+
+use rustls_pki_types::mozilla_certdata::{parse, CertdataError, ServerAuthTrust};
+use rustls_pki_types::{CertificateDer, UnixTime};
+
+#[test]
+fn excerpt() {
+    let certdata = fs::read_to_string("testdata/certdata.txt").unwrap();
+    let anchors = parse(&certdata).unwrap();
+
+    // The trust object without a certificate is not returned.
+    assert_eq!(anchors.len(), 3);
+
+    let (isrg, trust) = &anchors[0];
+    assert!(contains(&isrg.subject, b"ISRG Root X1"));
+    assert_eq!(trust.server_auth, ServerAuthTrust::Trusted);
+    assert_eq!(trust.server_distrust_after, None);
+    assert_eq!(isrg.name_constraints, None);
+
+    let (entrust, trust) = &anchors[1];
+    assert!(contains(
+        &entrust.subject,
+        b"Entrust Root Certification Authority - G2"
+    ));
+    assert_eq!(trust.server_auth, ServerAuthTrust::Trusted);
+    // 2024-11-30T23:59:59Z
+    assert_eq!(
+        trust.server_distrust_after,
+        Some(UnixTime::since_unix_epoch(Duration::from_secs(
+            1_733_011_199
+        )))
+    );
+
+    // A distrusted certificate is kept, for policy to act on.
+    let (root, trust) = &anchors[2];
+    assert_eq!(trust.server_auth, ServerAuthTrust::Distrusted);
+    assert_eq!(trust.server_distrust_after, None);
+    let der = CertificateDer::from(fs::read("testdata/root.der").unwrap());
+    let spans = der.spans().unwrap();
+    assert_eq!(root.subject.as_ref(), spans.subject());
+    let spki = spans.subject_public_key_info();
+    assert!(spki.ends_with(&root.subject_public_key_info));
+    assert_eq!(spki.len(), root.subject_public_key_info.len() + 4);
+}
+
+#[test]
+fn without_trust_object() {
+    // Only the certificate object of the first entry.
+    let certdata = fs::read_to_string("testdata/certdata.txt").unwrap();
+    let end = certdata.find("# Trust for \"ISRG Root X1\"").unwrap();
+    let anchors = parse(&certdata[..end]).unwrap();
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].1.server_auth, ServerAuthTrust::MustVerify);
+}
+
+#[test]
+fn truncated() {
+    let certdata = fs::read_to_string("testdata/certdata.txt").unwrap();
+
+    // Cut after a few lines of the `CKA_VALUE` of the first certificate, which is left
+    // without its `END`.
+    let value = certdata.find("CKA_VALUE MULTILINE_OCTAL").unwrap();
+    let line = certdata[..value].lines().count() + 1;
+    let cut = value + 200 + certdata[value + 200..].find('\n').unwrap() + 1;
+    assert_eq!(
+        parse(&certdata[..cut]),
+        Err(CertdataError::InvalidSyntax { line })
+    );
+
+    // Cut in the middle of a line of octets.
+    assert_eq!(
+        parse(&certdata[..cut - 3]),
+        Err(CertdataError::InvalidSyntax {
+            line: certdata[..cut].lines().count()
+        })
+    );
+
+    // Cut before the `CKA_VALUE`.
+    assert_eq!(
+        parse(&certdata[..value]),
+        Err(CertdataError::MissingAttribute("CKA_VALUE"))
+    );
+}
+
+#[test]
+fn invalid() {
+    let certdata = fs::read_to_string("testdata/certdata.txt").unwrap();
+
+    // A distrust date that is not a `UTCTime`.
+    let bad = certdata.replacen(
+        "\\062\\064\\061\\061\\063\\060\\062\\063\\065\\071\\065\\071\\132",
+        "\\062\\064\\061\\061\\063\\060\\062\\063\\065\\071\\065\\071",
+        1,
+    );
+    assert!(matches!(
+        parse(&bad),
+        Err(CertdataError::InvalidDistrustAfter { .. })
+    ));
+
+    // An octal escape with too few digits.
+    let bad = certdata.replacen("\\060\\117", "\\60\\117", 1);
+    assert!(matches!(
+        parse(&bad),
+        Err(CertdataError::InvalidSyntax { .. })
+    ));
+
+    // A certificate that is not DER.
+    let value = certdata.find("CKA_VALUE MULTILINE_OCTAL\n").unwrap();
+    let mut bad = certdata.clone();
+    bad.replace_range(value + 26..value + 30, "\\061");
+    assert!(matches!(
+        parse(&bad),
+        Err(CertdataError::InvalidCertificate { .. })
+    ));
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
@@ -0,0 +1,114 @@
+#![cfg(all(feature = "pem", feature = "std"))]
+
+//! Tests of `load_native_cert_sources()`.
+//!
+//! The environment is shared by the whole process, so everything is checked in a single test,
+//! and this file holds no others.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{load_native_cert_sources, CertificateDer, LoadError};
+
+#[test]
+fn environment_variables() {
+    let chain = CertificateDer::pem_file_iter("testdata/chain.pem")
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(chain.len(), 3);
+    let first = tempfile::tempdir().unwrap();
+    fs::copy("testdata/root.der", first.path().join("a.der")).unwrap();
+    fs::copy("testdata/root2.der", first.path().join("b.der")).unwrap();
+    let second = tempfile::tempdir().unwrap();
+    fs::copy("testdata/ed25519.pem", second.path().join("a.pem")).unwrap();
+    fs::write(second.path().join("b.txt"), "not a certificate").unwrap();
+
+    let root = cert("root.der");
+    let root2 = cert("root2.der");
+    let ed25519 = cert("ed25519.der");
+
+    // Neither variable set, or both empty.
+    env::remove_var("SSL_CERT_FILE");
+    env::remove_var("SSL_CERT_DIR");
+    assert!(matches!(
+        load_native_cert_sources(),
+        Err(LoadError::NoSourcesConfigured)
+    ));
+    env::set_var("SSL_CERT_FILE", "");
+    env::set_var("SSL_CERT_DIR", "");
+    assert!(matches!(
+        load_native_cert_sources(),
+        Err(LoadError::NoSourcesConfigured)
+    ));
+
+    // Only the file.
+    env::set_var("SSL_CERT_FILE", "testdata/chain.pem");
+    let sources = load_native_cert_sources().unwrap();
+    assert_eq!(sources.certs, chain);
+    assert_eq!(
+        sources.file.as_deref(),
+        Some(Path::new("testdata/chain.pem"))
+    );
+    assert!(sources.dirs.is_empty());
+    assert!(sources.errors.is_empty());
+
+    // A DER-encoded file.
+    env::set_var("SSL_CERT_FILE", "testdata/root.der");
+    let sources = load_native_cert_sources().unwrap();
+    assert_eq!(sources.certs, std::slice::from_ref(&root));
+
+    // Only the directories, in the order given.
+    env::remove_var("SSL_CERT_FILE");
+    let dirs = env::join_paths([second.path(), first.path()]).unwrap();
+    env::set_var("SSL_CERT_DIR", &dirs);
+    let sources = load_native_cert_sources().unwrap();
+    assert_eq!(
+        sources.certs,
+        [ed25519.clone(), root.clone(), root2.clone()]
+    );
+    assert_eq!(sources.file, None);
+    assert_eq!(sources.dirs, [second.path(), first.path()]);
+    assert_eq!(sources.errors.len(), 1);
+    assert!(matches!(
+        &sources.errors[0],
+        LoadError::UnrecognizedFormat { path } if path.ends_with("b.txt")
+    ));
+
+    // Both, with the file first.
+    env::set_var("SSL_CERT_FILE", "testdata/chain.pem");
+    env::set_var("SSL_CERT_DIR", first.path());
+    let sources = load_native_cert_sources().unwrap();
+    let expected = chain
+        .iter()
+        .cloned()
+        .chain([root, root2])
+        .collect::<Vec<_>>();
+    assert_eq!(sources.certs, expected);
+    assert_eq!(
+        sources.file.as_deref(),
+        Some(Path::new("testdata/chain.pem"))
+    );
+    assert_eq!(sources.dirs, [first.path()]);
+
+    // Sources that cannot be read.
+    env::set_var("SSL_CERT_FILE", "testdata/missing.pem");
+    assert!(matches!(
+        load_native_cert_sources(),
+        Err(LoadError::Io { path, .. }) if path.ends_with("missing.pem")
+    ));
+    env::remove_var("SSL_CERT_FILE");
+    env::set_var("SSL_CERT_DIR", first.path().join("missing"));
+    assert!(matches!(
+        load_native_cert_sources(),
+        Err(LoadError::Io { .. })
+    ));
+
+    env::remove_var("SSL_CERT_DIR");
+}
+
+fn cert(name: &str) -> CertificateDer<'static> {
+    CertificateDer::from(fs::read(format!("testdata/{name}")).unwrap())
+}
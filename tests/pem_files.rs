@@ -0,0 +1,151 @@
+#![cfg(all(feature = "pem", feature = "std"))]
+
+use std::fs;
+
+use rustls_pki_types::pem::{self, Error, Item, PemObject};
+use rustls_pki_types::{CertificateDer, PrivateKeyDer};
+
+#[test]
+fn invalid_base64_line() {
+    for name in ["corrupt.pem", "corrupt-crlf.pem"] {
+        let pem = read(name);
+        let mut items = pem::read_all(&pem);
+        assert!(
+            matches!(items.next(), Some(Ok(Item::X509Certificate(_)))),
+            "{name}"
+        );
+
+        let err = items.next().unwrap().unwrap_err();
+        assert!(
+            matches!(err, Error::InvalidBase64 { line: 20 }),
+            "{name}: {err:?}"
+        );
+        assert_eq!(err.to_string(), "invalid base64 on line 20");
+
+        let err = CertificateDer::pem_file_iter(format!("testdata/pem/{name}"))
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        assert!(
+            matches!(err, Error::InvalidBase64 { line: 20 }),
+            "{name}: {err:?}"
+        );
+    }
+}
+
+#[test]
+fn crlf() {
+    assert_eq!(certificates("crlf.pem"), ders(&["leaf", "int", "root"]));
+}
+
+#[test]
+fn no_final_newline() {
+    assert_eq!(certificates("no-final-newline.pem"), ders(&["ed25519"]));
+    let cert = CertificateDer::from_pem_file("testdata/pem/no-final-newline.pem").unwrap();
+    assert_eq!(
+        cert.as_ref(),
+        &fs::read("testdata/ed25519.der").unwrap()[..]
+    );
+}
+
+#[test]
+fn text_before_sections() {
+    assert_eq!(certificates("text.pem"), ders(&["leaf", "int", "root"]));
+}
+
+#[test]
+fn dek_info() {
+    let pem = read("rsa-dek-info.pem");
+    let items = pem::read_all(&pem).collect::<Result<Vec<_>, _>>().unwrap();
+    let (label, headers, contents) = match &items[..] {
+        [Item::EncryptedLegacy {
+            label,
+            headers,
+            contents,
+        }] => (label, headers, contents),
+        items => panic!("unexpected {items:?}"),
+    };
+
+    assert_eq!(label, "RSA PRIVATE KEY");
+    assert_eq!(headers.len(), 2);
+    assert_eq!(
+        headers[0],
+        ("Proc-Type".to_owned(), "4,ENCRYPTED".to_owned())
+    );
+    assert_eq!(headers[1].0, "DEK-Info");
+    let (cipher, iv) = headers[1].1.split_once(',').unwrap();
+    assert_eq!(cipher, "AES-128-CBC");
+    assert_eq!(iv.len(), 32);
+    assert!(iv.bytes().all(|b| b.is_ascii_hexdigit()));
+
+    // The contents are the padded PKCS#1 key, still encrypted.
+    let pkcs1 = fs::read("testdata/rsa2048.pkcs1.der").unwrap();
+    assert_eq!(contents.len(), (pkcs1.len() / 16 + 1) * 16);
+    assert!(!contents.starts_with(&pkcs1[..4]));
+
+    let err = PrivateKeyDer::from_pem_file("testdata/pem/rsa-dek-info.pem").unwrap_err();
+    assert!(matches!(err, Error::EncryptedKeyUnsupported), "{err:?}");
+}
+
+#[test]
+fn unsupported_keys() {
+    let err = PrivateKeyDer::from_pem_file("testdata/pem/openssh.pem").unwrap_err();
+    assert!(matches!(err, Error::OpenSshKeyUnsupported), "{err:?}");
+    let err = PrivateKeyDer::from_pem_file("testdata/pem/encrypted-pkcs8.pem").unwrap_err();
+    assert!(matches!(err, Error::EncryptedKeyUnsupported), "{err:?}");
+
+    // A usable key is returned even if it comes after them.
+    for name in ["openssh.pem", "encrypted-pkcs8.pem"] {
+        let pem = [read(name), read("keys.pem")].concat();
+        let key = PrivateKeyDer::from_pem_slice(&pem).unwrap();
+        assert!(matches!(key, PrivateKeyDer::Pkcs1(_)), "{name}");
+    }
+
+    // The first unusable key is reported.
+    let pem = [read("encrypted-pkcs8.pem"), read("openssh.pem")].concat();
+    let err = PrivateKeyDer::from_pem_slice(&pem).unwrap_err();
+    assert!(matches!(err, Error::EncryptedKeyUnsupported), "{err:?}");
+    assert_eq!(
+        err.to_string(),
+        "encrypted private keys are not supported; remove the passphrase, for example with \
+         `openssl pkey -in <file> -out <new file>`"
+    );
+
+    let pem = [read("openssh.pem"), read("encrypted-pkcs8.pem")].concat();
+    let err = PrivateKeyDer::from_pem_slice(&pem).unwrap_err();
+    assert!(matches!(err, Error::OpenSshKeyUnsupported), "{err:?}");
+    assert_eq!(
+        err.to_string(),
+        "OpenSSH private keys are not supported; convert the key to PKCS#8 with \
+         `ssh-keygen -p -m pkcs8 -f <file>`"
+    );
+}
+
+/// The certificates in a PEM file, from `read_all()` and from `pem_file_iter()`, which must agree
+fn certificates(name: &str) -> Vec<Vec<u8>> {
+    let pem = read(name);
+    let items = pem::read_all(&pem)
+        .map(|item| match item.unwrap() {
+            Item::X509Certificate(cert) => cert.to_vec(),
+            item => panic!("unexpected {item:?}"),
+        })
+        .collect::<Vec<_>>();
+
+    let certs = CertificateDer::pem_file_iter(format!("testdata/pem/{name}"))
+        .unwrap()
+        .map(|cert| cert.unwrap().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(items, certs, "{name}");
+    items
+}
+
+fn ders(names: &[&str]) -> Vec<Vec<u8>> {
+    names
+        .iter()
+        .map(|name| fs::read(format!("testdata/{name}.der")).unwrap())
+        .collect()
+}
+
+fn read(name: &str) -> Vec<u8> {
+    fs::read(format!("testdata/pem/{name}")).unwrap()
+}
@@ -0,0 +1,74 @@
+#![cfg(all(feature = "pem", feature = "std"))]
+
+use std::cell::Cell;
+use std::fs;
+use std::io::{self, Read};
+use std::rc::Rc;
+
+use rustls_pki_types::pem::{self, Item};
+
+/// A bundle of several megabytes, read 13 bytes at a time, yields the same items as the slice
+/// iterator, without reading far ahead of the section being decoded
+#[test]
+fn multi_megabyte_bundle_in_small_chunks() {
+    let mut unit = fs::read("testdata/chain.pem").unwrap();
+    unit.extend_from_slice(b"Text between sections is skipped.\r\n");
+    unit.extend_from_slice(&fs::read("testdata/ed25519.trusted.pem").unwrap());
+    unit.extend_from_slice(b"-----BEGIN UNKNOWN-----\nAAAA\n-----END UNKNOWN-----\n");
+
+    let mut bundle = Vec::new();
+    while bundle.len() < 4 << 20 {
+        bundle.extend_from_slice(&unit);
+    }
+
+    let expected = pem::read_all(&bundle)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(expected.len() > 5_000);
+    assert!(expected
+        .iter()
+        .any(|item| matches!(item, Item::X509Certificate(_))));
+
+    // The offsets just after each section's `END` line.
+    let ends = bundle
+        .windows(END.len())
+        .enumerate()
+        .filter(|(_, window)| *window == END)
+        .map(|(at, _)| at + bundle[at..].iter().position(|&b| b == b'\n').unwrap() + 1)
+        .collect::<Vec<_>>();
+    assert_eq!(ends.len(), expected.len());
+
+    let read = Rc::new(Cell::new(0));
+    let reader = Chunked {
+        data: &bundle,
+        read: read.clone(),
+    };
+    let mut items = pem::ReadIter::new(reader);
+    let mut ahead = 0;
+    for (i, expected) in expected.iter().enumerate() {
+        assert_eq!(&items.next().unwrap().unwrap(), expected, "{i}");
+        // Input is read no further than the rest of the last chunk holding the section.
+        ahead = ahead.max(read.get() - ends[i]);
+    }
+    assert!(items.next().is_none());
+    assert_eq!(read.get(), bundle.len());
+    assert!(ahead < 13, "read {ahead} bytes ahead");
+}
+
+/// Yields at most 13 bytes from each call to `read()`, and counts the bytes yielded
+struct Chunked<'a> {
+    data: &'a [u8],
+    read: Rc<Cell<usize>>,
+}
+
+impl Read for Chunked<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(13).min(self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        self.read.set(self.read.get() + len);
+        Ok(len)
+    }
+}
+
+const END: &[u8] = b"-----END ";
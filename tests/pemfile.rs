@@ -0,0 +1,95 @@
+#![cfg(all(feature = "pem", feature = "std"))]
+
+use std::fs;
+use std::io::{self, BufReader};
+
+use rustls_pki_types::pem::{self, Item};
+use rustls_pki_types::{pemfile, PrivateKeyDer};
+
+#[test]
+fn bundle() {
+    let mut rd = reader("chain.pem");
+    let certs = pemfile::certs(&mut rd)
+        .map(|cert| cert.unwrap().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(certs, ders(&["leaf", "int", "root"]));
+
+    // The CRL and the section with an unknown label are skipped where they don't fit.
+    let items = pemfile::read_all(&mut reader("pem/bundle.pem"))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(matches!(
+        &items[..],
+        [Item::X509Certificate(_), Item::Crl(_)]
+    ));
+
+    assert_eq!(pemfile::certs(&mut reader("pem/bundle.pem")).count(), 1);
+    let crls = pemfile::crls(&mut reader("pem/bundle.pem"))
+        .map(|crl| crl.unwrap().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(crls, ders(&["empty.crl"]));
+    assert!(pemfile::private_key(&mut reader("pem/bundle.pem"))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn key_before_certificate() {
+    // The key is read from the start, leaving the certificate to be read after it.
+    let mut rd = reader("pem/key-then-cert.pem");
+    let key = pemfile::private_key(&mut rd).unwrap().unwrap();
+    assert!(matches!(key, PrivateKeyDer::Pkcs8(_)));
+    assert_eq!(key.secret_der(), &ders(&["ed25519.pkcs8"])[0][..]);
+
+    let certs = pemfile::certs(&mut rd)
+        .map(|cert| cert.unwrap().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(certs, ders(&["ed25519"]));
+
+    // Reading the certificates first skips over the key.
+    let mut rd = reader("pem/key-then-cert.pem");
+    assert_eq!(pemfile::certs(&mut rd).count(), 1);
+    assert!(pemfile::private_key(&mut rd).unwrap().is_none());
+}
+
+#[test]
+fn comments_between_sections() {
+    let certs = pemfile::certs(&mut reader("pem/comments.pem"))
+        .map(|cert| cert.unwrap().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(certs, ders(&["leaf", "int", "root"]));
+}
+
+#[test]
+fn error_then_stop() {
+    // The second certificate is corrupt; the third is never reached.
+    let mut rd = reader("pem/corrupt.pem");
+    let mut items = pemfile::read_all(&mut rd);
+    assert!(matches!(items.next(), Some(Ok(Item::X509Certificate(_)))));
+
+    let err = items.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "invalid base64 on line 20");
+    let inner = err.into_inner().unwrap().downcast::<pem::Error>().unwrap();
+    assert!(matches!(*inner, pem::Error::InvalidBase64 { line: 20 }));
+
+    assert!(items.next().is_none());
+    assert!(items.next().is_none());
+
+    let mut rd = reader("pem/corrupt.pem");
+    let certs = pemfile::certs(&mut rd).collect::<Vec<_>>();
+    assert_eq!(certs.len(), 2);
+    assert!(certs[0].is_ok());
+    assert!(certs[1].is_err());
+}
+
+fn reader(name: &str) -> BufReader<fs::File> {
+    BufReader::new(fs::File::open(format!("testdata/{name}")).unwrap())
+}
+
+fn ders(names: &[&str]) -> Vec<Vec<u8>> {
+    names
+        .iter()
+        .map(|name| fs::read(format!("testdata/{name}.der")).unwrap())
+        .collect()
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "alloc")]
+
+use std::mem;
+use std::sync::Arc;
+
+use rustls_pki_types::{CertificateDer, CertificatePool, MemoryUsage};
+
+#[test]
+fn identical_certificates_are_interned_once() {
+    let mut pool = CertificatePool::new();
+    assert!(pool.is_empty());
+
+    let owned = pool.intern(CertificateDer::from(ROOT.to_vec()));
+    let borrowed = pool.intern(CertificateDer::from(ROOT));
+    let other = pool.intern(CertificateDer::from(LEAF));
+    assert_eq!(pool.len(), 2);
+
+    assert!(Arc::ptr_eq(&owned, &borrowed));
+    assert!(!Arc::ptr_eq(&owned, &other));
+    // Interning is transparent to comparisons.
+    assert_eq!(*owned, CertificateDer::from(ROOT));
+    assert_eq!(*other, CertificateDer::from(LEAF));
+}
+
+#[test]
+fn memory_usage_counts_shared_certificates_once() {
+    let mut pool = CertificatePool::new();
+    let first = pool.intern(CertificateDer::from(ROOT.to_vec()));
+    let one = pool.approx_heap_size();
+    let handles = (0..10)
+        .map(|_| pool.intern(CertificateDer::from(ROOT.to_vec())))
+        .collect::<Vec<_>>();
+    assert_eq!(pool.approx_heap_size(), one);
+    assert!(handles.iter().all(|handle| Arc::ptr_eq(handle, &first)));
+
+    // The certificate's contents are counted, plus the `Arc` allocation and the pool's table.
+    let arc = 2 * mem::size_of::<usize>() + mem::size_of::<CertificateDer<'_>>();
+    assert!(one >= ROOT.len() + arc + mem::size_of::<usize>());
+
+    // Ten separate copies would hold ten times the contents.
+    let copies = (0..10)
+        .map(|_| CertificateDer::from(ROOT.to_vec()))
+        .collect::<Vec<_>>();
+    let copied = copies.iter().map(|c| c.approx_heap_size()).sum::<usize>();
+    assert!(copied >= 10 * ROOT.len());
+    assert!(one < 2 * ROOT.len());
+}
+
+#[test]
+fn idle_certificates_are_evicted() {
+    let mut pool = CertificatePool::new();
+    let kept = pool.intern(CertificateDer::from(ROOT));
+    drop(pool.intern(CertificateDer::from(LEAF)));
+    assert_eq!(pool.len(), 2);
+
+    assert_eq!(pool.evict_idle(), 1);
+    assert_eq!(pool.len(), 1);
+    assert!(Arc::ptr_eq(&kept, &pool.intern(CertificateDer::from(ROOT))));
+
+    drop(kept);
+    assert_eq!(pool.evict_idle(), 1);
+    assert!(pool.is_empty());
+}
+
+/// Stand-ins for certificates, long enough that their contents outweigh the pool's overhead
+const ROOT: &[u8] = &[0x52; 512];
+const LEAF: &[u8] = &[0x4c; 256];
@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+
+use std::fs;
+
+use rustls_pki_types::{InvalidDer, PrivateKeyDer};
+
+#[test]
+fn rsa_pkcs1() {
+    let der = fs::read("testdata/rsa2048.pkcs1.der").unwrap();
+    assert!(matches!(
+        PrivateKeyDer::try_from(&der[..]),
+        Ok(PrivateKeyDer::Pkcs1(_))
+    ));
+    assert!(matches!(
+        PrivateKeyDer::try_from(der),
+        Ok(PrivateKeyDer::Pkcs1(_))
+    ));
+}
+
+#[test]
+fn p256_sec1() {
+    let der = fs::read("testdata/p256.sec1.der").unwrap();
+    assert!(matches!(
+        PrivateKeyDer::try_from(&der[..]),
+        Ok(PrivateKeyDer::Sec1(_))
+    ));
+    assert!(matches!(
+        PrivateKeyDer::try_from(der),
+        Ok(PrivateKeyDer::Sec1(_))
+    ));
+}
+
+#[test]
+fn pkcs8() {
+    for path in [
+        "testdata/rsa2048.pkcs8.der",
+        "testdata/p256.pkcs8.der",
+        "testdata/ed25519.pkcs8.der",
+    ] {
+        let der = fs::read(path).unwrap();
+        let key = PrivateKeyDer::try_from(&der[..]).unwrap();
+        assert!(matches!(key, PrivateKeyDer::Pkcs8(_)), "{path}");
+        assert_eq!(key.secret_der(), &der[..]);
+        assert!(matches!(
+            PrivateKeyDer::try_from(der.clone()),
+            Ok(PrivateKeyDer::Pkcs8(_))
+        ));
+    }
+}
+
+#[test]
+fn truncated() {
+    for path in [
+        "testdata/rsa2048.pkcs1.der",
+        "testdata/p256.sec1.der",
+        "testdata/ed25519.pkcs8.der",
+    ] {
+        let der = fs::read(path).unwrap();
+        for len in [0, 1, 2, der.len() / 2, der.len() - 1] {
+            assert!(
+                PrivateKeyDer::try_from(&der[..len]).is_err(),
+                "{path} {len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn unknown_structure() {
+    // A certificate is a SEQUENCE, but does not start with a version INTEGER.
+    let cert = fs::read("testdata/ed25519.der").unwrap();
+    assert!(PrivateKeyDer::try_from(&cert[..]).is_err());
+
+    // SEC1 keys are always version 1.
+    assert_eq!(
+        PrivateKeyDer::try_from(&[0x30, 0x05, 0x02, 0x01, 0x00, 0x04, 0x00][..]),
+        Err(InvalidDer::InvalidValue)
+    );
+    // Trailing data after the key.
+    let mut der = fs::read("testdata/ed25519.pkcs8.der").unwrap();
+    der.push(0);
+    assert!(PrivateKeyDer::try_from(der).is_err());
+}
@@ -0,0 +1,270 @@
+#![cfg(feature = "std")]
+
+use std::io::Cursor;
+use std::time::Duration;
+
+use rustls_pki_types::{
+    CacheError, CertificateChainDer, CertificateDer, Der, QualifiedTrustAnchor, TrustAnchor,
+    UnixTime,
+};
+
+#[test]
+fn round_trip_without_metadata() {
+    for anchor in [anchor(None), anchor(Some(NAME_CONSTRAINTS))] {
+        let qualified = QualifiedTrustAnchor::from(anchor.clone());
+        let buf = write(&qualified);
+        let read = QualifiedTrustAnchor::read_cached(&buf[..]).unwrap();
+        assert_eq!(read, qualified);
+        assert_eq!(TrustAnchor::from(read), anchor);
+    }
+}
+
+#[test]
+fn round_trip_with_metadata() {
+    let mut buf = Vec::new();
+    for qualified in qualified() {
+        qualified.write_cached(&mut buf).unwrap();
+    }
+    buf.extend_from_slice(b"after");
+
+    let mut r = Cursor::new(&buf[..]);
+    for qualified in qualified() {
+        assert_eq!(
+            QualifiedTrustAnchor::read_cached(&mut r).unwrap(),
+            qualified
+        );
+    }
+    assert_eq!(&buf[r.position() as usize..], b"after");
+}
+
+#[test]
+fn metadata_is_kept_apart() {
+    // An empty allow-list is not the same as no restriction, and empty parts are kept.
+    let mut restricted = QualifiedTrustAnchor::from(anchor(Some(b"")));
+    restricted.allowed_ekus = Some(Vec::new());
+    restricted.flags = vec![String::new()];
+    let read = QualifiedTrustAnchor::read_cached(&write(&restricted)[..]).unwrap();
+    assert_eq!(read, restricted);
+    assert!(!read.permits_eku(SERVER_AUTH));
+    assert_eq!(read.anchor.name_constraints.as_deref(), Some(&b""[..]));
+}
+
+#[test]
+fn anchor_encoding() {
+    let qualified = QualifiedTrustAnchor {
+        anchor: TrustAnchor::from_parts(b"S", b"K", None),
+        distrust_after: Some(UnixTime::since_unix_epoch(Duration::from_secs(0x0102))),
+        allowed_ekus: Some(vec![Der::from_slice(b"E")]),
+        flags: vec!["f".to_owned()],
+    };
+    let buf = write(&qualified);
+
+    let entries = b"\x00\x00\x00\x06\
+        \x00\x00\x00\x02\x00S\
+        \x00\x00\x00\x02\x01K\
+        \x00\x00\x00\x09\x03\x00\x00\x00\x00\x00\x00\x01\x02\
+        \x00\x00\x00\x01\x04\
+        \x00\x00\x00\x02\x05E\
+        \x00\x00\x00\x02\x06f";
+    assert_eq!(&buf[..5], b"pkic\x01");
+    assert_eq!(&buf[5..buf.len() - 4], entries);
+}
+
+#[test]
+fn malformed_entries() {
+    let subject = &[0x00, b'S'][..];
+    let spki = &[0x01, b'K'][..];
+    let cases: &[&[&[u8]]] = &[
+        // Missing subject or public key
+        &[],
+        &[subject],
+        &[spki],
+        // Empty entry, without a tag
+        &[subject, spki, &[]],
+        // Unknown tag
+        &[subject, spki, &[0x07]],
+        // Out of order, or repeated
+        &[spki, subject],
+        &[subject, subject, spki],
+        &[subject, spki, &[0x03, 0, 0, 0, 0, 0, 0, 0, 0], &[0x02]],
+        &[subject, spki, &[0x04], &[0x04]],
+        &[subject, spki, &[0x06, b'f'], &[0x05, b'E']],
+        // Distrust date of the wrong length
+        &[subject, spki, &[0x03, 0, 0, 0, 0]],
+        // Allowed EKU without the restriction marker, or a marker with contents
+        &[subject, spki, &[0x05, b'E']],
+        &[subject, spki, &[0x04, b'E']],
+        // Flag that is not UTF-8
+        &[subject, spki, &[0x06, 0xff]],
+    ];
+
+    for (i, entries) in cases.iter().enumerate() {
+        let err = QualifiedTrustAnchor::read_cached(&record(entries)[..]).unwrap_err();
+        assert!(matches!(err, CacheError::MalformedEntry), "{i}: {err:?}");
+        assert_eq!(err.to_string(), "malformed trust anchor cache entry");
+    }
+
+    // Repeated allowed EKUs and flags are fine.
+    let read = QualifiedTrustAnchor::read_cached(
+        &record(&[
+            subject,
+            spki,
+            &[0x04],
+            &[0x05, b'E'],
+            &[0x05, b'F'],
+            &[0x06, b'f'],
+            &[0x06, b'g'],
+        ])[..],
+    )
+    .unwrap();
+    assert_eq!(read.allowed_ekus.unwrap().len(), 2);
+    assert_eq!(read.flags, ["f", "g"]);
+}
+
+#[test]
+fn truncated() {
+    let buf = write(&qualified()[1]);
+    for len in 0..buf.len() {
+        let err = QualifiedTrustAnchor::read_cached(&buf[..len]).unwrap_err();
+        assert!(matches!(err, CacheError::Truncated), "{len}: {err:?}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_round_trip() {
+    for qualified in qualified() {
+        let json = serde_json::to_string(&qualified).unwrap();
+        let read = serde_json::from_str::<QualifiedTrustAnchor<'static>>(&json).unwrap();
+        assert_eq!(read, qualified);
+    }
+
+    let anchor = anchor(Some(NAME_CONSTRAINTS));
+    let json = serde_json::to_string(&anchor).unwrap();
+    assert_eq!(
+        serde_json::from_str::<TrustAnchor<'static>>(&json).unwrap(),
+        anchor
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_format() {
+    let json = serde_json::to_value(&qualified()[1]).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "anchor": {
+                "subject": "MQswCQYDVQQDDAJjYQ==",
+                "subject_public_key_info": "MAUGAytlcAMDAHBr",
+                "name_constraints": null,
+            },
+            "distrust_after": 1_733_011_199,
+            "allowed_ekus": ["KwYBBQUHAwE="],
+            "flags": ["ev"],
+        })
+    );
+
+    // Optional fields can be left out, but the anchor's parts cannot.
+    let read = serde_json::from_str::<QualifiedTrustAnchor<'static>>(
+        r#"{"anchor": {"subject": "MQA=", "subject_public_key_info": "MAA="}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        read,
+        QualifiedTrustAnchor::from(TrustAnchor::from_parts(&[0x31, 0x00], &[0x30, 0x00], None))
+    );
+
+    for (json, message) in [
+        (
+            r#"{"subject": "MQA="}"#,
+            "missing field `subject_public_key_info`",
+        ),
+        (
+            r#"{"subject": "MQA=", "subject": "MQA="}"#,
+            "duplicate field `subject`",
+        ),
+        (r#"{"subject": "!"}"#, "invalid base64"),
+        (r#"{"issuer": "MQA="}"#, "unknown field `issuer`"),
+    ] {
+        let err = serde_json::from_str::<TrustAnchor<'static>>(json).unwrap_err();
+        assert!(err.to_string().starts_with(message), "{json}: {err}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn postcard_round_trip() {
+    for qualified in qualified() {
+        let bytes = postcard::to_allocvec(&qualified).unwrap();
+        let read = postcard::from_bytes::<QualifiedTrustAnchor<'static>>(&bytes).unwrap();
+        assert_eq!(read, qualified);
+        // DER is written as bytes, not base64.
+        assert!(bytes
+            .windows(qualified.anchor.subject.len())
+            .any(|window| window == &qualified.anchor.subject[..]));
+    }
+}
+
+/// Anchors with and without each kind of metadata
+fn qualified() -> Vec<QualifiedTrustAnchor<'static>> {
+    let plain = QualifiedTrustAnchor::from(anchor(None));
+
+    let mut full = plain.clone();
+    full.distrust_after = Some(UnixTime::since_unix_epoch(Duration::from_secs(
+        1_733_011_199,
+    )));
+    full.allowed_ekus = Some(vec![Der::from_slice(SERVER_AUTH)]);
+    full.flags = vec!["ev".to_owned()];
+
+    let mut constrained = QualifiedTrustAnchor::from(anchor(Some(NAME_CONSTRAINTS)));
+    constrained.allowed_ekus = Some(vec![
+        Der::from_slice(SERVER_AUTH),
+        Der::from_slice(CLIENT_AUTH),
+    ]);
+    constrained.flags = vec!["gov".to_owned(), "ünïcode".to_owned()];
+
+    vec![plain, full, constrained]
+}
+
+fn anchor(name_constraints: Option<&'static [u8]>) -> TrustAnchor<'static> {
+    TrustAnchor::from_parts(SUBJECT, SPKI, name_constraints)
+}
+
+fn write(qualified: &QualifiedTrustAnchor<'_>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    qualified.write_cached(&mut buf).unwrap();
+    buf
+}
+
+/// A record holding `entries`, with a valid checksum
+fn record(entries: &[&[u8]]) -> Vec<u8> {
+    // A chain record has the same framing.
+    let certs = entries
+        .iter()
+        .map(|entry| CertificateDer::from(entry.to_vec()))
+        .collect::<Vec<_>>();
+    let mut buf = Vec::new();
+    CertificateChainDer::from(certs)
+        .write_cached(&mut buf)
+        .unwrap();
+    buf
+}
+
+/// `SEQUENCE { SET { SEQUENCE { commonName, UTF8String "ca" } } }`, without the outer header
+const SUBJECT: &[u8] = &[
+    0x31, 0x0b, 0x30, 0x09, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x02, b'c', b'a',
+];
+/// An Ed25519 `SubjectPublicKeyInfo`, without the outer header, with a two-octet "key"
+const SPKI: &[u8] = &[
+    0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x03, 0x00, b'p', b'k',
+];
+/// `permittedSubtrees` holding `dNSName "example.gov"`
+const NAME_CONSTRAINTS: &[u8] = &[
+    0xa0, 0x0f, 0x30, 0x0d, 0x82, 0x0b, b'e', b'x', b'a', b'm', b'p', b'l', b'e', b'.', b'g', b'o',
+    b'v',
+];
+/// id-kp-serverAuth
+const SERVER_AUTH: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x01];
+/// id-kp-clientAuth
+const CLIENT_AUTH: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x02];
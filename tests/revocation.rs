@@ -0,0 +1,176 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+
+#[cfg(feature = "pem")]
+use rustls_pki_types::pem::{self, Item, PemObject};
+#[cfg(feature = "std")]
+use rustls_pki_types::LoadError;
+use rustls_pki_types::{
+    CertificateRevocationListDer, InvalidDer, OcspResponseDer, RevocationMaterial,
+};
+
+#[test]
+fn crls() {
+    for name in ["empty.crl.der", "large.crl.der"] {
+        let der = fs::read(format!("testdata/{name}")).unwrap();
+        let material = RevocationMaterial::from_der(&der).unwrap();
+        assert!(matches!(material, RevocationMaterial::Crl(_)), "{name}");
+        assert_eq!(material.as_ref(), der);
+    }
+}
+
+#[test]
+fn ocsp_responses() {
+    let der = fs::read("testdata/ocsp.der").unwrap();
+    let material = RevocationMaterial::from_der(&der).unwrap();
+    assert!(matches!(material, RevocationMaterial::Ocsp(_)));
+    assert_eq!(material.as_ref(), der);
+
+    // A response with a status other than `successful` has no response bytes.
+    let unauthorized = [0x30, 0x03, 0x0a, 0x01, 0x06];
+    assert!(matches!(
+        RevocationMaterial::from_der(&unauthorized),
+        Ok(RevocationMaterial::Ocsp(_))
+    ));
+}
+
+/// Certificates also start with a `SEQUENCE` in a `SEQUENCE`, but are not CRLs
+#[test]
+fn certificates_are_rejected() {
+    for name in [
+        "root.der",
+        "int.der",
+        "leaf.der",
+        "rsaleaf.der",
+        "ed25519.der",
+    ] {
+        let der = fs::read(format!("testdata/{name}")).unwrap();
+        assert!(RevocationMaterial::from_der(&der).is_err(), "{name}");
+    }
+}
+
+#[test]
+fn malformed() {
+    for der in [
+        &[][..],
+        &[0x30, 0x00],
+        &[0x31, 0x03, 0x0a, 0x01, 0x00],
+        &[0x30, 0x03, 0x02, 0x01, 0x00],
+        &[0x30, 0x02, 0x30, 0x00],
+    ] {
+        assert!(RevocationMaterial::from_der(der).is_err(), "{der:02x?}");
+    }
+
+    assert_eq!(
+        RevocationMaterial::from_der(&[0x30, 0x03, 0x02, 0x01, 0x00]).unwrap_err(),
+        InvalidDer::UnexpectedTag {
+            expected: 0x30,
+            found: 0x02
+        }
+    );
+}
+
+/// A list can hold both kinds, owned or borrowed
+#[test]
+fn mixed_list() {
+    let crl = fs::read("testdata/empty.crl.der").unwrap();
+    let ocsp = fs::read("testdata/ocsp.der").unwrap();
+    let material = vec![
+        RevocationMaterial::from(CertificateRevocationListDer::from(&crl[..])),
+        RevocationMaterial::from(OcspResponseDer::from(&ocsp[..])),
+        RevocationMaterial::from_der(&ocsp).unwrap(),
+        RevocationMaterial::from_der(&crl).unwrap(),
+    ];
+
+    let owned = material
+        .iter()
+        .cloned()
+        .map(RevocationMaterial::into_owned)
+        .collect::<Vec<RevocationMaterial<'static>>>();
+    assert_eq!(owned, material);
+    assert!(matches!(
+        owned[..],
+        [
+            RevocationMaterial::Crl(_),
+            RevocationMaterial::Ocsp(_),
+            RevocationMaterial::Ocsp(_),
+            RevocationMaterial::Crl(_),
+        ]
+    ));
+    assert!(format!("{:?}", owned[1]).starts_with("Ocsp("));
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn pem_bundle() {
+    let bundle = fs::read("testdata/pem/revocation.pem").unwrap();
+    let items = pem::read_all(&bundle)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(matches!(
+        &items[..],
+        [
+            Item::Crl(_),
+            Item::X509Certificate(_),
+            Item::OcspResponse(_)
+        ]
+    ));
+
+    // The certificate is skipped.
+    assert_eq!(
+        RevocationMaterial::from_pem_slice(&bundle).unwrap(),
+        RevocationMaterial::Crl(fs::read("testdata/empty.crl.der").unwrap().into())
+    );
+    assert_eq!(
+        OcspResponseDer::from_pem_slice(&bundle).unwrap(),
+        OcspResponseDer::from(fs::read("testdata/ocsp.der").unwrap())
+    );
+}
+
+#[cfg(all(feature = "pem", feature = "std"))]
+#[test]
+fn pem_file() {
+    let material = RevocationMaterial::pem_file_iter("testdata/pem/revocation.pem")
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(material, expected());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn directory() {
+    let dir = tempfile::tempdir().unwrap();
+    for (name, fixture) in [
+        ("a.crl", "empty.crl.der"),
+        ("b.ocsp", "ocsp.der"),
+        ("c.pem", "pem/revocation.pem"),
+        ("d.der", "root.der"),
+    ] {
+        fs::copy(format!("testdata/{fixture}"), dir.path().join(name)).unwrap();
+    }
+    fs::create_dir(dir.path().join("e")).unwrap();
+
+    let files = RevocationMaterial::from_dir_iter(dir.path())
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(files.len(), 4);
+    let expected = expected();
+    assert_eq!(files[0].as_ref().unwrap(), &expected[..1]);
+    assert_eq!(files[1].as_ref().unwrap(), &expected[1..]);
+    assert_eq!(files[2].as_ref().unwrap(), &expected);
+    // A certificate is neither a CRL nor an OCSP response.
+    assert!(matches!(
+        &files[3],
+        Err(LoadError::UnrecognizedFormat { path }) if path.ends_with("d.der")
+    ));
+}
+
+#[cfg(feature = "std")]
+fn expected() -> Vec<RevocationMaterial<'static>> {
+    vec![
+        RevocationMaterial::Crl(fs::read("testdata/empty.crl.der").unwrap().into()),
+        RevocationMaterial::Ocsp(fs::read("testdata/ocsp.der").unwrap().into()),
+    ]
+}
@@ -0,0 +1,132 @@
+#![cfg(all(feature = "x509", feature = "std"))]
+
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use rustls_pki_types::{CertificateDer, InvalidDer, SubjectAltName};
+
+#[test]
+fn leaf() {
+    let leaf = cert("leaf.der");
+    let names = leaf
+        .subject_alternative_names()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        names,
+        [
+            SubjectAltName::DnsName("www.example.com"),
+            SubjectAltName::DnsName("*.example.com"),
+            SubjectAltName::IpAddress(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+            SubjectAltName::IpAddress(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))),
+            SubjectAltName::Other {
+                tag: 0x86,
+                value: b"https://example.com/id",
+            },
+        ]
+    );
+}
+
+#[test]
+fn without_names() {
+    // `rsaleaf.der` has extensions, but no subject alternative names.
+    for name in ["rsaleaf.der", "root.der"] {
+        let cert = cert(name);
+        assert_eq!(
+            cert.subject_alternative_names().unwrap().count(),
+            0,
+            "{name}"
+        );
+    }
+
+    // A certificate without any extensions.
+    let cert = CertificateDer::from(minimal(&[]));
+    assert_eq!(cert.subject_alternative_names().unwrap().count(), 0);
+}
+
+#[test]
+fn malformed_names() {
+    for (names, expected) in [
+        // A dNSName which is not ASCII.
+        (
+            tlv(0x82, "bücher.example".as_bytes()),
+            InvalidDer::InvalidValue,
+        ),
+        // An iPAddress of neither 4 nor 16 octets.
+        (tlv(0x87, &[192, 0, 2]), InvalidDer::InvalidValue),
+        // A GeneralName running past the end of the list.
+        (vec![0x82, 0x7f, b'a'], InvalidDer::Truncated),
+    ] {
+        let names = [tlv(0x82, b"ok.example"), names, tlv(0x82, b"after.example")].concat();
+        let cert = CertificateDer::from(minimal(&san_extension(&names)));
+        let mut iter = cert.subject_alternative_names().unwrap();
+        assert_eq!(iter.next(), Some(Ok(SubjectAltName::DnsName("ok.example"))));
+        assert_eq!(iter.next(), Some(Err(expected)));
+        // Nothing further is yielded after an error.
+        assert_eq!(iter.next(), None);
+    }
+}
+
+#[test]
+fn trailing_data_after_extensions() {
+    let extensions = san_extension(&tlv(0x82, b"ok.example"));
+    let cert = CertificateDer::from(minimal(&extensions));
+    assert!(cert.spans().is_ok());
+
+    for junk in [&[0x05, 0x00][..], &[0xa4, 0x00], &extensions] {
+        let cert = CertificateDer::from(minimal(&[&extensions[..], junk].concat()));
+        assert_eq!(cert.spans().unwrap_err(), InvalidDer::TrailingData);
+        assert_eq!(
+            cert.subject_alternative_names().unwrap_err(),
+            InvalidDer::TrailingData
+        );
+    }
+}
+
+/// A `[3]` extensions field with a single, non-critical subjectAltName extension
+fn san_extension(names: &[u8]) -> Vec<u8> {
+    let oid = tlv(0x06, &[0x55, 0x1d, 0x11]);
+    let value = tlv(0x04, &tlv(0x30, names));
+    let extension = tlv(0x30, &[oid, value].concat());
+    tlv(0xa3, &tlv(0x30, &extension))
+}
+
+/// A structurally valid v3 certificate, with `extensions` at the end of its `tbsCertificate`
+fn minimal(extensions: &[u8]) -> Vec<u8> {
+    let ed25519 = tlv(0x30, &tlv(0x06, &[0x2b, 0x65, 0x70]));
+    let validity = [tlv(0x17, b"250101000000Z"), tlv(0x17, b"350101000000Z")].concat();
+    let spki = [ed25519.clone(), tlv(0x03, &[0x00; 33])].concat();
+    let tbs = [
+        tlv(0xa0, &tlv(0x02, &[0x02])),
+        tlv(0x02, &[0x01]),
+        ed25519.clone(),
+        tlv(0x30, &[]),
+        tlv(0x30, &validity),
+        tlv(0x30, &[]),
+        tlv(0x30, &spki),
+        extensions.to_vec(),
+    ]
+    .concat();
+
+    tlv(
+        0x30,
+        &[tlv(0x30, &tbs), ed25519, tlv(0x03, &[0x00; 65])].concat(),
+    )
+}
+
+fn tlv(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut der = vec![tag];
+    match contents.len() {
+        len @ 0..=0x7f => der.push(len as u8),
+        len @ 0x80..=0xff => der.extend([0x81, len as u8]),
+        len => der.extend([0x82, (len >> 8) as u8, len as u8]),
+    }
+    der.extend(contents);
+    der
+}
+
+fn cert(name: &str) -> CertificateDer<'static> {
+    CertificateDer::from(fs::read(format!("testdata/{name}")).unwrap())
+}
@@ -0,0 +1,125 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+
+use rustls_pki_types::{CertificateDer, InvalidSct, Sct, SctList};
+
+/// The log IDs, timestamps and signature lengths of the SCTs in `sct/amazon.der`, as printed
+/// by `openssl x509 -text`
+const EXPECTED: [(&str, u64, usize); 3] = [
+    (
+        "2979BEF09E393921F056739F63A577E5BE577D9C600AF8F94D5D265C255DC784",
+        1_633_489_148_091,
+        0x47,
+    ),
+    (
+        "51A3B0F5FD01799C566DB837788F0CA47ACC1B27CBF79E88429A0DFED48B05E5",
+        1_633_489_148_132,
+        0x48,
+    ),
+    (
+        "41C8CAB1DF22464A10C6A13A0942875E4E318B1B03EBEB4BC768F090629606F6",
+        1_633_489_148_063,
+        0x46,
+    ),
+];
+
+#[test]
+fn embedded_scts() {
+    let cert = cert();
+    let list = cert.sct_list().unwrap().unwrap();
+    let scts = list.iter().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(scts.len(), EXPECTED.len());
+
+    for (sct, (log_id, millis, signature_len)) in scts.iter().zip(EXPECTED) {
+        assert_eq!(hex(sct.log_id()), log_id);
+        assert_eq!(sct.timestamp_millis(), millis);
+        assert_eq!(sct.timestamp().as_secs(), millis / 1000);
+        assert!(sct.extensions().is_empty());
+
+        // SHA-256 (4) with ECDSA (3), then the DER signature.
+        let signature = sct.signature();
+        assert_eq!(signature[..2], [4, 3]);
+        let len = usize::from(u16::from_be_bytes([signature[2], signature[3]]));
+        assert_eq!(len, signature_len);
+        assert_eq!(signature.len(), 4 + len);
+        assert_eq!(signature[4], 0x30);
+    }
+
+    // The first SCT's signature, as printed by OpenSSL.
+    assert_eq!(hex(&scts[0].signature()[4..12]), "3045022100E223BC");
+}
+
+#[test]
+fn no_scts() {
+    for name in ["root.der", "leaf.der", "rsaleaf.der"] {
+        let der = fs::read(format!("testdata/{name}")).unwrap();
+        assert_eq!(CertificateDer::from(der).sct_list(), Ok(None), "{name}");
+    }
+}
+
+#[test]
+fn unsupported_version_is_skipped() {
+    let cert = cert();
+    let list = cert.sct_list().unwrap().unwrap();
+    let mut bytes = list.as_bytes().to_vec();
+
+    // Mark the second SCT as version 2: it is after the list length, the first SCT and its
+    // length, and the second SCT's length.
+    let first_len = usize::from(u16::from_be_bytes([bytes[2], bytes[3]]));
+    let second = 2 + 2 + first_len + 2;
+    assert_eq!(bytes[second], 0);
+    bytes[second] = 1;
+
+    let results = SctList::from_slice(&bytes).iter().collect::<Vec<_>>();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert_eq!(results[1], Err(InvalidSct::UnsupportedVersion(1)));
+    assert_eq!(
+        results[2].as_ref().unwrap().timestamp_millis(),
+        EXPECTED[2].1
+    );
+}
+
+#[test]
+fn broken_framing() {
+    let cert = cert();
+    let list = cert.sct_list().unwrap().unwrap();
+    let bytes = list.as_bytes();
+
+    // Cut short, the list length claims more than there is.
+    let results = SctList::from_slice(&bytes[..bytes.len() - 1])
+        .iter()
+        .collect::<Vec<_>>();
+    assert_eq!(results, [Err(InvalidSct::Truncated)]);
+
+    let mut trailing = bytes.to_vec();
+    trailing.push(0);
+    let results = SctList::from_slice(&trailing).iter().collect::<Vec<_>>();
+    assert_eq!(results, [Err(InvalidSct::TrailingData)]);
+
+    assert_eq!(
+        SctList::from_slice(&[0, 0]).iter().collect::<Vec<_>>(),
+        [Err(InvalidSct::Truncated)]
+    );
+
+    // A single SCT cut short at every length.
+    let first_len = usize::from(u16::from_be_bytes([bytes[2], bytes[3]]));
+    let first = &bytes[4..4 + first_len];
+    assert!(Sct::parse(first).is_ok());
+    for len in 0..first.len() {
+        assert_eq!(
+            Sct::parse(&first[..len]),
+            Err(InvalidSct::Truncated),
+            "{len}"
+        );
+    }
+}
+
+fn cert() -> CertificateDer<'static> {
+    CertificateDer::from(fs::read("testdata/sct/amazon.der").unwrap())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02X}")).collect()
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "serde-secrets")]
+
+use std::fs;
+
+use rustls_pki_types::{PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer};
+
+#[test]
+fn json_round_trip() {
+    for (key, variant) in keys() {
+        let json = serde_json::to_string(&key).unwrap();
+        let der = key.secret_der();
+        let expected = format!(r#"{{"{variant}":"{}"}}"#, base64(der));
+        assert_eq!(json, expected);
+
+        let read = serde_json::from_str::<PrivateKeyDer<'static>>(&json).unwrap();
+        assert_eq!(read, key);
+    }
+}
+
+#[test]
+fn postcard_round_trip() {
+    for (index, (key, _)) in keys().into_iter().enumerate() {
+        let bytes = postcard::to_allocvec(&key).unwrap();
+        // The variant index, then the DER encoding prefixed with its varint length.
+        let der = key.secret_der();
+        assert_eq!(bytes[0], index as u8);
+        assert_eq!(&bytes[bytes.len() - der.len()..], der);
+
+        let read = postcard::from_bytes::<PrivateKeyDer<'static>>(&bytes).unwrap();
+        assert_eq!(read, key);
+    }
+}
+
+#[test]
+fn single_formats() {
+    let pkcs1 = PrivatePkcs1KeyDer::from(fs::read("testdata/rsa2048.pkcs1.der").unwrap());
+    let json = serde_json::to_string(&pkcs1).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PrivatePkcs1KeyDer<'static>>(&json).unwrap(),
+        pkcs1
+    );
+    let bytes = postcard::to_allocvec(&pkcs1).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<PrivatePkcs1KeyDer<'static>>(&bytes).unwrap(),
+        pkcs1
+    );
+
+    let sec1 = PrivateSec1KeyDer::from(fs::read("testdata/p256.sec1.der").unwrap());
+    let json = serde_json::to_string(&sec1).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PrivateSec1KeyDer<'static>>(&json).unwrap(),
+        sec1
+    );
+    let bytes = postcard::to_allocvec(&sec1).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<PrivateSec1KeyDer<'static>>(&bytes).unwrap(),
+        sec1
+    );
+
+    let pkcs8 = PrivatePkcs8KeyDer::from(fs::read("testdata/ed25519.pkcs8.der").unwrap());
+    let json = serde_json::to_string(&pkcs8).unwrap();
+    assert_eq!(json, format!("\"{}\"", base64(pkcs8.secret_pkcs8_der())));
+    assert_eq!(
+        serde_json::from_str::<PrivatePkcs8KeyDer<'static>>(&json).unwrap(),
+        pkcs8
+    );
+    let bytes = postcard::to_allocvec(&pkcs8).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<PrivatePkcs8KeyDer<'static>>(&bytes).unwrap(),
+        pkcs8
+    );
+}
+
+#[test]
+fn invalid() {
+    let err = serde_json::from_str::<PrivateKeyDer<'static>>(r#"{"Pkcs12":"AAAA"}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("unknown variant `Pkcs12`"),
+        "{err}"
+    );
+
+    assert!(serde_json::from_str::<PrivateKeyDer<'static>>(r#"{"Pkcs8":"A*AA"}"#).is_err());
+    assert!(serde_json::from_str::<PrivateKeyDer<'static>>(r#"{"Pkcs8":[48,0]}"#).is_err());
+
+    // A variant index past the last format.
+    assert!(postcard::from_bytes::<PrivateKeyDer<'static>>(&[3, 2, 0x30, 0x00]).is_err());
+    // A length past the end of the input.
+    assert!(postcard::from_bytes::<PrivateKeyDer<'static>>(&[2, 5, 0x30, 0x00]).is_err());
+}
+
+fn keys() -> Vec<(PrivateKeyDer<'static>, &'static str)> {
+    vec![
+        (
+            PrivatePkcs1KeyDer::from(fs::read("testdata/rsa2048.pkcs1.der").unwrap()).into(),
+            "Pkcs1",
+        ),
+        (
+            PrivateSec1KeyDer::from(fs::read("testdata/p256.sec1.der").unwrap()).into(),
+            "Sec1",
+        ),
+        (
+            PrivatePkcs8KeyDer::from(fs::read("testdata/ed25519.pkcs8.der").unwrap()).into(),
+            "Pkcs8",
+        ),
+    ]
+}
+
+/// Padded standard base64, written independently of the crate's encoder
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::new();
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char),
+                false => out.push('='),
+            }
+        }
+    }
+    out
+}
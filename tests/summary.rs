@@ -0,0 +1,60 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+
+use rustls_pki_types::{CertificateDer, CertificateRevocationListDer};
+
+#[test]
+fn fixtures() {
+    // Compared with the summaries written by `generate.sh` from OpenSSL's view of the fixtures.
+    let expected = fs::read_to_string("testdata/summary.txt").unwrap();
+    let mut seen = 0;
+    for line in expected.lines() {
+        let (file, expected) = line.split_once('\t').unwrap();
+        let der = fs::read(format!("testdata/{file}")).unwrap();
+        let summary = match file.ends_with(".crl.der") {
+            true => CertificateRevocationListDer::from(der)
+                .display_summary()
+                .to_string(),
+            false => CertificateDer::from(der).display_summary().to_string(),
+        };
+        assert_eq!(summary, expected, "{file}");
+        seen += 1;
+    }
+    assert_eq!(seen, 4);
+}
+
+#[test]
+fn malformed_certificate() {
+    let cert = CertificateDer::from(&[0x30, 0x03, 0x02, 0x01, 0x00][..]);
+    assert_eq!(
+        cert.display_summary().to_string(),
+        "malformed certificate (5 bytes: 3003020100)"
+    );
+
+    // Only the first 16 octets are written.
+    let der = (0..40).collect::<Vec<u8>>();
+    assert_eq!(
+        CertificateDer::from(&der[..]).display_summary().to_string(),
+        "malformed certificate (40 bytes: 000102030405060708090a0b0c0d0e0f...)"
+    );
+
+    assert_eq!(
+        CertificateDer::from(&[][..]).display_summary().to_string(),
+        "malformed certificate (0 bytes: )"
+    );
+}
+
+#[test]
+fn malformed_crl() {
+    // A valid certificate is not a CRL.
+    let der = fs::read("testdata/ed25519.der").unwrap();
+    let summary = CertificateRevocationListDer::from(&der[..])
+        .display_summary()
+        .to_string();
+    assert!(
+        summary.starts_with(&format!("malformed CRL ({} bytes: 3082", der.len())),
+        "{summary}"
+    );
+    assert!(summary.ends_with("...)"), "{summary}");
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "alloc")]
+
+use rustls_pki_types::{CertUsage, InvalidTlsaRecord, MatchingType, Selector, TlsaRecord};
+
+/// RFC 6698 section 2.3: a SHA-256 association of a PKIX CA certificate
+const CA_SHA256: &str = "0 0 1 d2abde240d7cd3ee6b4b28c54df034b9
+      7983a1d16e8a410e4561cb106618e971";
+
+/// RFC 6698 section 2.3: a SHA-512 association of the public key of a PKIX end-entity
+/// certificate
+const EE_SPKI_SHA512: &str = "1 1 2 92003ba34942dc74152e2f2c408d29ec
+      a5a520e7f2e06bb944f4dca346baf63c
+      1b177615d466f6c4b71c216a50292bd5
+      8c9ebdd2f74e38fe51ffd48c43326cbc";
+
+#[test]
+fn rfc6698_examples() {
+    let record = TlsaRecord::from_presentation(CA_SHA256).unwrap();
+    assert_eq!(record.usage, CertUsage::PkixTa);
+    assert_eq!(record.selector, Selector::FullCertificate);
+    assert_eq!(record.matching_type, MatchingType::Sha256);
+    assert!(record.matching_type.is_hashed());
+    assert_eq!(record.data.len(), 32);
+    assert_eq!(record.data[..4], [0xd2, 0xab, 0xde, 0x24]);
+    assert_eq!(record.data[28..], [0x66, 0x18, 0xe9, 0x71]);
+    assert_eq!(
+        record.to_string(),
+        "0 0 1 D2ABDE240D7CD3EE6B4B28C54DF034B97983A1D16E8A410E4561CB106618E971"
+    );
+
+    let rdata = record.to_rdata();
+    assert_eq!(rdata[..3], [0, 0, 1]);
+    assert_eq!(rdata[3..], record.data[..]);
+    assert_eq!(TlsaRecord::from_rdata(&rdata), Ok(record));
+
+    let record = TlsaRecord::from_presentation(EE_SPKI_SHA512).unwrap();
+    assert_eq!(record.usage, CertUsage::PkixEe);
+    assert_eq!(record.selector, Selector::SubjectPublicKeyInfo);
+    assert_eq!(record.matching_type, MatchingType::Sha512);
+    assert_eq!(record.matching_type.digest_len(), Some(64));
+    assert_eq!(record.data[..4], [0x92, 0x00, 0x3b, 0xa3]);
+    assert_eq!(record.data[60..], [0x43, 0x32, 0x6c, 0xbc]);
+    assert_eq!(TlsaRecord::from_rdata(&record.to_rdata()), Ok(record));
+}
+
+#[test]
+fn mnemonics() {
+    let numeric = TlsaRecord::from_presentation(EE_SPKI_SHA512).unwrap();
+    let text = EE_SPKI_SHA512.replacen("1 1 2", "PKIX-EE spki sha2-512", 1);
+    assert_eq!(TlsaRecord::from_presentation(&text), Ok(numeric));
+
+    let record = TlsaRecord::from_presentation("DANE-TA Cert Full 3082").unwrap();
+    assert_eq!(record.usage, CertUsage::DaneTa);
+    assert!(!record.matching_type.is_hashed());
+    assert_eq!(record.data, [0x30, 0x82]);
+}
+
+#[test]
+fn unknown_parameters() {
+    let record = TlsaRecord::from_rdata(&[4, 2, 3, 0xab]).unwrap();
+    assert_eq!(record.usage, CertUsage::Unknown(4));
+    assert_eq!(record.selector, Selector::Unknown(2));
+    assert_eq!(record.matching_type, MatchingType::Unknown(3));
+    assert_eq!(record.matching_type.digest_len(), None);
+    assert_eq!(record.to_rdata(), [4, 2, 3, 0xab]);
+
+    let record = TlsaRecord::from_rdata(&[255, 255, 255, 0xab]).unwrap();
+    assert_eq!(record.usage, CertUsage::PrivCert);
+    assert_eq!(record.selector, Selector::PrivSel);
+    assert_eq!(record.matching_type, MatchingType::PrivMatch);
+    assert_eq!(record.to_string(), "255 255 255 AB");
+}
+
+#[test]
+fn malformed_rdata() {
+    for rdata in [&[][..], &[3], &[3, 1], &[3, 1, 1]] {
+        assert_eq!(
+            TlsaRecord::from_rdata(rdata),
+            Err(InvalidTlsaRecord::Truncated),
+            "{rdata:?}"
+        );
+    }
+
+    let mut rdata = TlsaRecord::from_presentation(CA_SHA256).unwrap().to_rdata();
+    rdata.pop();
+    assert_eq!(
+        TlsaRecord::from_rdata(&rdata),
+        Err(InvalidTlsaRecord::InvalidDigestLength)
+    );
+    rdata.extend_from_slice(&[0; 2]);
+    assert_eq!(
+        TlsaRecord::from_rdata(&rdata),
+        Err(InvalidTlsaRecord::InvalidDigestLength)
+    );
+
+    // A SHA-256 digest with the SHA-512 matching type.
+    rdata.pop();
+    rdata[2] = 2;
+    assert_eq!(
+        TlsaRecord::from_rdata(&rdata),
+        Err(InvalidTlsaRecord::InvalidDigestLength)
+    );
+}
+
+#[test]
+fn malformed_presentation() {
+    for text in [
+        "",
+        "3 1",
+        "3 1 1",
+        "3 1 1 abc",
+        "3 1 1 zz",
+        "3 1 1 0x12",
+        "256 1 0 ab",
+        "-1 1 0 ab",
+        "DANE-XX 1 0 ab",
+        "3 1 0 ab c",
+    ] {
+        let expected = match text {
+            "3 1 1" => InvalidTlsaRecord::Truncated,
+            _ => InvalidTlsaRecord::InvalidText,
+        };
+        assert_eq!(TlsaRecord::from_presentation(text), Err(expected), "{text}");
+    }
+}
+
+#[cfg(feature = "x509")]
+#[test]
+fn select() {
+    use rustls_pki_types::CertificateDer;
+
+    let der = std::fs::read("testdata/root.der").unwrap();
+    let cert = CertificateDer::from(&der[..]);
+
+    // A full certificate association of a trust anchor, like the last example of RFC 6698.
+    let text = format!("2 0 0 {}", hex(&der));
+    let record = TlsaRecord::from_presentation(&text).unwrap();
+    assert_eq!(record.selector.select(&cert), Ok(Some(&record.data[..])));
+
+    let spans = cert.spans().unwrap();
+    let spki = spans.subject_public_key_info();
+    assert_eq!(
+        Selector::SubjectPublicKeyInfo.select(&cert),
+        Ok(Some(spki.as_ref()))
+    );
+    assert_eq!(Selector::PrivSel.select(&cert), Ok(None));
+    assert_eq!(Selector::Unknown(7).select(&cert), Ok(None));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let record = TlsaRecord::from_presentation(CA_SHA256).unwrap();
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, format!("\"{record}\""));
+    assert_eq!(serde_json::from_str::<TlsaRecord>(&json).unwrap(), record);
+    assert!(serde_json::from_str::<TlsaRecord>("\"0 0 1 d2ab\"").is_err());
+}
+
+#[cfg(feature = "x509")]
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,195 @@
+#![cfg(feature = "alloc")]
+
+use rustls_pki_types::{AlgorithmIdentifier, InvalidSignature, SignatureVerificationAlgorithm};
+
+/// A stand-in for a real algorithm: the "signature" is the FNV-1a hash of the public key and
+/// the message, which is sensitive to the order of the octets
+struct Fnv;
+
+impl SignatureVerificationAlgorithm for Fnv {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        check(fnv(FNV_OFFSET, &[public_key, message]), signature)
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        AlgorithmIdentifier::from_slice(&[])
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        AlgorithmIdentifier::from_slice(&[])
+    }
+}
+
+/// [`Fnv`], hashing the fragments one at a time rather than concatenating them
+struct StreamingFnv;
+
+impl SignatureVerificationAlgorithm for StreamingFnv {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        Fnv.verify_signature(public_key, message, signature)
+    }
+
+    fn verify_signature_vectored(
+        &self,
+        public_key: &[u8],
+        message: &[&[u8]],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        let hash = message
+            .iter()
+            .fold(fnv(FNV_OFFSET, &[public_key]), |hash, fragment| {
+                fnv(hash, &[fragment])
+            });
+        check(hash, signature)
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        AlgorithmIdentifier::from_slice(&[])
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        AlgorithmIdentifier::from_slice(&[])
+    }
+}
+
+/// A faulty override, which only hashes the last fragment
+struct LastFragmentOnly;
+
+impl SignatureVerificationAlgorithm for LastFragmentOnly {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        Fnv.verify_signature(public_key, message, signature)
+    }
+
+    fn verify_signature_vectored(
+        &self,
+        public_key: &[u8],
+        message: &[&[u8]],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        let last = message.last().copied().unwrap_or_default();
+        Fnv.verify_signature(public_key, last, signature)
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        AlgorithmIdentifier::from_slice(&[])
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        AlgorithmIdentifier::from_slice(&[])
+    }
+}
+
+#[test]
+fn default_implementation() {
+    for (message, signature) in cases() {
+        conformance(&Fnv, KEY, &message, &signature).unwrap();
+    }
+}
+
+#[test]
+fn streaming_override() {
+    for (message, signature) in cases() {
+        conformance(&StreamingFnv, KEY, &message, &signature).unwrap();
+    }
+}
+
+#[test]
+fn faulty_override_is_caught() {
+    let (message, signature) = cases().nth(8).unwrap();
+    assert!(message.starts_with(b"TLS"));
+    let split = conformance(&LastFragmentOnly, KEY, &message, &signature).unwrap_err();
+    assert!(split.len() > 1);
+}
+
+/// Check that `alg` gives the same outcome for `message` as one fragment and however it is
+/// split, returning a split for which it does not
+fn conformance<'m>(
+    alg: &dyn SignatureVerificationAlgorithm,
+    public_key: &[u8],
+    message: &'m [u8],
+    signature: &[u8],
+) -> Result<(), Vec<&'m [u8]>> {
+    let expected = alg.verify_signature(public_key, message, signature).is_ok();
+
+    let mut splits = vec![vec![message]];
+    // Fragments of every size, down to single octets, or none for an empty message.
+    for size in 1..=message.len().max(1) {
+        splits.push(message.chunks(size).collect());
+    }
+    // Every split into two, with an empty fragment at either end.
+    for at in 0..=message.len() {
+        let (head, tail) = message.split_at(at);
+        splits.push(vec![head, tail]);
+    }
+    // Empty fragments between every octet.
+    splits.push(
+        message
+            .chunks(1)
+            .flat_map(|octet| [&[][..], octet])
+            .collect(),
+    );
+
+    for split in splits {
+        assert_eq!(split.concat(), message);
+        if alg
+            .verify_signature_vectored(public_key, &split, signature)
+            .is_ok()
+            != expected
+        {
+            return Err(split);
+        }
+    }
+    Ok(())
+}
+
+/// Messages with a valid signature and each way to spoil it
+fn cases() -> impl Iterator<Item = (Vec<u8>, Vec<u8>)> {
+    let messages: [&[u8]; 4] = [
+        b"",
+        b"x",
+        b"TLS 1.3, server CertificateVerify\x00transcript hash",
+        &[0x20; 64],
+    ];
+
+    messages.into_iter().flat_map(|message| {
+        let signature = fnv(FNV_OFFSET, &[KEY, message]).to_be_bytes().to_vec();
+        let mut flipped = signature.clone();
+        flipped[7] ^= 1;
+        let truncated = signature[..7].to_vec();
+        [signature, flipped, truncated, vec![]]
+            .into_iter()
+            .map(move |signature| (message.to_vec(), signature))
+    })
+}
+
+fn check(hash: u64, signature: &[u8]) -> Result<(), InvalidSignature> {
+    match signature == hash.to_be_bytes() {
+        true => Ok(()),
+        false => Err(InvalidSignature),
+    }
+}
+
+fn fnv(mut hash: u64, data: &[&[u8]]) -> u64 {
+    for octet in data.iter().flat_map(|data| data.iter()) {
+        hash ^= u64::from(*octet);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const KEY: &[u8] = b"public key";
@@ -0,0 +1,79 @@
+#![cfg(all(unix, feature = "std", feature = "dangerous-serialize-secrets"))]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::sync::Arc;
+use std::thread;
+
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer};
+
+#[test]
+fn new_file_is_private() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("key.pem");
+    key().write_pem_file(&path).unwrap();
+
+    assert_eq!(mode(&path), 0o600);
+    assert_eq!(PrivateKeyDer::from_pem_file(&path).unwrap(), key());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn replaced_file_is_not_widened() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("key.pem");
+    fs::write(&path, "old contents").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+    assert_eq!(mode(&path), 0o640);
+
+    key().write_pem_file(&path).unwrap();
+    assert_eq!(mode(&path), 0o600);
+    assert_eq!(PrivateKeyDer::from_pem_file(&path).unwrap(), key());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn concurrent_writers() {
+    let dir = Arc::new(tempfile::tempdir().unwrap());
+    let threads = (0..8)
+        .map(|_| {
+            let dir = dir.clone();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    key().write_pem_file(dir.path().join("key.pem")).unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let path = dir.path().join("key.pem");
+    assert_eq!(PrivateKeyDer::from_pem_file(&path).unwrap(), key());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn failed_write_leaves_directory_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    // Renaming a file over a non-empty directory fails.
+    let path = dir.path().join("key.pem");
+    fs::create_dir(&path).unwrap();
+    fs::write(path.join("inside"), "").unwrap();
+
+    key().write_pem_file(&path).unwrap_err();
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    assert!(path.join("inside").exists());
+}
+
+fn key() -> PrivateKeyDer<'static> {
+    PrivateKeyDer::from(PrivatePkcs8KeyDer::from(
+        fs::read("testdata/ed25519.pkcs8.der").unwrap(),
+    ))
+}
+
+fn mode(path: &std::path::Path) -> u32 {
+    fs::metadata(path).unwrap().permissions().mode() & 0o777
+}
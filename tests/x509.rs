@@ -0,0 +1,108 @@
+#![cfg(all(feature = "x509", feature = "alloc"))]
+
+use std::fs;
+use std::ops::Range;
+
+use rustls_pki_types::{CertificateDer, InvalidDer};
+
+#[test]
+fn self_issued() {
+    for (name, expected) in [
+        ("root.der", true),
+        ("root2.der", true),
+        ("ed25519.der", true),
+        ("int.der", false),
+        // The same intermediate, issued by another root.
+        ("int-cross.der", false),
+        ("leaf.der", false),
+        ("rsaleaf.der", false),
+    ] {
+        let cert = cert(name);
+        assert_eq!(cert.is_self_issued(), Ok(expected), "{name}");
+        let spans = cert.spans().unwrap();
+        assert_eq!(spans.is_self_issued(), expected, "{name}");
+    }
+
+    // The cross-signed intermediate has the subject of the other one, and a different issuer.
+    let int = cert("int.der");
+    let cross = cert("int-cross.der");
+    let (int, cross) = (int.spans().unwrap(), cross.spans().unwrap());
+    assert_eq!(int.subject(), cross.subject());
+    assert_ne!(int.issuer(), cross.issuer());
+}
+
+#[test]
+fn spans_match_asn1parse() {
+    // Compared with the offsets `generate.sh` records from `openssl asn1parse`.
+    let fields = fs::read_to_string("testdata/spans.txt").unwrap();
+    assert_eq!(fields.lines().count(), 5);
+    for line in fields.lines() {
+        let mut columns = line.split('\t');
+        let name = columns.next().unwrap();
+        let der = fs::read(format!("testdata/{name}")).unwrap();
+        let [tbs, algorithm, signature] = [(); 3].map(|_| field(&der, columns.next().unwrap()));
+
+        let cert = CertificateDer::from(&der[..]);
+        let spans = cert.spans().unwrap();
+        assert_eq!(span(&der, spans.tbs_certificate()), tbs.encoded, "{name}");
+        assert_eq!(cert.tbs_certificate().unwrap(), spans.tbs_certificate());
+        assert_eq!(
+            span(&der, spans.signature_algorithm()),
+            algorithm.contents,
+            "{name}"
+        );
+        assert_eq!(
+            cert.signature_algorithm().unwrap(),
+            spans.signature_algorithm()
+        );
+
+        // The signature follows the unused bits octet, which is zero.
+        let value = spans.signature_value().unwrap();
+        assert_eq!(der[signature.contents.start], 0, "{name}");
+        assert_eq!(
+            span(&der, value),
+            signature.contents.start + 1..signature.contents.end,
+            "{name}"
+        );
+        assert_eq!(cert.signature_value().unwrap(), value);
+
+        let mut unused_bits = der.clone();
+        unused_bits[signature.contents.start] = 1;
+        let cert = CertificateDer::from(&unused_bits[..]);
+        assert_eq!(
+            cert.signature_value(),
+            Err(InvalidDer::InvalidValue),
+            "{name}"
+        );
+    }
+}
+
+/// The range of `der` occupied by a field, as borrowed by an accessor
+fn span(der: &[u8], field: &[u8]) -> Range<usize> {
+    let start = field.as_ptr() as usize - der.as_ptr() as usize;
+    start..start + field.len()
+}
+
+/// The ranges of a field's encoding and contents, from its offset, header length and contents
+/// length in `spans.txt`
+fn field(der: &[u8], column: &str) -> Field {
+    let numbers = column
+        .split(' ')
+        .map(|n| n.parse().unwrap())
+        .collect::<Vec<usize>>();
+    let (offset, header_len, len) = (numbers[0], numbers[1], numbers[2]);
+    assert!(offset + header_len + len <= der.len());
+    Field {
+        encoded: offset..offset + header_len + len,
+        contents: offset + header_len..offset + header_len + len,
+    }
+}
+
+struct Field {
+    encoded: Range<usize>,
+    contents: Range<usize>,
+}
+
+fn cert(name: &str) -> CertificateDer<'static> {
+    CertificateDer::from(fs::read(format!("testdata/{name}")).unwrap())
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "alloc")]
+
+use std::fs;
+
+use rustls_pki_types::{CertificateChainDer, CertificateDer, X5cError};
+
+#[test]
+fn round_trip() {
+    let chain = chain();
+    let x5c = chain.to_x5c();
+    assert_eq!(x5c.len(), 3);
+    assert_eq!(CertificateChainDer::from_x5c(&x5c).unwrap(), chain);
+}
+
+#[test]
+fn strict_base64() {
+    // An OCTET STRING in a SEQUENCE, chosen so that its encoding needs padding and uses `/`.
+    let valid = "MAQEAvv/vw==";
+    let x5c = chain().to_x5c();
+    let decoded = CertificateChainDer::from_x5c([&x5c[0], valid, &x5c[2]]).unwrap();
+    assert_eq!(
+        decoded[1].as_ref(),
+        [0x30, 0x04, 0x04, 0x02, 0xfb, 0xff, 0xbf]
+    );
+
+    let wrapped = x5c[0]
+        .as_bytes()
+        .chunks(64)
+        .map(|line| std::str::from_utf8(line).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+    for bad in [
+        "MAQEAvv/vw",
+        "MAQEAvv/vw=",
+        "MAQEAvv/vw===",
+        "MAQEAvv_vw==",
+        " MAQEAvv/vw==",
+        "MAQEAvv/vw==\n",
+        "MAQE Avv/vw==",
+        "MAQE\r\nAvv/vw==",
+        "MAQEAvv/vw==MAA=",
+        &wrapped,
+    ] {
+        assert_eq!(
+            CertificateChainDer::from_x5c([&x5c[0], bad, &x5c[2]]),
+            Err(X5cError::InvalidBase64 { index: 1 }),
+            "{bad:?}"
+        );
+    }
+}
+
+#[test]
+fn empty() {
+    assert_eq!(
+        CertificateChainDer::from_x5c(Vec::<String>::new()),
+        Err(X5cError::Empty)
+    );
+}
+
+#[test]
+fn order_is_not_checked_when_decoding() {
+    // Decoding gives the same result whatever the features: only base64 is checked.
+    let mut x5c = chain().to_x5c();
+    x5c.reverse();
+    x5c.push("MAA=".to_owned());
+    assert_eq!(CertificateChainDer::from_x5c(&x5c).unwrap().len(), 4);
+}
+
+#[cfg(feature = "x509")]
+#[test]
+fn issuer_order() {
+    let chain = chain();
+    assert_eq!(chain.check_issuer_order(), Ok(()));
+    assert_eq!(
+        CertificateChainDer::from(chain[..1].to_vec()).check_issuer_order(),
+        Ok(())
+    );
+
+    let mut reversed = chain.to_vec();
+    reversed.reverse();
+    assert_eq!(
+        CertificateChainDer::from(reversed).check_issuer_order(),
+        Err(X5cError::NotLeafFirst { index: 0 })
+    );
+
+    // The intermediate cross-signed by another root does not issue the leaf's issuer.
+    let cross = CertificateDer::from(fs::read("testdata/int-cross.der").unwrap());
+    let mixed = vec![chain[0].clone(), cross, chain[2].clone()];
+    assert_eq!(
+        CertificateChainDer::from(mixed).check_issuer_order(),
+        Err(X5cError::NotLeafFirst { index: 1 })
+    );
+
+    let garbage = vec![chain[0].clone(), CertificateDer::from(vec![0x30, 0x00])];
+    assert!(matches!(
+        CertificateChainDer::from(garbage).check_issuer_order(),
+        Err(X5cError::InvalidCertificate { index: 1, .. })
+    ));
+}
+
+/// The leaf, intermediate and root fixtures, in that order
+fn chain() -> CertificateChainDer<'static> {
+    ["leaf", "int", "root"]
+        .iter()
+        .map(|name| CertificateDer::from(fs::read(format!("testdata/{name}.der")).unwrap()))
+        .collect()
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "zeroize")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ptr;
+
+use rustls_pki_types::{PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer};
+use zeroize::Zeroize;
+
+/// Passes allocations through to the system allocator, keeping a copy of the contents of the
+/// watched allocation when it is freed
+///
+/// The watched allocation and the copy are per thread, so that tests can run in parallel.
+struct Capture;
+
+unsafe impl GlobalAlloc for Capture {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = WATCHED.try_with(|watched| {
+            if watched.get() != ptr {
+                return;
+            }
+
+            let len = layout.size().min(CAPTURE_LIMIT);
+            CAPTURED.with(|captured| {
+                for (i, octet) in captured.iter().take(len).enumerate() {
+                    octet.set(*ptr.add(i));
+                }
+            });
+            CAPTURED_LEN.with(|captured_len| captured_len.set(len));
+            watched.set(ptr::null_mut());
+        });
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Capture = Capture;
+
+thread_local! {
+    static WATCHED: Cell<*mut u8> = const { Cell::new(ptr::null_mut()) };
+    static CAPTURED: [Cell<u8>; CAPTURE_LIMIT] = const { [ZERO; CAPTURE_LIMIT] };
+    static CAPTURED_LEN: Cell<usize> = const { Cell::new(0) };
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: Cell<u8> = Cell::new(0);
+const CAPTURE_LIMIT: usize = 64;
+
+#[test]
+fn keys_are_wiped_on_drop() {
+    assert_eq!(freed(|vec| drop(PrivatePkcs1KeyDer::from(vec))), [0; 32]);
+    assert_eq!(freed(|vec| drop(PrivateSec1KeyDer::from(vec))), [0; 32]);
+    assert_eq!(freed(|vec| drop(PrivatePkcs8KeyDer::from(vec))), [0; 32]);
+    for variant in [pkcs1, sec1, pkcs8] {
+        assert_eq!(freed(|vec| drop(variant(vec))), [0; 32]);
+    }
+
+    // The same bytes in a plain `Vec` are not wiped, so the harness sees the secret.
+    assert_eq!(freed(drop), SECRET);
+}
+
+#[test]
+fn zeroize_clears_in_place() {
+    for variant in [pkcs1, sec1, pkcs8] {
+        let freed = freed(|vec| {
+            let mut key = variant(vec);
+            key.zeroize();
+            assert!(key.secret_der().is_empty());
+            // Take the buffer out, so that it is freed as a plain `Vec`, without another wipe.
+            drop(key.into_secret_vec());
+        });
+        assert_eq!(freed, [0; 32]);
+    }
+
+    // Without the wipe, a buffer taken out this way still holds the secret.
+    assert_eq!(freed(|vec| drop(pkcs8(vec).into_secret_vec())), SECRET);
+}
+
+#[test]
+fn into_secret_vec_moves_owned_buffers() {
+    for variant in [pkcs1, sec1, pkcs8] {
+        let vec = SECRET.to_vec();
+        let ptr = vec.as_ptr();
+        let taken = variant(vec).into_secret_vec();
+        assert_eq!(taken.as_ptr(), ptr);
+        assert_eq!(taken, SECRET);
+    }
+
+    let borrowed = PrivateKeyDer::from(PrivateSec1KeyDer::from(&SECRET[..]));
+    let copy = borrowed.into_secret_vec();
+    assert_ne!(copy.as_ptr(), SECRET.as_ptr());
+    assert_eq!(copy, SECRET);
+}
+
+#[test]
+fn secret_der_owned_is_wiped_on_drop() {
+    for variant in [pkcs1, sec1, pkcs8] {
+        let key = variant(SECRET.to_vec());
+        let copy = key.secret_der_owned();
+        assert_ne!(copy.as_ptr(), key.secret_der().as_ptr());
+        assert_eq!(&copy[..], SECRET);
+
+        watch(copy.as_ptr());
+        drop(copy);
+        assert_eq!(captured(), [0; 32]);
+        // The key itself is untouched.
+        assert_eq!(key.secret_der(), SECRET);
+    }
+
+    // The copy from a borrowed key is wiped too.
+    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(&SECRET[..]));
+    let copy = key.secret_der_owned();
+    watch(copy.as_ptr());
+    drop(copy);
+    assert_eq!(captured(), [0; 32]);
+}
+
+#[test]
+fn borrowed_keys_are_left_alone() {
+    let mut secret = SECRET;
+    let mut key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(&secret[..]));
+    key.zeroize();
+    assert_eq!(key.secret_der(), SECRET);
+    drop(key);
+
+    secret.zeroize();
+    assert_eq!(secret, [0; 32]);
+}
+
+/// Give `f` a `Vec` holding [`SECRET`], and return the contents of its buffer when it is freed
+fn freed(f: impl FnOnce(Vec<u8>)) -> Vec<u8> {
+    let vec = SECRET.to_vec();
+    watch(vec.as_ptr());
+    f(vec);
+    captured()
+}
+
+/// Capture the contents of the allocation at `ptr` when it is freed
+fn watch(ptr: *const u8) {
+    WATCHED.with(|watched| watched.set(ptr as *mut u8));
+    CAPTURED_LEN.with(|len| len.set(0));
+}
+
+/// The contents of the watched allocation when it was freed
+fn captured() -> Vec<u8> {
+    assert!(
+        WATCHED.with(|watched| watched.get().is_null()),
+        "buffer was not freed"
+    );
+    let len = CAPTURED_LEN.with(|len| len.get());
+    CAPTURED.with(|captured| captured.iter().take(len).map(|octet| octet.get()).collect())
+}
+
+fn pkcs1(vec: Vec<u8>) -> PrivateKeyDer<'static> {
+    PrivateKeyDer::from(PrivatePkcs1KeyDer::from(vec))
+}
+
+fn sec1(vec: Vec<u8>) -> PrivateKeyDer<'static> {
+    PrivateKeyDer::from(PrivateSec1KeyDer::from(vec))
+}
+
+fn pkcs8(vec: Vec<u8>) -> PrivateKeyDer<'static> {
+    PrivateKeyDer::from(PrivatePkcs8KeyDer::from(vec))
+}
+
+const SECRET: [u8; 32] = *b"an entirely secret key material!";